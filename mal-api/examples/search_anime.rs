@@ -0,0 +1,43 @@
+//! Search anime by title, searching is public so a client id is enough
+//!
+//! ```sh
+//! MAL_CLIENT_ID=<client id> cargo run -p mal-api-rs --example search_anime -- "cowboy bebop"
+//! ```
+use mal_api::{get_anime_list, BearerToken, ClientId, GetAnimeListQuery};
+
+#[tokio::main]
+async fn main() -> Result<(), mal_api::Error> {
+    let q = std::env::args()
+        .nth(1)
+        .unwrap_or("Cowboy Bebop".to_string());
+    let query = GetAnimeListQuery {
+        q,
+        limit: 10,
+        offset: 0,
+        nsfw: false,
+        fields: Some("mean,num_episodes".to_string()),
+    };
+
+    let result = match std::env::var("MAL_ACCESS_TOKEN") {
+        Ok(token) => get_anime_list(&query, &BearerToken::new(token)).await?,
+        Err(_) => {
+            let client_id = std::env::var("MAL_CLIENT_ID")
+                .expect("either MAL_ACCESS_TOKEN or MAL_CLIENT_ID has to be set");
+            get_anime_list(&query, &ClientId::new(client_id)).await?
+        }
+    };
+
+    for node in result.data {
+        let anime = node.node;
+        println!(
+            "{:>6}  {:<50}  {} eps  mean {}",
+            anime.id,
+            anime.title,
+            anime
+                .num_episodes
+                .map_or("?".to_string(), |n| n.to_string()),
+            anime.mean.map_or("N/A".to_string(), |m| m.to_string())
+        );
+    }
+    Ok(())
+}
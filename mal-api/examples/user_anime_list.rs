@@ -0,0 +1,44 @@
+//! Print the watching list of a user (the logged in user by default)
+//!
+//! ```sh
+//! MAL_ACCESS_TOKEN=<token> cargo run -p mal-api-rs --example user_anime_list -- [username]
+//! ```
+use mal_api::{
+    get_user_anime_list,
+    model::{TitleLanguage, UserWatchStatus},
+    BearerToken, GetUserAnimeListQuery,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), mal_api::Error> {
+    let token = std::env::var("MAL_ACCESS_TOKEN").expect("MAL_ACCESS_TOKEN is not set");
+    let auth = BearerToken::new(token);
+    let user = std::env::args().nth(1).unwrap_or("@me".to_string());
+
+    let query = GetUserAnimeListQuery {
+        fields: Some("alternative_titles,num_episodes,list_status".to_string()),
+        status: Some(UserWatchStatus::Watching),
+        sort: None,
+        limit: 100,
+        offset: 0,
+        nsfw: true,
+    };
+
+    let result = get_user_anime_list(user, &query, &auth).await?;
+    for entry in result.data {
+        let watched = entry
+            .list_status
+            .as_ref()
+            .map_or(0, |s| s.num_episodes_watched);
+        println!(
+            "{:<50}  {}/{}",
+            entry.node.get_title(&TitleLanguage::English, false)[0],
+            watched,
+            entry
+                .node
+                .num_episodes
+                .map_or("?".to_string(), |n| n.to_string())
+        );
+    }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use super::model::*;
 use super::Error;
+use super::TokenProvider;
 use super::{get, handle_response, API_URL};
-use crate::auth::OAuth;
 use serde::Serialize;
 
 /// Get Anime List Request
@@ -15,7 +15,10 @@ pub struct GetAnimeListQuery {
     pub fields: Option<String>,
 }
 
-pub async fn get_anime_list(query: &GetAnimeListQuery, auth: &OAuth) -> Result<Page<Anime>, Error> {
+pub async fn get_anime_list(
+    query: &GetAnimeListQuery,
+    auth: &impl TokenProvider,
+) -> Result<Page<Anime>, Error> {
     let response = get(
         &format!("{}/anime?{}", API_URL, serde_urlencoded::to_string(query)?),
         auth,
@@ -34,7 +37,7 @@ pub struct GetAnimeDetailQuery {
 pub async fn get_anime_details(
     anime_id: u64,
     query: &GetAnimeDetailQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<Anime, Error> {
     let response = get(
         &format!(
@@ -61,7 +64,7 @@ pub struct GetAnimeRankingQuery {
 
 pub async fn get_anime_ranking(
     query: &GetAnimeRankingQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<Ranking<RankingAnimePair>, Error> {
     let response = get(
         &format!(
@@ -88,7 +91,7 @@ pub struct GetSeasonalAnimeQuery {
 pub async fn get_seasonal_anime(
     season: &AnimeSeason,
     query: &GetSeasonalAnimeQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<Page<Anime>, Error> {
     let season_name: &'static str = season.season.clone().into();
     let response = get(
@@ -116,7 +119,7 @@ pub struct GetSuggestedAnimeQuery {
 
 pub async fn get_suggested_anime(
     query: &GetSuggestedAnimeQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<Page<Anime>, Error> {
     let response = get(
         &format!(
@@ -135,7 +138,7 @@ pub mod tests {
 
     use super::*;
 
-    pub async fn get_anime<T: ToString>(q: T, auth: &OAuth) -> Result<Anime, Error> {
+    pub async fn get_anime<T: ToString>(q: T, auth: &impl TokenProvider) -> Result<Anime, Error> {
         let anime_query = GetAnimeListQuery {
             q: q.to_string(),
             limit: 4,
@@ -150,9 +153,7 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_get_anime_list() {
-        let mut auth = crate::auth::tests::get_auth();
-        auth.refresh().unwrap();
-        // let oauth = crate::auth::OAuth::get_auth(app_config::AppConfig::Load())
+        let auth = crate::test_utils::get_auth();
         let query = GetAnimeListQuery {
             q: "Code Geass".to_string(),
             limit: 4,
@@ -167,7 +168,7 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_get_anime_details() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetAnimeDetailQuery {
             fields: Some(ALL_ANIME_AND_MANGA_FIELDS.to_string()),
             nsfw: false,
@@ -181,7 +182,7 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_get_anime_ranking() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetAnimeRankingQuery {
             ranking_type: AnimeRankingType::All,
             limit: 4,
@@ -195,7 +196,7 @@ pub mod tests {
     }
     #[tokio::test]
     async fn test_get_seasonal_anime() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetSeasonalAnimeQuery {
             sort: None,
             limit: 4,
@@ -213,7 +214,7 @@ pub mod tests {
     }
     #[tokio::test]
     async fn test_get_suggested_anime() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetSuggestedAnimeQuery {
             limit: 4,
             offset: 0,
@@ -1,11 +1,11 @@
 use super::model::*;
 use super::Error;
+use super::TokenProvider;
 use super::{delete, get, handle_response, patch, API_URL};
-use crate::auth::OAuth;
 use serde::Serialize;
 
 /// Update specified anime in animelist
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct UpdateUserAnimeListStatusQuery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<UserWatchStatus>,
@@ -25,12 +25,16 @@ pub struct UpdateUserAnimeListStatusQuery {
     pub tags: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish_date: Option<DateWrapper>,
 }
 
 pub async fn update_anime_list_status(
     anime_id: u64,
     update: &UpdateUserAnimeListStatusQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<UserAnimeListStatus, Error> {
     let response = patch(
         &format!("{}/anime/{}/my_list_status", API_URL, anime_id,),
@@ -41,7 +45,7 @@ pub async fn update_anime_list_status(
     handle_response(&response)
 }
 
-pub async fn delete_anime_from_list(anime_id: u64, auth: &OAuth) -> Result<(), Error> {
+pub async fn delete_anime_from_list(anime_id: u64, auth: &impl TokenProvider) -> Result<(), Error> {
     let response = delete(
         &format!("{}/anime/{}/my_list_status", API_URL, anime_id),
         auth,
@@ -70,8 +74,8 @@ pub struct GetUserAnimeListQuery {
 pub async fn get_user_anime_list<U: ToString>(
     user: U,
     query: &GetUserAnimeListQuery,
-    auth: &OAuth,
-) -> Result<Page<Anime>, Error> {
+    auth: &impl TokenProvider,
+) -> Result<UserList<Anime, UserAnimeListStatus>, Error> {
     let response = get(
         &format!(
             "{}/users/{}/animelist?{}",
@@ -88,19 +92,19 @@ pub async fn get_user_anime_list<U: ToString>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::api::anime::tests::*;
+    use crate::anime::tests::*;
 
     #[tokio::test]
     #[ignore]
     async fn test_delete_anime_from_list() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let anime = get_anime("God of High School", &auth).await.unwrap();
         delete_anime_from_list(anime.id, &auth).await.unwrap();
     }
 
     #[tokio::test]
     async fn test_update_anime_list() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = UpdateUserAnimeListStatusQuery {
             status: Some(UserWatchStatus::Completed),
             is_rewatching: None,
@@ -111,6 +115,8 @@ mod tests {
             rewatch_value: None,
             tags: None,
             comments: None,
+            start_date: None,
+            finish_date: None,
         };
 
         let anime = get_anime(
@@ -129,7 +135,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_user_anime_list() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetUserAnimeListQuery {
             fields: Some(ALL_ANIME_AND_MANGA_FIELDS.to_string()),
             status: None,
@@ -0,0 +1,287 @@
+//! Async bindings for the [myanimelist v2 api](https://myanimelist.net/apiconfig/references/api/v2)
+//!
+//! Every endpoint takes something implementing [`TokenProvider`], use [`BearerToken`]
+//! if you already have an access token, or [`ClientId`] for read-only access to the
+//! public endpoints. See `examples/` for usage.
+#![allow(ambiguous_glob_reexports)]
+/// Anime API endpoints
+pub mod anime;
+pub use anime::*;
+/// User animelist API endpoints
+pub mod animelist;
+pub use animelist::*;
+/// manga API endpoints
+pub mod manga;
+pub use manga::*;
+/// User mangalist API endpoints
+pub mod mangalist;
+pub use mangalist::*;
+/// API objects
+pub mod model;
+/// User API endpoints
+pub mod user;
+pub use user::*;
+
+use reqwest::{ClientBuilder, RequestBuilder};
+use serde::{Deserialize, Serialize};
+
+pub const API_URL: &str = "https://api.myanimelist.net/v2";
+
+/// Credentials used to authorize API requests
+pub trait TokenProvider {
+    /// OAuth2 access token sent as a bearer token, `None` if not logged in
+    fn access_token(&self) -> Option<&str>;
+    /// User agent sent with every request
+    fn user_agent(&self) -> &str;
+    /// Client id sent as `X-MAL-CLIENT-ID` when there is no access token,
+    /// which is enough for the public (read-only) endpoints
+    fn client_id(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// A plain bearer token, for when the token is obtained elsewhere
+#[derive(Clone, Debug)]
+pub struct BearerToken {
+    pub access_token: String,
+    pub user_agent: String,
+}
+
+impl BearerToken {
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Self {
+            access_token: access_token.into(),
+            user_agent: concat!("mal-api-rs/", env!("CARGO_PKG_VERSION")).to_string(),
+        }
+    }
+}
+
+impl TokenProvider for BearerToken {
+    fn access_token(&self) -> Option<&str> {
+        Some(&self.access_token)
+    }
+
+    fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+}
+
+/// Client id only, public endpoints work but anything under `@me` fails
+#[derive(Clone, Debug)]
+pub struct ClientId {
+    pub client_id: String,
+    pub user_agent: String,
+}
+
+impl ClientId {
+    pub fn new(client_id: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            user_agent: concat!("mal-api-rs/", env!("CARGO_PKG_VERSION")).to_string(),
+        }
+    }
+}
+
+impl TokenProvider for ClientId {
+    fn access_token(&self) -> Option<&str> {
+        None
+    }
+
+    fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    fn client_id(&self) -> Option<&str> {
+        Some(&self.client_id)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    NoAuth,
+    TimedOut,
+    Unknown,
+    NoBody,
+    ParseError(serde_json::Error),
+    QuerySerializeError(serde_urlencoded::ser::Error),
+    HttpError(reqwest::StatusCode),
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            Error::TimedOut
+        } else {
+            Error::Unknown
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::ParseError(e)
+    }
+}
+
+impl From<serde_urlencoded::ser::Error> for Error {
+    fn from(e: serde_urlencoded::ser::Error) -> Self {
+        Error::QuerySerializeError(e)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ApiResponse {
+    status: reqwest::StatusCode,
+    body: Option<String>,
+}
+
+type ApiResult<T> = Result<T, Error>;
+
+pub(crate) fn apply_headers<A: TokenProvider>(
+    req: RequestBuilder,
+    auth: &A,
+) -> ApiResult<RequestBuilder> {
+    let req = req
+        .header(reqwest::header::ACCEPT, "application/json")
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            "application/x-www-form-urlencoded",
+        );
+    match (auth.access_token(), auth.client_id()) {
+        (Some(access_token), _) => Ok(req.header(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {}", access_token),
+        )),
+        (None, Some(client_id)) => Ok(req.header("X-MAL-CLIENT-ID", client_id)),
+        (None, None) => Err(Error::NoAuth),
+    }
+}
+
+pub(crate) async fn send<A: TokenProvider>(
+    request: RequestBuilder,
+    auth: &A,
+) -> ApiResult<ApiResponse> {
+    let request = apply_headers(request, auth)?;
+    let response = request.send().await?;
+    let status = response.status();
+    Ok(ApiResponse {
+        status,
+        body: (response.text().await).ok(),
+    })
+}
+
+pub(crate) async fn get<U: reqwest::IntoUrl, A: TokenProvider>(
+    url: U,
+    auth: &A,
+) -> ApiResult<ApiResponse> {
+    let request = ClientBuilder::new()
+        .user_agent(auth.user_agent())
+        .build()?
+        .get(url);
+    send(request, auth).await
+}
+
+pub(crate) async fn patch<U: reqwest::IntoUrl, B: Serialize, A: TokenProvider>(
+    url: U,
+    auth: &A,
+    body: &B,
+) -> ApiResult<ApiResponse> {
+    let request = ClientBuilder::new()
+        .user_agent(auth.user_agent())
+        .build()?
+        .patch(url)
+        .body(serde_urlencoded::to_string(body)?);
+    send(request, auth).await
+}
+
+pub(crate) async fn delete<U: reqwest::IntoUrl, A: TokenProvider>(
+    url: U,
+    auth: &A,
+) -> ApiResult<ApiResponse> {
+    let request = ClientBuilder::new()
+        .user_agent(auth.user_agent())
+        .build()?
+        .delete(url);
+    send(request, auth).await
+}
+
+pub(crate) fn handle_response<'a, D: Deserialize<'a>>(res: &'a ApiResponse) -> ApiResult<D> {
+    if !res.status.is_success() {
+        return Err(Error::HttpError(res.status));
+    }
+    if let Some(body) = &res.body {
+        Ok(serde_json::from_str::<D>(body)?)
+    } else {
+        Err(Error::NoBody)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use super::BearerToken;
+
+    /// Tests hit the live API, export a valid token as `MAL_ACCESS_TOKEN` to run them
+    pub fn get_auth() -> BearerToken {
+        BearerToken::new(std::env::var("MAL_ACCESS_TOKEN").expect("MAL_ACCESS_TOKEN is not set"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubAuth {
+        access_token: Option<&'static str>,
+        client_id: Option<&'static str>,
+    }
+
+    impl TokenProvider for StubAuth {
+        fn access_token(&self) -> Option<&str> {
+            self.access_token
+        }
+
+        fn user_agent(&self) -> &str {
+            "stub"
+        }
+
+        fn client_id(&self) -> Option<&str> {
+            self.client_id
+        }
+    }
+
+    fn headers(auth: &StubAuth) -> ApiResult<reqwest::header::HeaderMap> {
+        let req = reqwest::Client::new().get("https://api.myanimelist.net/v2/anime");
+        Ok(apply_headers(req, auth)?.build()?.headers().clone())
+    }
+
+    #[test]
+    fn test_apply_headers() {
+        // the token wins over the client id
+        let headers_of_token = headers(&StubAuth {
+            access_token: Some("token"),
+            client_id: Some("client"),
+        })
+        .unwrap();
+        assert_eq!(
+            headers_of_token[reqwest::header::AUTHORIZATION],
+            "Bearer token"
+        );
+        assert!(!headers_of_token.contains_key("X-MAL-CLIENT-ID"));
+
+        let headers_of_client = headers(&StubAuth {
+            access_token: None,
+            client_id: Some("client"),
+        })
+        .unwrap();
+        assert_eq!(headers_of_client["X-MAL-CLIENT-ID"], "client");
+        assert!(!headers_of_client.contains_key(reqwest::header::AUTHORIZATION));
+
+        assert!(matches!(
+            headers(&StubAuth {
+                access_token: None,
+                client_id: None,
+            }),
+            Err(Error::NoAuth)
+        ));
+    }
+}
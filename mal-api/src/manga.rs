@@ -1,7 +1,7 @@
 use super::model::*;
 use super::Error;
+use super::TokenProvider;
 use super::{get, handle_response, API_URL};
-use crate::auth::OAuth;
 use serde::Serialize;
 
 #[derive(Clone, Debug, Serialize)]
@@ -14,7 +14,10 @@ pub struct GetMangaListQuery {
     pub fields: Option<String>,
 }
 
-pub async fn get_manga_list(query: &GetMangaListQuery, auth: &OAuth) -> Result<Page<Manga>, Error> {
+pub async fn get_manga_list(
+    query: &GetMangaListQuery,
+    auth: &impl TokenProvider,
+) -> Result<Page<Manga>, Error> {
     let response = get(
         &format! {"{}/manga?{}", API_URL, serde_urlencoded::to_string(query)?},
         auth,
@@ -33,7 +36,7 @@ pub struct GetMangaDetailQuery {
 pub async fn get_manga_details(
     manga_id: u64,
     query: &GetMangaDetailQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<Manga, Error> {
     let response = get(
         &format!(
@@ -60,7 +63,7 @@ pub struct GetMangaRankingQuery {
 
 pub async fn get_manga_ranking(
     query: &GetMangaRankingQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<Ranking<RankingMangaPair>, Error> {
     let response = get(
         &format!(
@@ -78,7 +81,7 @@ pub async fn get_manga_ranking(
 pub mod tests {
     use super::*;
 
-    pub async fn get_manga<T: ToString>(q: T, auth: &OAuth) -> Result<Manga, Error> {
+    pub async fn get_manga<T: ToString>(q: T, auth: &impl TokenProvider) -> Result<Manga, Error> {
         let manga_query = GetMangaListQuery {
             q: q.to_string(),
             limit: 4,
@@ -93,7 +96,7 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_get_manga_list() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetMangaListQuery {
             q: "Kaguya-Sama Wa Kokurasetai".to_string(),
             limit: 2,
@@ -108,7 +111,7 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_get_manga_details() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetMangaDetailQuery {
             fields: Some(ALL_ANIME_AND_MANGA_FIELDS.to_string()),
             nsfw: false,
@@ -124,7 +127,7 @@ pub mod tests {
 
     #[tokio::test]
     async fn test_get_manga_ranking() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetMangaRankingQuery {
             ranking_type: MangaRankingType::All,
             limit: 100,
@@ -1,10 +1,10 @@
 use super::model::*;
 use super::Error;
+use super::TokenProvider;
 use super::{delete, get, handle_response, patch, API_URL};
-use crate::auth::OAuth;
 use serde::Serialize;
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct UpdateUserMangaStatus {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<UserReadStatus>,
@@ -26,12 +26,16 @@ pub struct UpdateUserMangaStatus {
     pub tags: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<DateWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish_date: Option<DateWrapper>,
 }
 
 pub async fn update_manga_list_status(
     manga_id: u64,
     update: &UpdateUserMangaStatus,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<UserMangaListStatus, Error> {
     let response = patch(
         &format!("{}/manga/{}/my_list_status", API_URL, manga_id),
@@ -42,7 +46,7 @@ pub async fn update_manga_list_status(
     handle_response(&response)
 }
 
-pub async fn delete_manga_from_list(manga_id: u64, auth: &OAuth) -> Result<(), Error> {
+pub async fn delete_manga_from_list(manga_id: u64, auth: &impl TokenProvider) -> Result<(), Error> {
     let response = delete(
         &format!("{}/manga/{}/my_list_status", API_URL, manga_id),
         auth,
@@ -71,8 +75,8 @@ pub struct GetUserMangaListQuery {
 pub async fn get_user_manga_list<U: ToString>(
     user: U,
     query: &GetUserMangaListQuery,
-    auth: &OAuth,
-) -> Result<Page<Manga>, Error> {
+    auth: &impl TokenProvider,
+) -> Result<UserList<Manga, UserMangaListStatus>, Error> {
     let response = get(
         &format!(
             "{}/users/{}/mangalist?{}",
@@ -89,18 +93,18 @@ pub async fn get_user_manga_list<U: ToString>(
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::api::manga::tests::*;
+    use crate::manga::tests::*;
 
     #[tokio::test]
     async fn test_delete_manga_from_list() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let manga = get_manga("Grand Blue", &auth).await.unwrap();
         delete_manga_from_list(manga.id, &auth).await.unwrap();
     }
 
     #[tokio::test]
     async fn test_update_manga_list() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = UpdateUserMangaStatus {
             status: Some(UserReadStatus::Reading),
             is_rereading: None,
@@ -112,6 +116,8 @@ mod test {
             reread_value: None,
             tags: None,
             comments: None,
+            start_date: None,
+            finish_date: None,
         };
         let manga = get_manga("Grand Blue", &auth).await.unwrap();
         let result = update_manga_list_status(manga.id, &query, &auth)
@@ -123,7 +129,7 @@ mod test {
 
     #[tokio::test]
     async fn test_get_user_manga_list() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetUserMangaListQuery {
             fields: Some(ALL_ANIME_AND_MANGA_FIELDS.to_string()),
             status: None,
@@ -1,5 +1,3 @@
-use crate::config::app_config::{AppConfig, TitleLanguage};
-
 use super::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -253,7 +251,7 @@ pub enum SortStyle {
 }
 
 impl Anime {
-    pub fn get_title(&self, app_config: &AppConfig, both: bool) -> Vec<String> {
+    pub fn get_title(&self, language: &TitleLanguage, both: bool) -> Vec<String> {
         if both {
             vec![
                 self.title.clone(),
@@ -263,7 +261,7 @@ impl Anime {
                     .unwrap_or_else(|| self.title.clone()),
             ]
         } else {
-            match app_config.title_language {
+            match language {
                 TitleLanguage::Japanese => vec![self.title.clone()],
                 TitleLanguage::English => {
                     if let Some(ref alternative_titles) = self.alternative_titles {
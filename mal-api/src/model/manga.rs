@@ -1,5 +1,3 @@
-use crate::config::app_config::{AppConfig, MangaDisplayType, TitleLanguage};
-
 use super::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -138,7 +136,7 @@ pub struct MangaRecommendation {
 }
 
 impl Manga {
-    pub fn get_title(&self, app_config: &AppConfig, both: bool) -> Vec<String> {
+    pub fn get_title(&self, language: &TitleLanguage, both: bool) -> Vec<String> {
         if both {
             vec![
                 self.title.clone(),
@@ -149,7 +147,7 @@ impl Manga {
                     }),
             ]
         } else {
-            match app_config.title_language {
+            match language {
                 TitleLanguage::Japanese => vec![self.title.clone()],
                 TitleLanguage::English => {
                     if let Some(ref alternative_titles) = self.alternative_titles {
@@ -169,8 +167,8 @@ impl Manga {
             }
         }
     }
-    pub fn get_num(&self, app_config: &AppConfig) -> String {
-        match app_config.manga_display_type {
+    pub fn get_num(&self, display_type: &MangaDisplayType) -> String {
+        match display_type {
             MangaDisplayType::Vol => self.num_volumes.map_or("N/A vol".to_string(), |n| {
                 if n == 0 {
                     "N/A".to_string()
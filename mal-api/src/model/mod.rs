@@ -28,6 +28,7 @@ use time::{
 
 pub type Page<T> = PageableData<Vec<Node<T>>>;
 pub type Ranking<T> = PageableData<Vec<T>>;
+pub type UserList<T, S> = PageableData<Vec<ListNode<T, S>>>;
 
 #[derive(Debug, Clone)]
 pub enum RankingType {
@@ -58,6 +59,28 @@ pub struct Node<N: Clone + std::fmt::Debug> {
     pub node: N,
 }
 
+/// Entry of a user's list, `list_status` belongs to the list owner
+/// while `node.my_list_status` always belongs to the authorized user
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ListNode<N: Clone + Debug, S: Clone + Debug> {
+    pub node: N,
+    pub list_status: Option<S>,
+}
+
+impl<N: Clone + Debug, S: Clone + Debug> UserList<N, S> {
+    /// Drops the list statuses
+    pub fn into_page(self) -> Page<N> {
+        PageableData {
+            data: self
+                .data
+                .into_iter()
+                .map(|entry| Node { node: entry.node })
+                .collect(),
+            paging: self.paging,
+        }
+    }
+}
+
 pub enum Media<'a> {
     Anime(&'a Anime),
     Manga(&'a Manga),
@@ -69,6 +92,21 @@ pub struct Picture {
     pub medium: Option<String>,
 }
 
+/// Which title to prefer when displaying an entry
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub enum TitleLanguage {
+    Japanese,
+    English,
+}
+
+/// Which counts to show for a manga
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum MangaDisplayType {
+    Vol,
+    Ch,
+    Both,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AlternativeTitles {
     pub synonyms: Option<Vec<String>>,
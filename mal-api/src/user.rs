@@ -1,7 +1,7 @@
 use super::model::*;
 use super::Error;
+use super::TokenProvider;
 use super::{get, handle_response, API_URL};
-use crate::auth::OAuth;
 use serde::Serialize;
 
 #[derive(Clone, Debug, Serialize)]
@@ -12,7 +12,7 @@ pub struct GetUserInformationQuery {
 pub async fn get_my_user_information<U: ToString>(
     user: U,
     query: &GetUserInformationQuery,
-    auth: &OAuth,
+    auth: &impl TokenProvider,
 ) -> Result<UserInfo, Error> {
     let response = get(
         &format!(
@@ -33,7 +33,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_user_information() {
-        let auth = crate::auth::tests::get_auth();
+        let auth = crate::test_utils::get_auth();
         let query = GetUserInformationQuery {
             fields: Some(ALL_USER_FIELDS.to_string()),
         };
@@ -0,0 +1,366 @@
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Utc, Weekday,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        self,
+        model::{Anime, AnimeStatus, Broadcast, UserWatchStatus},
+        TokenProvider,
+    },
+    stats,
+};
+
+// MAL broadcast times are in Japan time
+const JST_OFFSET_SECONDS: i32 = 9 * 3600;
+
+// what the calendar and the ics export need
+pub const AIRING_FIELDS: &str = "id,title,alternative_titles,status,my_list_status,num_episodes,\
+    broadcast,main_picture,start_date,average_episode_duration";
+
+// used when MAL has no episode duration
+const DEFAULT_EPISODE_MINUTES: u64 = 24;
+
+// longest content line of an ics file, in octets
+const ICS_LINE_LIMIT: usize = 75;
+
+/// next time the weekly broadcast starts after `now`
+pub fn next_broadcast(broadcast: &Broadcast, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let weekday: Weekday = broadcast.day_of_the_week.parse().ok()?;
+    let start = broadcast.start_time.as_ref()?.time;
+    let time = NaiveTime::from_hms_opt(start.hour() as u32, start.minute() as u32, 0)?;
+
+    let jst = FixedOffset::east_opt(JST_OFFSET_SECONDS)?;
+    let today = now.with_timezone(&jst).date_naive();
+    let days_ahead =
+        (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    let mut next = jst
+        .from_local_datetime(&(today + Duration::days(days_ahead as i64)).and_time(time))
+        .single()?
+        .with_timezone(&Utc);
+    // airs today but already started
+    if next <= now {
+        next += Duration::weeks(1);
+    }
+    Some(next)
+}
+
+/// next broadcast of an anime still airing
+pub fn next_episode_airing(anime: &Anime, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if !matches!(anime.status, Some(AnimeStatus::CurrentlyAiring)) {
+        return None;
+    }
+    next_broadcast(anime.broadcast.as_ref()?, now)
+}
+
+/// time left until `at`, like "2d 4h" or "35m"
+pub fn format_countdown(at: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (at - now).num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) => format!("{}m", minutes),
+        (0, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h", days, hours),
+    }
+}
+
+/// titles of my list with one of `statuses`, with the airing fields
+pub async fn fetch_my_anime(
+    statuses: &[UserWatchStatus],
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<Vec<Anime>, api::Error> {
+    let list = stats::fetch_full_anime_list("@me", AIRING_FIELDS, nsfw, auth).await?;
+    Ok(list
+        .into_iter()
+        .filter(|entry| {
+            entry
+                .list_status
+                .as_ref()
+                .is_some_and(|s| statuses.contains(&s.status))
+        })
+        .map(|entry| entry.node)
+        .collect())
+}
+
+/// airing titles of my list with one of `statuses`
+pub async fn fetch_my_airing(
+    statuses: &[UserWatchStatus],
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<Vec<Anime>, api::Error> {
+    Ok(fetch_my_anime(statuses, nsfw, auth)
+        .await?
+        .into_iter()
+        .filter(|anime| matches!(anime.status, Some(AnimeStatus::CurrentlyAiring)))
+        .collect())
+}
+
+/// first broadcast on or after the start date
+pub fn premiere(anime: &Anime) -> Option<DateTime<Utc>> {
+    let broadcast = anime.broadcast.as_ref()?;
+    let start = anime.start_date.as_ref()?.date;
+    let date = NaiveDate::from_ymd_opt(start.year(), start.month() as u32, start.day() as u32)?;
+    let jst = FixedOffset::east_opt(JST_OFFSET_SECONDS)?;
+    let midnight = jst
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .single()?;
+    next_broadcast(
+        broadcast,
+        midnight.with_timezone(&Utc) - Duration::seconds(1),
+    )
+}
+
+/// broadcast of the last episode, one per week since the premiere
+pub fn final_broadcast(anime: &Anime) -> Option<DateTime<Utc>> {
+    let episodes = anime.num_episodes.filter(|n| *n > 0)?;
+    Some(premiere(anime)? + Duration::weeks(episodes as i64 - 1))
+}
+
+/// number of the episode broadcast at `at`, one per week since the premiere
+pub fn episode_number(anime: &Anime, at: DateTime<Utc>) -> Option<u64> {
+    let first = premiere(anime)?;
+    if at < first {
+        return None;
+    }
+    let number = (at - first).num_weeks() as u64 + 1;
+    match anime.num_episodes {
+        // past the last episode, the schedule slipped
+        Some(episodes) if episodes > 0 && number > episodes => None,
+        _ => Some(number),
+    }
+}
+
+/// weekly recurring events of the airing titles, as an RFC 5545 calendar
+pub fn to_ics(anime: &[Anime], now: DateTime<Utc>) -> String {
+    let format = "%Y%m%dT%H%M%SZ";
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//mal-cli//airing schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "X-WR-CALNAME:Airing anime".to_string(),
+    ];
+    for anime in anime {
+        if !matches!(anime.status, Some(AnimeStatus::CurrentlyAiring)) {
+            continue;
+        }
+        // without a start date the aired episodes are unknown, so is the count left
+        let (first, count) = match premiere(anime) {
+            Some(first) => (first, anime.num_episodes.filter(|n| *n > 0)),
+            None => match anime
+                .broadcast
+                .as_ref()
+                .and_then(|b| next_broadcast(b, now))
+            {
+                Some(next) => (next, None),
+                None => continue,
+            },
+        };
+        let minutes = anime
+            .average_episode_duration
+            .map(|seconds| seconds / 60)
+            .filter(|m| *m > 0)
+            .unwrap_or(DEFAULT_EPISODE_MINUTES);
+        // the broadcast is weekly at the same UTC time, Japan has no daylight saving
+        let rrule = match count {
+            Some(count) => format!("RRULE:FREQ=WEEKLY;COUNT={}", count),
+            None => "RRULE:FREQ=WEEKLY".to_string(),
+        };
+        lines.extend([
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:anime-{}@mal-cli", anime.id),
+            format!("DTSTAMP:{}", now.format(format)),
+            format!("DTSTART:{}", first.format(format)),
+            format!("DURATION:PT{}M", minutes),
+            rrule,
+            format!("SUMMARY:{}", escape_ics_text(&anime.title)),
+            format!("URL:https://myanimelist.net/anime/{}", anime.id),
+            "END:VEVENT".to_string(),
+        ]);
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines
+        .iter()
+        .map(|line| fold_ics_line(line))
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// a CRLF terminated line, split every 75 octets with a leading space on the next lines
+fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        // continuation lines start with a space, which counts
+        if length + c.len_utf8() > ICS_LINE_LIMIT {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// Titles the weekly calendar is built from
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ScheduleSource {
+    // watching and plan to watch entries of my list
+    MyList,
+    CurrentSeason,
+}
+
+/// Airing titles by the local weekday they air on, Monday first
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Schedule {
+    pub source: ScheduleSource,
+    // seven days, each sorted by broadcast time
+    pub days: Vec<Vec<Anime>>,
+}
+
+impl Schedule {
+    /// a title airs on the same weekday every week, so the days only depend on the timezone
+    pub fn new<Tz: TimeZone>(
+        source: ScheduleSource,
+        anime: Vec<Anime>,
+        now: DateTime<Utc>,
+        tz: &Tz,
+    ) -> Self {
+        let mut days: Vec<Vec<(NaiveTime, Anime)>> = vec![vec![]; 7];
+        for anime in anime {
+            if let Some(next) = next_episode_airing(&anime, now) {
+                let local = next.with_timezone(tz);
+                days[local.weekday().num_days_from_monday() as usize].push((local.time(), anime));
+            }
+        }
+        Self {
+            source,
+            days: days
+                .into_iter()
+                .map(|mut day| {
+                    day.sort_by_key(|(time, _)| *time);
+                    day.into_iter().map(|(_, anime)| anime).collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::model::TimeWrapper;
+
+    fn broadcast(day: &str, hour: u8, minute: u8) -> Broadcast {
+        Broadcast {
+            day_of_the_week: day.to_string(),
+            start_time: Some(TimeWrapper {
+                time: time::Time::from_hms(hour, minute, 0).unwrap(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_next_broadcast() {
+        // Saturday 2025-01-04 12:00 UTC is 21:00 in Japan
+        let now = Utc.with_ymd_and_hms(2025, 1, 4, 12, 0, 0).unwrap();
+
+        // later the same day in Japan
+        let next = next_broadcast(&broadcast("saturday", 23, 30), now).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2025, 1, 4, 14, 30, 0).unwrap());
+
+        // already aired today, next week
+        let next = next_broadcast(&broadcast("saturday", 1, 0), now).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2025, 1, 10, 16, 0, 0).unwrap());
+
+        // sunday 00:30 in Japan is still saturday in UTC
+        let next = next_broadcast(&broadcast("sunday", 0, 30), now).unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2025, 1, 4, 15, 30, 0).unwrap());
+
+        assert!(next_broadcast(&broadcast("other", 0, 30), now).is_none());
+        assert_eq!(format_countdown(next, now), "3h 30m");
+    }
+
+    #[test]
+    fn test_weekly_schedule_in_local_time() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 4, 12, 0, 0).unwrap();
+        let airing = |id, day, hour| {
+            let mut anime: Anime = serde_json::from_value(serde_json::json!({
+                "id": id,
+                "title": format!("anime {}", id),
+                "status": "currently_airing",
+            }))
+            .unwrap();
+            anime.broadcast = Some(broadcast(day, hour, 0));
+            anime
+        };
+        let anime = vec![
+            airing(1, "monday", 23),
+            airing(2, "monday", 8),
+            airing(3, "tuesday", 1),
+        ];
+        // UTC-5, 9 + 5 hours behind Japan
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let schedule = Schedule::new(ScheduleSource::MyList, anime, now, &tz);
+        let ids = |day: usize| -> Vec<u64> { schedule.days[day].iter().map(|a| a.id).collect() };
+        // monday 23:00 and tuesday 01:00 in Japan are monday 09:00 and 11:00 there
+        assert_eq!(ids(0), vec![1, 3]);
+        // monday 08:00 in Japan is sunday 18:00 there
+        assert_eq!(ids(6), vec![2]);
+    }
+
+    #[test]
+    fn test_ics_export() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 4, 12, 0, 0).unwrap();
+        let mut anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 52991,
+            "title": "Sousou no Frieren; part 2, with a very long title that needs to be folded",
+            "status": "currently_airing",
+            "start_date": "2025-01-03",
+            "num_episodes": 12,
+            "average_episode_duration": 1440,
+        }))
+        .unwrap();
+        anime.broadcast = Some(broadcast("saturday", 23, 30));
+        let ics = to_ics(&[anime], now);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        // first saturday on or after the start date, 23:30 in Japan
+        assert!(ics.contains("DTSTART:20250104T143000Z\r\n"));
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;COUNT=12\r\n"));
+        assert!(ics.contains("DURATION:PT24M\r\n"));
+        assert!(ics.contains("SUMMARY:Sousou no Frieren\\; part 2\\, with"));
+        assert!(ics.split("\r\n").all(|line| line.len() <= ICS_LINE_LIMIT));
+    }
+
+    #[test]
+    fn test_episode_number() {
+        let mut anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": "anime",
+            "status": "currently_airing",
+            "start_date": "2025-01-03",
+            "num_episodes": 2,
+        }))
+        .unwrap();
+        anime.broadcast = Some(broadcast("saturday", 23, 30));
+        let first = Utc.with_ymd_and_hms(2025, 1, 4, 14, 30, 0).unwrap();
+
+        assert_eq!(premiere(&anime), Some(first));
+        assert_eq!(episode_number(&anime, first - Duration::minutes(1)), None);
+        assert_eq!(episode_number(&anime, first), Some(1));
+        assert_eq!(episode_number(&anime, first + Duration::weeks(1)), Some(2));
+        assert_eq!(episode_number(&anime, first + Duration::weeks(2)), None);
+    }
+}
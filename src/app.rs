@@ -1,8 +1,21 @@
 #![allow(clippy::new_without_default)]
 #![allow(clippy::large_enum_variant)]
-use crate::api::{self, model::*};
-use crate::config::app_config::AppConfig;
+use crate::airing::Schedule;
+use crate::api::{self, model::*, UpdateUserAnimeListStatusQuery, UpdateUserMangaStatus};
+use crate::bookmarks::{Bookmark, Bookmarks, MediaKind};
+use crate::bulk::{self, BulkAction, BulkEdit, BulkPopup, BulkProgress};
+use crate::compare::Comparison;
+use crate::config::app_config::{AppConfig, HomeWidget};
+use crate::config::keymap::{Action, KeyContext};
+use crate::event::Key;
+use crate::fuzzy::{self, FuzzyFilter};
+use crate::history::{History, Viewed};
+use crate::home::{self, Home};
 use crate::network::IoEvent;
+use crate::palette::{self, Command, Palette};
+use crate::progress;
+use crate::stats::{AnimeDashboard, MangaStatistics};
+use crate::undo::{Change, ListChanges};
 use chrono::Datelike;
 use image::{DynamicImage, ImageError};
 use ratatui::layout::Rect;
@@ -11,9 +24,12 @@ use ratatui::widgets::{Block, Borders};
 use ratatui::Frame;
 use ratatui_image::picker::Picker;
 use ratatui_image::protocol::StatefulProtocol;
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::sync::mpsc::Sender;
+use std::time::Instant;
 use tui_logger::{TuiLoggerWidget, TuiWidgetState};
 
 use strum_macros::IntoStaticStr;
@@ -26,15 +42,45 @@ const DEFAULT_ROUTE: Route = Route {
     image: None,
 };
 
+// grid used until the terminal size is known
 pub const DISPLAY_RAWS_NUMBER: usize = 5;
 
 pub const SEASONS: [&str; 4] = ["Winter", "Spring", "Summer", "Fall"];
 
 pub const DISPLAY_COLUMN_NUMBER: usize = 3;
 
+// smallest card that still shows its five lines
+pub const CARD_MIN_WIDTH: u16 = 28;
+pub const CARD_MIN_HEIGHT: u16 = 7;
+
 pub const ANIME_OPTIONS: [&str; 3] = ["Seasonal", "Ranking", "Suggested"];
 
-pub const USER_OPTIONS: [&str; 3] = ["Stats", "AnimeList", "MangaList"];
+pub const USER_OPTIONS: [&str; 5] = ["Stats", "AnimeList", "MangaList", "Bookmarks", "History"];
+
+// shown instead of USER_OPTIONS when browsing without login
+pub const ANONYMOUS_USER_OPTIONS: [&str; 3] = ["Login", "Bookmarks", "History"];
+
+pub const DASHBOARD_TABS: [&str; 6] = [
+    "Scores",
+    "Genres",
+    "Studios",
+    "Seasons",
+    "Media Types",
+    "Deviation",
+];
+
+// rows of the user list sort & filter popup
+pub const LIST_VIEW_OPTIONS: [&str; 9] = [
+    "Sort",
+    "Order",
+    "Genre",
+    "Media Type",
+    "Status",
+    "Min Score",
+    "Max Score",
+    "Tag",
+    "Reset",
+];
 
 pub const GENERAL_OPTIONS: [&str; 3] = ["Help", "About", "Quit"];
 
@@ -50,9 +96,12 @@ pub const USER_READ_STATUS: [&str; 5] =
 
 pub const ANIME_OPTIONS_RANGE: std::ops::Range<usize> = 0..3;
 
-pub const USER_OPTIONS_RANGE: std::ops::Range<usize> = 3..6;
+pub const USER_OPTIONS_RANGE: std::ops::Range<usize> = 3..8;
+
+pub const GENERAL_OPTIONS_RANGE: std::ops::Range<usize> = 8..11;
 
-pub const GENERAL_OPTIONS_RANGE: std::ops::Range<usize> = 6..9;
+// library.selected_index past every side menu entry, nothing is highlighted
+pub const NO_MENU_SELECTION: usize = GENERAL_OPTIONS_RANGE.end;
 
 pub const RATING_OPTIONS: [&str; 11] = [
     "None",
@@ -103,7 +152,7 @@ pub enum ActiveBlock {
     Error,
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
 pub enum ActiveDisplayBlock {
     SearchResultBlock,
     Help,
@@ -119,14 +168,148 @@ pub enum ActiveDisplayBlock {
     Empty,
     AnimeDetails,
     MangaDetails,
+    Comparison,
+    StatsDashboard,
+    Bookmarks,
+    History,
+    ListChanges,
+    Schedule,
+}
+/// Number of cards the result views fit on a page
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GridSize {
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl GridSize {
+    pub fn from_area(width: u16, height: u16) -> Self {
+        Self {
+            columns: (width / CARD_MIN_WIDTH).max(1) as usize,
+            rows: (height / CARD_MIN_HEIGHT).max(1) as usize,
+        }
+    }
+
+    pub fn page_len(&self) -> usize {
+        self.columns * self.rows
+    }
+
+    /// last card shown on the page starting at `start`
+    pub fn end_index(&self, start: usize, len: usize) -> usize {
+        (start + self.page_len()).min(len).saturating_sub(1)
+    }
+}
+
+/// Selected card of a grid: `start` is the first card of the page (always the
+/// start of a row) and `selected` the position in the page
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GridPosition {
+    pub start: usize,
+    pub selected: usize,
+}
+
+impl GridPosition {
+    /// keeps the selected card on the page after a resize or when the list got shorter
+    pub fn clamp(self, grid: GridSize, len: usize) -> Self {
+        if len == 0 {
+            return Self {
+                start: 0,
+                selected: 0,
+            };
+        }
+        let absolute = (self.start + self.selected).min(len - 1);
+        let row_start = absolute - absolute % grid.columns;
+        let start = (self.start - self.start % grid.columns)
+            .min(row_start)
+            .max((row_start + grid.columns).saturating_sub(grid.page_len()));
+        Self {
+            start,
+            selected: absolute - start,
+        }
+    }
+
+    pub fn left(self, grid: GridSize) -> Self {
+        if self.selected.is_multiple_of(grid.columns) {
+            return self;
+        }
+        Self {
+            selected: self.selected - 1,
+            ..self
+        }
+    }
+
+    pub fn right(self, grid: GridSize, len: usize) -> Self {
+        if self.selected % grid.columns == grid.columns - 1 || self.start + self.selected + 1 >= len
+        {
+            return self;
+        }
+        Self {
+            selected: self.selected + 1,
+            ..self
+        }
+    }
+
+    /// moves to the row above, scrolling when on the first row of the page
+    pub fn up(self, grid: GridSize) -> Self {
+        if self.selected >= grid.columns {
+            Self {
+                selected: self.selected - grid.columns,
+                ..self
+            }
+        } else {
+            Self {
+                start: self.start.saturating_sub(grid.columns),
+                ..self
+            }
+        }
+    }
+
+    /// moves to the row below (the last card if the row is shorter),
+    /// scrolling when on the last row of the page
+    pub fn down(self, grid: GridSize, len: usize) -> Self {
+        let absolute = self.start + self.selected;
+        let next_row_start = absolute - absolute % grid.columns + grid.columns;
+        if next_row_start >= len {
+            return self;
+        }
+        let target = (absolute + grid.columns).min(len - 1);
+        let start = if self.selected + grid.columns < grid.page_len() {
+            self.start
+        } else {
+            self.start + grid.columns
+        };
+        Self {
+            start,
+            selected: target - start,
+        }
+    }
 }
+
+/// What a mouse click on an area drawn by the ui selects
 #[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ClickTarget {
+    SearchInput,
+    // library.selected_index of the side menu item
+    MenuItem(usize),
+    TopThree(usize),
+    // tab of the current view, in display order
+    Tab(usize),
+    // card position in the page, or the row in the table layout
+    Card(usize),
+    // option of the open popup, and of its second list (the year of the seasonal popup)
+    PopupOption(usize),
+    PopupSideOption(usize),
+    // widget of the home dashboard (in the configured order) or day of the calendar, and its entry
+    Entry(usize, usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize, Serialize)]
 pub enum SelectedSearchTab {
     Anime,
     Manga,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SearchResult {
     pub anime: Option<Page<Anime>>,
     pub manga: Option<Page<Manga>>,
@@ -170,7 +353,7 @@ pub struct Library {
     pub saved_manga: ScrollablePages<Page<Manga>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Navigator {
     pub history: Vec<u16>,
     pub index: usize,
@@ -277,11 +460,14 @@ pub struct App {
     pub input_idx: usize,
     pub library: Library,
     pub help_menu_offset: u32,
-    pub help_menu_page: u32,
-    pub help_menu_max_lines: u32,
-    pub help_docs_size: u32,
+    // context the help screen was opened from
+    pub help_context: KeyContext,
+    pub help_show_all: bool,
+    pub help_filter: FuzzyFilter,
     // logger:
     pub logger_state: TuiWidgetState,
+    // auth:
+    pub is_anonymous: bool,
     // exit:
     pub exit_flag: bool,
     pub exit_confirmation_popup: bool,
@@ -335,12 +521,54 @@ pub struct App {
     pub manga_ranking_type_index: u8,
     //profile:
     pub user_profile: Option<UserInfo>,
+    // computed from the whole manga list, the api doesn't provide them
+    pub manga_statistics: Option<MangaStatistics>,
+    // show manga statistics instead of anime ones
+    pub profile_show_manga: bool,
+    pub stats_dashboard: Option<AnimeDashboard>,
+    // index in DASHBOARD_TABS
+    pub stats_dashboard_tab: usize,
+    pub schedule: Option<Schedule>,
+    // column of the weekly calendar, Monday first
+    pub schedule_day: usize,
     // use UserWatchStatus to determine the current tab
     pub anime_list_status: Option<UserWatchStatus>,
     // use UserReadStatus to determine the current tab
     pub manga_list_status: Option<UserReadStatus>,
+    // owner of the displayed list, None is the logged in user
+    pub list_user: Option<String>,
+    // compare another user's list with mine
+    pub compare_mode: bool,
+    pub list_my_scores: HashMap<u64, u8>,
+    pub comparison: Option<Comparison>,
+    // selected row in LIST_VIEW_OPTIONS
+    pub list_view_popup_index: usize,
+    // narrows the cards of the current view, cleared when the route changes
+    pub fuzzy_filter: FuzzyFilter,
+    // views switched away from the default layout (cards or table)
+    pub toggled_layout_views: HashSet<ActiveDisplayBlock>,
+    // clickable areas of the last drawn frame, the ui only has a shared reference
+    pub click_areas: RefCell<Vec<(Rect, ClickTarget)>>,
+    // area of the last drawn card grid, the handlers move in the same grid
+    pub grid_area: Cell<Option<Rect>>,
     // to track pagination (with local data)
     pub start_card_list_index: u16,
+    pub palette: Palette,
+    pub bookmarks: Bookmarks,
+    pub history: History,
+    pub home: Home,
+    // outcome of the last action, shown in the title bar until the next key
+    pub status_message: Option<String>,
+    // last background check of new episodes
+    pub last_episode_check: Option<Instant>,
+    // title whose pending update completes it, its score is asked for once it succeeds
+    pub score_prompt: Option<(MediaKind, u64)>,
+    // marked entries of my list and the bulk edit popup, cleared when the route changes
+    pub bulk: BulkEdit,
+    // the calls of a bulk edit go on after its route is left, one runs at a time
+    pub bulk_running: bool,
+    // changes of my list, to undo them
+    pub list_changes: ListChanges,
 }
 #[derive(Debug, Clone)]
 pub enum DetailPopup {
@@ -413,7 +641,7 @@ pub struct TopThreeAnime {
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum Data {
     SearchResult(SearchResult),
     Suggestions(SearchResult),
@@ -424,20 +652,35 @@ pub enum Data {
     UserMangaList(UserMangaList),
     AnimeRanking(Ranking<RankingAnimePair>),
     MangaRanking(Ranking<RankingMangaPair>),
+    Comparison(Comparison),
+    StatsDashboard(AnimeDashboard),
+    // the bookmarks themselves live in the app, they change outside of the route
+    Bookmarks,
+    // same for the viewed history
+    History,
+    // and the changes of my list
+    ListChanges,
+    Schedule(Schedule),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UserAnimeList {
     pub anime_list: Page<Anime>,
     pub status: Option<UserWatchStatus>,
+    // None is the logged in user
+    pub user: Option<String>,
+    // my scores for the entries of another user's list
+    pub my_scores: HashMap<u64, u8>,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UserMangaList {
     pub manga_list: Page<Manga>,
     pub status: Option<UserReadStatus>,
+    pub user: Option<String>,
+    pub my_scores: HashMap<u64, u8>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Route {
     pub data: Option<Data>,
     pub block: ActiveDisplayBlock,
@@ -457,6 +700,15 @@ impl App {
         if picker_res.is_ok() {
             picker = Some(picker_res.unwrap());
         }
+        let bookmarks =
+            Bookmarks::load(&app_config.paths.bookmarks_file_path).unwrap_or_else(|e| {
+                warn!("Could not read the bookmarks: {}", e);
+                Bookmarks::default()
+            });
+        let history = History::load(&app_config.paths.history_file_path).unwrap_or_else(|e| {
+            warn!("Could not read the viewed history: {}", e);
+            History::default()
+        });
         Self {
             io_tx: Some(io_tx),
             anime_season: Seasonal {
@@ -493,12 +745,12 @@ impl App {
             library: Library {
                 saved_anime: ScrollablePages::new(),
                 saved_manga: ScrollablePages::new(),
-                selected_index: 9, // out of range to show nothing
+                selected_index: NO_MENU_SELECTION,
             },
             help_menu_offset: 0,
-            help_menu_page: 0,
-            help_menu_max_lines: 0,
-            help_docs_size: 0,
+            help_context: KeyContext::Global,
+            help_show_all: false,
+            help_filter: FuzzyFilter::default(),
             active_block: ActiveBlock::DisplayBlock,
             active_display_block: DEFAULT_ROUTE.block,
             navigator: Navigator::new(),
@@ -547,6 +799,33 @@ impl App {
             manga_details_info_scroll_view_state: ScrollViewState::default(),
             manga_details_synopsys_scroll_view_state: ScrollViewState::default(),
             start_card_list_index: 0,
+            manga_statistics: None,
+            profile_show_manga: false,
+            stats_dashboard: None,
+            stats_dashboard_tab: 0,
+            schedule: None,
+            schedule_day: 0,
+            list_user: None,
+            compare_mode: false,
+            list_my_scores: HashMap::new(),
+            comparison: None,
+            list_view_popup_index: 0,
+            fuzzy_filter: FuzzyFilter::default(),
+            toggled_layout_views: HashSet::new(),
+            click_areas: RefCell::new(Vec::new()),
+            grid_area: Cell::new(None),
+            palette: Palette::default(),
+            bookmarks,
+            history,
+            home: Home::default(),
+            status_message: None,
+            last_episode_check: None,
+            score_prompt: None,
+            bulk: BulkEdit::default(),
+            bulk_running: false,
+            list_changes: ListChanges::default(),
+            // auth:
+            is_anonymous: false,
             // exit:
             exit_flag: false,
             exit_confirmation_popup: false,
@@ -626,6 +905,8 @@ impl App {
 
     pub fn push_navigation_stack(&mut self, r: Route) {
         self.clear_route_before_push();
+        self.fuzzy_filter = FuzzyFilter::default();
+        self.bulk = BulkEdit::default();
         self.navigator.add_route(r);
         self.remove_old_history();
     }
@@ -658,19 +939,18 @@ impl App {
         }
     }
 
-    pub fn calculate_help_menu_offset(&mut self) {
-        let old_offset = self.help_menu_offset;
-        if self.help_menu_max_lines < self.help_docs_size {
-            self.help_menu_offset = self.help_menu_page * self.help_menu_max_lines;
-        }
-        if self.help_menu_offset > self.help_docs_size {
-            self.help_menu_offset = old_offset;
-            self.help_menu_page -= 1;
-        }
-    }
-
     pub fn load_previous_route(&mut self) {
+        if self.bulk.popup.take().is_some() {
+            return;
+        }
         if self.popup {
+            // the popup of the user lists is their sort & filter one
+            if matches!(
+                self.active_display_block,
+                ActiveDisplayBlock::UserAnimeList | ActiveDisplayBlock::UserMangaList
+            ) {
+                self.save_list_views();
+            }
             // reset everything
             self.popup = false;
             self.result_popup = false;
@@ -680,9 +960,12 @@ impl App {
         }
 
         if self.navigator.index == 1 {
+            self.fuzzy_filter = FuzzyFilter::default();
+            self.bulk = BulkEdit::default();
             self.active_display_block = ActiveDisplayBlock::Empty;
             self.display_block_title = "Home".to_string();
             self.navigator.index = 0;
+            self.refresh_home();
             return;
         }
 
@@ -721,6 +1004,28 @@ impl App {
         self.load_state_data(self.navigator.index + 1);
     }
 
+    /// puts back the routes of a saved session and shows the one it was on
+    pub fn restore_navigator(&mut self, mut navigator: Navigator) {
+        if !navigator.validate_state() {
+            return;
+        }
+        for route in navigator.data.values_mut() {
+            // pictures are read from the cache, which may have been cleared since
+            let missing = route
+                .image
+                .as_ref()
+                .is_some_and(|(path, _, _)| !std::path::Path::new(path).exists());
+            if self.picker.is_none() || missing {
+                route.image = None;
+            }
+        }
+        let index = navigator.index;
+        self.navigator = navigator;
+        if index > 0 {
+            self.load_state_data(index);
+        }
+    }
+
     pub fn load_route(&mut self, id: u16) {
         self.push_existing_route(id);
         self.load_state_data(self.navigator.history.len() - 1);
@@ -747,6 +1052,8 @@ impl App {
             return;
         }
         self.navigator.index = i;
+        self.fuzzy_filter = FuzzyFilter::default();
+        self.bulk = BulkEdit::default();
         let route = match self.get_current_route() {
             Some(route) => route.clone(),
             None => return,
@@ -805,12 +1112,26 @@ impl App {
 
                     Data::UserAnimeList(d) => {
                         self.anime_list_status = d.status.clone();
-                        self.search_results.anime = Some(d.anime_list.clone());
+                        self.list_user = d.user.clone();
+                        self.list_my_scores = d.my_scores.clone();
+                        self.search_results.anime =
+                            Some(self.app_config.list_views.anime.apply(&d.anime_list));
                     }
 
+                    Data::Comparison(d) => self.comparison = Some(d.clone()),
+
+                    Data::StatsDashboard(d) => self.stats_dashboard = Some(d.clone()),
+
+                    Data::Schedule(d) => self.schedule = Some(d.clone()),
+
+                    Data::Bookmarks | Data::History | Data::ListChanges => {}
+
                     Data::UserMangaList(d) => {
                         self.manga_list_status = d.status.clone();
-                        self.search_results.manga = Some(d.manga_list.clone());
+                        self.list_user = d.user.clone();
+                        self.list_my_scores = d.my_scores.clone();
+                        self.search_results.manga =
+                            Some(self.app_config.list_views.manga.apply(&d.manga_list));
                     }
                 }
 
@@ -854,6 +1175,20 @@ impl App {
         }
     }
 
+    pub fn next_manga_list_status(&self) -> Option<UserReadStatus> {
+        match &self.manga_list_status {
+            Some(s) => match s {
+                UserReadStatus::Reading => Some(UserReadStatus::Completed),
+                UserReadStatus::Completed => Some(UserReadStatus::OnHold),
+                UserReadStatus::OnHold => Some(UserReadStatus::Dropped),
+                UserReadStatus::Dropped => Some(UserReadStatus::PlanToRead),
+                UserReadStatus::PlanToRead => None,
+                UserReadStatus::Other(_) => None,
+            },
+            None => Some(UserReadStatus::Reading),
+        }
+    }
+
     pub fn get_picture_from_cache(&self) -> Result<DynamicImage, ImageError> {
         // all images are stored in $HOME?/.cache/mal-cli/images/
         let file_name = self.media_image.as_ref().unwrap().0.clone();
@@ -861,6 +1196,743 @@ impl App {
         let image = image::ImageReader::open(file_path)?.decode()?;
         Ok(image)
     }
+    /// removes the downloaded pictures, routes forget theirs to not load them again
+    pub fn clear_image_cache(&mut self) -> std::io::Result<usize> {
+        let mut removed = 0;
+        for entry in std::fs::read_dir(&self.app_config.paths.picture_cache_dir_path)? {
+            std::fs::remove_file(entry?.path())?;
+            removed += 1;
+        }
+        for route in self.navigator.data.values_mut() {
+            route.image = None;
+        }
+        self.media_image = None;
+        self.image_state = None;
+        Ok(removed)
+    }
+
+    /// bookmarks the title of the opened detail page, or removes its bookmark
+    pub fn toggle_bookmark(&mut self) {
+        let title_language = &self.app_config.title_language;
+        let (kind, id, title) = match self.active_display_block {
+            ActiveDisplayBlock::AnimeDetails => match &self.anime_details {
+                Some(anime) => (
+                    MediaKind::Anime,
+                    anime.id,
+                    anime.get_title(title_language, false)[0].clone(),
+                ),
+                None => return,
+            },
+            ActiveDisplayBlock::MangaDetails => match &self.manga_details {
+                Some(manga) => (
+                    MediaKind::Manga,
+                    manga.id,
+                    manga.get_title(title_language, false)[0].clone(),
+                ),
+                None => return,
+            },
+            _ => return,
+        };
+        let added = self.bookmarks.toggle(Bookmark {
+            kind,
+            id,
+            title: title.clone(),
+            note: String::new(),
+            group: None,
+        });
+        self.status_message = Some(match self.save_bookmarks() {
+            Ok(()) if added => format!("Bookmarked {}", title),
+            Ok(()) => format!("Removed the bookmark of {}", title),
+            Err(e) => e,
+        });
+    }
+
+    /// one more episode or chapter of the detail page, or of the selected card of my list
+    pub fn increment_progress(&mut self) {
+        if self.is_anonymous {
+            self.status_message = Some("Login to update your list".to_string());
+            return;
+        }
+        let today = progress::today();
+        let policy = &self.app_config.list_updates;
+        let index = self.search_results.selected_display_card_index.unwrap_or(0)
+            + self.start_card_list_index as usize;
+        // other users' lists show their statuses, not mine
+        let my_list = self.list_user.is_none();
+        let anime = match self.active_display_block {
+            ActiveDisplayBlock::AnimeDetails => self.anime_details.as_ref(),
+            ActiveDisplayBlock::UserAnimeList if my_list => self
+                .search_results
+                .anime
+                .as_ref()
+                .and_then(|page| page.data.get(index))
+                .map(|node| &node.node),
+            _ => None,
+        };
+        let manga = match self.active_display_block {
+            ActiveDisplayBlock::MangaDetails => self.manga_details.as_ref(),
+            ActiveDisplayBlock::UserMangaList if my_list => self
+                .search_results
+                .manga
+                .as_ref()
+                .and_then(|page| page.data.get(index))
+                .map(|node| &node.node),
+            _ => None,
+        };
+        let update = if let Some(anime) = anime {
+            progress::next_episode(policy, anime, today).map(|(query, ask_score)| {
+                (
+                    (MediaKind::Anime, anime.id),
+                    IoEvent::PatchAnimeListStatus(anime.id, query),
+                    ask_score,
+                )
+            })
+        } else if let Some(manga) = manga {
+            progress::next_chapter(policy, manga, today).map(|(query, ask_score)| {
+                (
+                    (MediaKind::Manga, manga.id),
+                    IoEvent::PatchMangaListStatus(manga.id, query),
+                    ask_score,
+                )
+            })
+        } else {
+            return;
+        };
+        match update {
+            Some((title, event, ask_score)) => {
+                self.score_prompt = ask_score.then_some(title);
+                self.dispatch(event);
+            }
+            None => self.status_message = Some("Already at the last one".to_string()),
+        }
+    }
+
+    /// opens the score popup of a title its update just completed, when the policy asks for it
+    pub fn open_score_prompt(&mut self, kind: MediaKind, id: u64) {
+        if self.score_prompt != Some((kind, id)) {
+            return;
+        }
+        self.score_prompt = None;
+        let on_its_page = match kind {
+            MediaKind::Anime => {
+                self.active_display_block == ActiveDisplayBlock::AnimeDetails
+                    && self.anime_details.as_ref().is_some_and(|a| a.id == id)
+            }
+            MediaKind::Manga => {
+                self.active_display_block == ActiveDisplayBlock::MangaDetails
+                    && self.manga_details.as_ref().is_some_and(|m| m.id == id)
+            }
+        };
+        if !on_its_page {
+            self.status_message = Some("Completed, rate it from its page".to_string());
+            return;
+        }
+        match kind {
+            MediaKind::Anime => self.active_anime_detail_block = ActiveAnimeDetailBlock::Rate,
+            MediaKind::Manga => self.active_manga_detail_block = ActiveMangaDetailBlock::Rate,
+        }
+        self.active_detail_popup = DetailPopup::Rate;
+        self.selected_popup_rate = 0;
+        self.result_popup = false;
+        self.popup = true;
+        self.status_message = Some("Completed, how would you rate it?".to_string());
+    }
+
+    /// my list status of a title changed on MAL, shown wherever the title is
+    pub fn apply_anime_list_status(&mut self, id: u64, status: UserAnimeListStatus) {
+        let set = |anime: &mut Anime| {
+            if anime.id == id {
+                anime.my_list_status = Some(status.clone());
+            }
+        };
+        if let Some(anime) = self.anime_details.as_mut() {
+            set(anime);
+        }
+        // the cards of another user's list show their status
+        let showing_other_list = self.list_user.is_some()
+            && self.active_display_block == ActiveDisplayBlock::UserAnimeList;
+        if let Some(page) = self
+            .search_results
+            .anime
+            .as_mut()
+            .filter(|_| !showing_other_list)
+        {
+            page.data.iter_mut().for_each(|node| set(&mut node.node));
+        }
+        for route in self.navigator.data.values_mut() {
+            match &mut route.data {
+                Some(Data::Anime(anime)) => set(anime),
+                Some(Data::UserAnimeList(list)) if list.user.is_none() => list
+                    .anime_list
+                    .data
+                    .iter_mut()
+                    .for_each(|node| set(&mut node.node)),
+                _ => {}
+            }
+        }
+    }
+
+    pub fn apply_manga_list_status(&mut self, id: u64, status: UserMangaListStatus) {
+        let set = |manga: &mut Manga| {
+            if manga.id == id {
+                manga.my_list_status = Some(status.clone());
+            }
+        };
+        if let Some(manga) = self.manga_details.as_mut() {
+            set(manga);
+        }
+        let showing_other_list = self.list_user.is_some()
+            && self.active_display_block == ActiveDisplayBlock::UserMangaList;
+        if let Some(page) = self
+            .search_results
+            .manga
+            .as_mut()
+            .filter(|_| !showing_other_list)
+        {
+            page.data.iter_mut().for_each(|node| set(&mut node.node));
+        }
+        for route in self.navigator.data.values_mut() {
+            match &mut route.data {
+                Some(Data::Manga(manga)) => set(manga),
+                Some(Data::UserMangaList(list)) if list.user.is_none() => list
+                    .manga_list
+                    .data
+                    .iter_mut()
+                    .for_each(|node| set(&mut node.node)),
+                _ => {}
+            }
+        }
+    }
+
+    /// a title was deleted from my list on MAL
+    pub fn remove_anime_from_list(&mut self, id: u64) {
+        let unset = |anime: &mut Anime| {
+            if anime.id == id {
+                anime.my_list_status = None;
+            }
+        };
+        if let Some(anime) = self.anime_details.as_mut() {
+            unset(anime);
+        }
+        for route in self.navigator.data.values_mut() {
+            match &mut route.data {
+                Some(Data::Anime(anime)) => unset(anime),
+                Some(Data::UserAnimeList(list)) if list.user.is_none() => {
+                    list.anime_list.data.retain(|node| node.node.id != id)
+                }
+                _ => {}
+            }
+        }
+        match self.active_display_block {
+            // the cards of my list come from the route, sorted and filtered again
+            ActiveDisplayBlock::UserAnimeList if self.list_user.is_none() => {
+                self.apply_fuzzy_filter()
+            }
+            ActiveDisplayBlock::UserAnimeList => {}
+            _ => {
+                if let Some(page) = self.search_results.anime.as_mut() {
+                    page.data.iter_mut().for_each(|node| unset(&mut node.node));
+                }
+            }
+        }
+    }
+
+    pub fn remove_manga_from_list(&mut self, id: u64) {
+        let unset = |manga: &mut Manga| {
+            if manga.id == id {
+                manga.my_list_status = None;
+            }
+        };
+        if let Some(manga) = self.manga_details.as_mut() {
+            unset(manga);
+        }
+        for route in self.navigator.data.values_mut() {
+            match &mut route.data {
+                Some(Data::Manga(manga)) => unset(manga),
+                Some(Data::UserMangaList(list)) if list.user.is_none() => {
+                    list.manga_list.data.retain(|node| node.node.id != id)
+                }
+                _ => {}
+            }
+        }
+        match self.active_display_block {
+            ActiveDisplayBlock::UserMangaList if self.list_user.is_none() => {
+                self.apply_fuzzy_filter()
+            }
+            ActiveDisplayBlock::UserMangaList => {}
+            _ => {
+                if let Some(page) = self.search_results.manga.as_mut() {
+                    page.data.iter_mut().for_each(|node| unset(&mut node.node));
+                }
+            }
+        }
+    }
+
+    /// the title with my list status, wherever it was loaded
+    fn find_my_anime(&self, id: u64) -> Option<&Anime> {
+        // the cards of another user's list show their status
+        let showing_other_list = self.list_user.is_some()
+            && self.active_display_block == ActiveDisplayBlock::UserAnimeList;
+        let cards = self
+            .search_results
+            .anime
+            .iter()
+            .filter(|_| !showing_other_list)
+            .flat_map(|page| page.data.iter().map(|node| &node.node));
+        let routes = self
+            .navigator
+            .data
+            .values()
+            .flat_map(|route| match &route.data {
+                Some(Data::Anime(anime)) => vec![anime],
+                Some(Data::UserAnimeList(list)) if list.user.is_none() => {
+                    list.anime_list.data.iter().map(|node| &node.node).collect()
+                }
+                _ => vec![],
+            });
+        self.anime_details
+            .iter()
+            .chain(cards)
+            .chain(routes)
+            .find(|anime| anime.id == id)
+    }
+
+    fn find_my_manga(&self, id: u64) -> Option<&Manga> {
+        let showing_other_list = self.list_user.is_some()
+            && self.active_display_block == ActiveDisplayBlock::UserMangaList;
+        let cards = self
+            .search_results
+            .manga
+            .iter()
+            .filter(|_| !showing_other_list)
+            .flat_map(|page| page.data.iter().map(|node| &node.node));
+        let routes = self
+            .navigator
+            .data
+            .values()
+            .flat_map(|route| match &route.data {
+                Some(Data::Manga(manga)) => vec![manga],
+                Some(Data::UserMangaList(list)) if list.user.is_none() => {
+                    list.manga_list.data.iter().map(|node| &node.node).collect()
+                }
+                _ => vec![],
+            });
+        self.manga_details
+            .iter()
+            .chain(cards)
+            .chain(routes)
+            .find(|manga| manga.id == id)
+    }
+
+    /// keeps the entry as it was before a change went through, call it before applying the change
+    pub fn record_anime_change(
+        &mut self,
+        id: u64,
+        applied: Option<UpdateUserAnimeListStatusQuery>,
+    ) {
+        let Some(anime) = self.find_my_anime(id) else {
+            warn!("Anime {} is not loaded, its change can't be undone", id);
+            return;
+        };
+        let (title, before) = (anime.title.clone(), anime.my_list_status.clone());
+        let now = chrono::Utc::now().timestamp();
+        self.list_changes
+            .record(id, title, Change::Anime { before, applied }, now);
+    }
+
+    pub fn record_manga_change(&mut self, id: u64, applied: Option<UpdateUserMangaStatus>) {
+        let Some(manga) = self.find_my_manga(id) else {
+            warn!("Manga {} is not loaded, its change can't be undone", id);
+            return;
+        };
+        let (title, before) = (manga.title.clone(), manga.my_list_status.clone());
+        let now = chrono::Utc::now().timestamp();
+        self.list_changes
+            .record(id, title, Change::Manga { before, applied }, now);
+    }
+
+    /// sends the inverse of a change of the list changes, 0 is the last one
+    pub fn revert_list_change(&mut self, index: usize) {
+        if self.is_anonymous {
+            self.status_message = Some("Login to update your list".to_string());
+            return;
+        }
+        match self.list_changes.take(index) {
+            Some(change) => {
+                self.status_message = Some(format!("Undoing the change of {}", change.title));
+                self.dispatch(IoEvent::RevertListChange(change));
+            }
+            None => self.status_message = Some("Nothing to undo".to_string()),
+        }
+    }
+
+    /// id of the selected card of my list, the ones a bulk edit can mark
+    pub fn selected_my_list_entry(&self) -> Option<u64> {
+        if self.list_user.is_some() || self.is_anonymous {
+            return None;
+        }
+        let index = self.search_results.selected_display_card_index.unwrap_or(0)
+            + self.start_card_list_index as usize;
+        match self.active_display_block {
+            ActiveDisplayBlock::UserAnimeList => self
+                .search_results
+                .anime
+                .as_ref()
+                .and_then(|page| page.data.get(index))
+                .map(|node| node.node.id),
+            ActiveDisplayBlock::UserMangaList => self
+                .search_results
+                .manga
+                .as_ref()
+                .and_then(|page| page.data.get(index))
+                .map(|node| node.node.id),
+            _ => None,
+        }
+    }
+
+    /// sends the list calls of a bulk action on the marked entries of my list
+    pub fn start_bulk(&mut self, action: BulkAction) {
+        let today = progress::today();
+        let policy = &self.app_config.list_updates;
+        // the whole list of the route, marked entries may be filtered out of the cards
+        let requests = match self.get_current_route().and_then(|r| r.data.as_ref()) {
+            Some(Data::UserAnimeList(list)) => {
+                let marked: Vec<&Anime> = list
+                    .anime_list
+                    .data
+                    .iter()
+                    .map(|node| &node.node)
+                    .filter(|anime| self.bulk.is_marked(anime.id))
+                    .collect();
+                bulk::anime_requests(&action, &marked, policy, today)
+            }
+            Some(Data::UserMangaList(list)) => {
+                let marked: Vec<&Manga> = list
+                    .manga_list
+                    .data
+                    .iter()
+                    .map(|node| &node.node)
+                    .filter(|manga| self.bulk.is_marked(manga.id))
+                    .collect();
+                bulk::manga_requests(&action, &marked, policy, today)
+            }
+            _ => vec![],
+        };
+        if requests.is_empty() {
+            self.bulk.popup = None;
+            self.status_message = Some("The marked entries already have it".to_string());
+            return;
+        }
+        self.bulk.open(BulkPopup::Running(BulkProgress {
+            total: requests.len(),
+            ..Default::default()
+        }));
+        self.bulk_running = true;
+        self.dispatch(IoEvent::RunBulk(requests));
+    }
+
+    /// entries the home widget lists, once fetched
+    pub fn home_widget_len(&self, widget: HomeWidget) -> usize {
+        let len = |entries: &Option<Vec<Anime>>| entries.as_ref().map_or(0, Vec::len);
+        match widget {
+            HomeWidget::Watching => len(&self.home.watching),
+            HomeWidget::AiringThisSeason => len(&self.home.airing),
+            HomeWidget::RecentUpdates => len(&self.home.recent_updates),
+            HomeWidget::RecentlyViewed => self
+                .history
+                .entries
+                .len()
+                .min(self.app_config.home.entries_per_widget as usize),
+            HomeWidget::QuickActions => self.home_quick_actions().len(),
+        }
+    }
+
+    /// fetches the home widget entries again, the list may have changed since
+    pub fn refresh_home(&mut self) {
+        let needs_list = self.app_config.home.widgets.iter().any(|widget| {
+            matches!(
+                widget,
+                HomeWidget::Watching | HomeWidget::AiringThisSeason | HomeWidget::RecentUpdates
+            )
+        });
+        if needs_list && !self.is_anonymous {
+            self.dispatch(IoEvent::GetHomeDashboard);
+        }
+    }
+
+    /// checks the watching list for new episodes when the interval has passed
+    pub fn check_new_episodes(&mut self) {
+        let notifications = &self.app_config.notifications;
+        if !notifications.enabled || self.is_anonymous {
+            return;
+        }
+        let interval = notifications.check_interval();
+        if self
+            .last_episode_check
+            .is_some_and(|last| last.elapsed() < interval)
+        {
+            return;
+        }
+        self.last_episode_check = Some(Instant::now());
+        self.dispatch(IoEvent::CheckNewEpisodes);
+    }
+
+    pub fn home_quick_actions(&self) -> Vec<Command> {
+        home::quick_actions(get_season(), get_year(), self.is_anonymous)
+    }
+
+    /// logs an opened detail page, the history is saved right away
+    pub fn record_viewed(
+        &mut self,
+        kind: MediaKind,
+        id: u64,
+        title: String,
+        thumbnail: Option<&Picture>,
+    ) {
+        self.history.record(
+            Viewed {
+                kind,
+                id,
+                title,
+                thumbnail: thumbnail.and_then(|p| p.medium.clone()),
+                viewed_at: chrono::Utc::now().timestamp(),
+            },
+            self.app_config.history.limit,
+        );
+        if let Err(e) = self.save_history() {
+            warn!("{}", e);
+        }
+    }
+
+    pub fn save_history(&self) -> Result<(), String> {
+        self.history
+            .save(&self.app_config.paths.history_file_path)
+            .map_err(|e| format!("Could not save the viewed history: {}", e))
+    }
+
+    pub fn save_bookmarks(&self) -> Result<(), String> {
+        self.bookmarks
+            .save(&self.app_config.paths.bookmarks_file_path)
+            .map_err(|e| format!("Could not save the bookmarks: {}", e))
+    }
+
+    /// writes the user list of the current route as json in the export dir, one file per
+    /// user, media and status tab
+    pub fn export_current_list(&self) -> Result<String, String> {
+        let (content, user, media, status) =
+            match self.get_current_route().and_then(|r| r.data.as_ref()) {
+                Some(Data::UserAnimeList(d)) => (
+                    serde_json::to_string_pretty(&d.anime_list),
+                    d.user.clone(),
+                    "anime",
+                    d.status.as_ref().map(|s| s.to_string()),
+                ),
+                Some(Data::UserMangaList(d)) => (
+                    serde_json::to_string_pretty(&d.manga_list),
+                    d.user.clone(),
+                    "manga",
+                    d.status.as_ref().map(|s| s.to_string()),
+                ),
+                _ => return Err("Open an anime or manga list to export it".to_string()),
+            };
+        let content = content.map_err(|e| e.to_string())?;
+        let dir = &self.app_config.paths.export_dir_path;
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        let path = dir.join(format!(
+            "{}_{}_{}.json",
+            user.as_deref().unwrap_or("my"),
+            media,
+            status.as_deref().unwrap_or("all")
+        ));
+        std::fs::write(&path, content).map_err(|e| e.to_string())?;
+        Ok(path.display().to_string())
+    }
+
+    /// sorts and filters the loaded user list of the current route again,
+    /// after its list view changed
+    pub fn apply_list_view(&mut self) {
+        if !matches!(
+            self.get_current_route().and_then(|r| r.data.as_ref()),
+            Some(Data::UserAnimeList(_) | Data::UserMangaList(_))
+        ) {
+            return;
+        }
+        self.apply_fuzzy_filter();
+    }
+
+    /// writes the list views to the config, once the sort & filter popup is closed
+    pub fn save_list_views(&self) {
+        if let Err(e) = AppConfig::save_setting("list_views", &self.app_config.list_views) {
+            warn!("Could not save the list view: {}", e);
+        }
+    }
+
+    /// narrows the cards of the current route to the ones matching the filter line,
+    /// user lists are sorted and filtered by their list view first
+    pub fn apply_fuzzy_filter(&mut self) {
+        let query = self.fuzzy_filter.query.as_str();
+        match self.get_current_route().and_then(|r| r.data.as_ref()) {
+            Some(Data::SearchResult(d) | Data::Suggestions(d)) => {
+                let anime = d.anime.as_ref().map(|p| fuzzy::filter_page(p, query));
+                let manga = d.manga.as_ref().map(|p| fuzzy::filter_page(p, query));
+                self.search_results.anime = anime;
+                self.search_results.manga = manga;
+            }
+            Some(Data::UserAnimeList(d)) => {
+                let anime = self.app_config.list_views.anime.apply(&d.anime_list);
+                self.search_results.anime = Some(fuzzy::filter_page(&anime, query));
+            }
+            Some(Data::UserMangaList(d)) => {
+                let manga = self.app_config.list_views.manga.apply(&d.manga_list);
+                self.search_results.manga = Some(fuzzy::filter_page(&manga, query));
+            }
+            Some(Data::AnimeRanking(d)) => {
+                self.anime_ranking_data = Some(fuzzy::filter_page(d, query));
+            }
+            Some(Data::MangaRanking(d)) => {
+                self.manga_ranking_data = Some(fuzzy::filter_page(d, query));
+            }
+            // the history view narrows the entries itself
+            Some(Data::History) => {}
+            _ => return,
+        }
+        self.reset_result_index();
+    }
+
+    /// section of the keymap the next key press is looked up in
+    pub fn key_context(&self) -> KeyContext {
+        let in_display_block = self.active_block == ActiveBlock::DisplayBlock;
+        if self.active_block == ActiveBlock::Input
+            || self.palette.open
+            || (in_display_block && (self.fuzzy_filter.editing || self.bulk.is_typing()))
+            || (self.active_display_block == ActiveDisplayBlock::Help && self.help_filter.editing)
+        {
+            return KeyContext::Input;
+        }
+        if self.exit_confirmation_popup || self.popup || self.bulk.popup.is_some() {
+            return KeyContext::Popup;
+        }
+        if !in_display_block {
+            return KeyContext::Global;
+        }
+        self.display_key_context()
+    }
+
+    /// section of the keymap the display block uses once it is focused
+    pub fn display_key_context(&self) -> KeyContext {
+        match self.active_display_block {
+            ActiveDisplayBlock::AnimeDetails | ActiveDisplayBlock::MangaDetails => {
+                KeyContext::Details
+            }
+            ActiveDisplayBlock::SearchResultBlock
+            | ActiveDisplayBlock::Suggestions
+            | ActiveDisplayBlock::Seasonal
+            | ActiveDisplayBlock::AnimeRanking
+            | ActiveDisplayBlock::MangaRanking
+            | ActiveDisplayBlock::UserAnimeList
+            | ActiveDisplayBlock::UserMangaList
+            | ActiveDisplayBlock::History => KeyContext::Grid,
+            _ => KeyContext::Global,
+        }
+    }
+
+    /// rows of the help screen: description, keys and context,
+    /// narrowed by the help filter
+    pub fn help_rows(&self) -> Vec<[String; 3]> {
+        let context = (!self.help_show_all).then_some(self.help_context);
+        let query = self.help_filter.query.as_str();
+        self.app_config
+            .keymap
+            .bindings(context)
+            .into_iter()
+            .filter(|(_, _, keys)| !keys.is_empty())
+            .map(|(context, action, keys)| {
+                let context: &str = context.into();
+                [
+                    action.description().to_string(),
+                    keys.iter()
+                        .map(|k| k.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    context.to_string(),
+                ]
+            })
+            .filter(|row| {
+                query.is_empty() || row.iter().any(|c| fuzzy::fuzzy_score(query, c).is_some())
+            })
+            .collect()
+    }
+
+    /// commands of the palette matching its query
+    pub fn palette_commands(&self) -> Vec<Command> {
+        palette::matching_commands(&self.palette.query, get_year(), self.is_anonymous)
+    }
+
+    /// whether `key` is bound to `action` in the current context
+    pub fn is_action(&self, action: Action, key: Key) -> bool {
+        self.app_config
+            .keymap
+            .matches(self.key_context(), action, key)
+    }
+
+    /// key to replay an action in the current context
+    pub fn action_key(&self, action: Action) -> Option<Key> {
+        self.app_config.keymap.first_key(self.key_context(), action)
+    }
+
+    /// whether the current view shows a table instead of cards
+    pub fn is_table_layout(&self) -> bool {
+        self.app_config.table_layout.by_default
+            != self
+                .toggled_layout_views
+                .contains(&self.active_display_block)
+    }
+
+    pub fn toggle_layout(&mut self) {
+        let view = self.active_display_block;
+        if !self.toggled_layout_views.remove(&view) {
+            self.toggled_layout_views.insert(view);
+        }
+        self.reset_result_index();
+    }
+
+    pub fn register_click_area(&self, area: Rect, target: ClickTarget) {
+        self.click_areas.borrow_mut().push((area, target));
+    }
+
+    /// target drawn last (on top) at a terminal cell
+    pub fn get_click_target(&self, column: u16, row: u16) -> Option<ClickTarget> {
+        self.click_areas
+            .borrow()
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(ratatui::layout::Position::new(column, row)))
+            .map(|(_, target)| *target)
+    }
+
+    /// cards fitting the area the ui drew the grid in
+    pub fn grid_size(&self) -> GridSize {
+        match self.grid_area.get() {
+            Some(area) => GridSize::from_area(area.width, area.height),
+            None => GridSize {
+                columns: DISPLAY_COLUMN_NUMBER,
+                rows: DISPLAY_RAWS_NUMBER,
+            },
+        }
+    }
+
+    pub fn grid_position(&self) -> GridPosition {
+        GridPosition {
+            start: self.start_card_list_index as usize,
+            selected: self.search_results.selected_display_card_index.unwrap_or(0),
+        }
+    }
+
+    pub fn set_grid_position(&mut self, position: GridPosition) {
+        self.start_card_list_index = position.start as u16;
+        self.search_results.selected_display_card_index = Some(position.selected);
+    }
+
     pub fn reset_result_index(&mut self) {
         // reset the selected index in the search results
         self.search_results.selected_display_card_index = Some(0);
@@ -868,7 +1940,7 @@ impl App {
     }
 }
 
-fn get_season() -> Season {
+pub fn get_season() -> Season {
     let month = chrono::Utc::now().month();
     match month {
         3..=5 => Season::Spring,
@@ -878,6 +1950,10 @@ fn get_season() -> Season {
     }
 }
 
+pub fn get_year() -> u16 {
+    chrono::Utc::now().year() as u16
+}
+
 fn get_selected_season(season: &Season) -> u8 {
     match *season {
         Season::Winter => 0,
@@ -917,6 +1993,123 @@ pub mod test {
         assert_eq!(app.navigator.index, 4);
     }
 
+    #[test]
+    fn test_click_target_topmost() {
+        let app = get_app();
+        app.register_click_area(Rect::new(0, 0, 40, 20), ClickTarget::Card(0));
+        // a popup drawn over the card
+        app.register_click_area(Rect::new(10, 5, 10, 1), ClickTarget::PopupOption(2));
+
+        assert_eq!(app.get_click_target(1, 1), Some(ClickTarget::Card(0)));
+        assert_eq!(
+            app.get_click_target(12, 5),
+            Some(ClickTarget::PopupOption(2))
+        );
+        assert_eq!(app.get_click_target(50, 5), None);
+    }
+
+    #[test]
+    fn test_toggle_layout_per_view() {
+        let mut app = get_app();
+        app.active_display_block = ActiveDisplayBlock::AnimeRanking;
+        let default = app.is_table_layout();
+        app.toggle_layout();
+        assert_eq!(app.is_table_layout(), !default);
+
+        // other views keep the default
+        app.active_display_block = ActiveDisplayBlock::Seasonal;
+        assert_eq!(app.is_table_layout(), default);
+
+        app.active_display_block = ActiveDisplayBlock::AnimeRanking;
+        app.toggle_layout();
+        assert_eq!(app.is_table_layout(), default);
+    }
+
+    #[test]
+    fn test_grid_navigation() {
+        let grid = GridSize::from_area(4 * CARD_MIN_WIDTH, 2 * CARD_MIN_HEIGHT);
+        assert_eq!(
+            grid,
+            GridSize {
+                columns: 4,
+                rows: 2
+            }
+        );
+        assert_eq!(grid.end_index(0, 3), 2);
+        assert_eq!(grid.end_index(4, 20), 11);
+
+        // the handlers use the grid the ui drew
+        let app = get_app();
+        app.grid_area.set(Some(Rect::new(
+            2,
+            5,
+            4 * CARD_MIN_WIDTH + 3,
+            2 * CARD_MIN_HEIGHT,
+        )));
+        assert_eq!(app.grid_size(), grid);
+
+        let len = 10;
+        let position = GridPosition {
+            start: 0,
+            selected: 0,
+        };
+        // the first column and the first row of the list are edges
+        assert_eq!(position.left(grid), position);
+        assert_eq!(position.up(grid), position);
+
+        let position = position.right(grid, len).down(grid, len);
+        assert_eq!(
+            position,
+            GridPosition {
+                start: 0,
+                selected: 5
+            }
+        );
+        // last row of the page scrolls
+        let position = position.down(grid, len);
+        assert_eq!(
+            position,
+            GridPosition {
+                start: 4,
+                selected: 5
+            }
+        );
+        // the next row is shorter, moves to the last card
+        let position = GridPosition {
+            start: 4,
+            selected: 2,
+        }
+        .down(grid, len);
+        assert_eq!(
+            position,
+            GridPosition {
+                start: 4,
+                selected: 5
+            }
+        );
+        assert_eq!(position.down(grid, len), position);
+
+        // a smaller grid after a resize keeps the selected card visible
+        let small = GridSize {
+            columns: 2,
+            rows: 1,
+        };
+        assert_eq!(
+            position.clamp(small, len),
+            GridPosition {
+                start: 8,
+                selected: 1
+            }
+        );
+        assert_eq!(
+            position.clamp(grid, 3),
+            GridPosition {
+                start: 0,
+                selected: 2
+            }
+        );
+    }
+
     #[test]
     fn test_backward_navigation() {
         let mut app = get_app();
@@ -7,6 +7,7 @@ pub mod token;
 /// methods for cache
 pub mod cache;
 
+use crate::api::TokenProvider;
 use crate::config::oauth_config::AuthConfig;
 use color_eyre::Result;
 use rand::{distr::Alphanumeric, rng, Rng};
@@ -28,6 +29,8 @@ pub enum AuthError {
     InvalidResponse(String),
     AuthNotPresent,
     TokenNotPresent,
+    // the login page didn't answer, the cached auth is deleted
+    LoginPageUnavailable,
 }
 
 impl From<reqwest::Error> for AuthError {
@@ -48,6 +51,7 @@ impl std::error::Error for AuthError {
             AuthError::InvalidResponse(_) => None,
             AuthError::AuthNotPresent => None,
             AuthError::TokenNotPresent => None,
+            AuthError::LoginPageUnavailable => None,
         }
     }
 }
@@ -60,6 +64,7 @@ impl std::fmt::Display for AuthError {
             AuthError::InvalidResponse(ref err) => err.fmt(f),
             AuthError::AuthNotPresent => write!(f, "Auth is not present"),
             AuthError::TokenNotPresent => write!(f, "Token is not present"),
+            AuthError::LoginPageUnavailable => write!(f, "Failed to open URL"),
         }
     }
 }
@@ -296,7 +301,8 @@ impl OAuth {
 
     /// Refresh the token
     pub fn refresh(&mut self) -> Result<(), AuthError> {
-        if self.token().unwrap().expired() {
+        // nothing to refresh in anonymous mode
+        if self.token().is_some_and(|t| t.expired()) {
             let request = reqwest::blocking::ClientBuilder::new()
                 .user_agent(USER_AGENT)
                 .build()?
@@ -319,7 +325,7 @@ impl OAuth {
 
     /// Refresh the token (async)
     pub async fn refresh_async(&mut self) -> Result<(), AuthError> {
-        if self.token().unwrap().expired() {
+        if self.token().is_some_and(|t| t.expired()) {
             let request = reqwest::ClientBuilder::new()
                 .user_agent(USER_AGENT)
                 .build()?
@@ -345,37 +351,77 @@ impl OAuth {
             auth.refresh_async().await?;
             Ok(auth)
         } else {
-            let auth = OAuth::new(
-                config.get_user_agent(),
-                config.client_id.clone(),
-                None,
-                config.get_redirect_uri(),
-            );
-
-            let url = auth.get_auth_url();
-
-            if test_oauth_url(&url).await {
-                open(&url).unwrap();
-            } else {
+            let mut auth = OAuth::anonymous(&config);
+            if let Err(e) = auth.login_async().await {
                 println!("==> Please verify your creds and retry.");
-                println!("==> Note: cached auth file will be deleted.");
-                // delete oauth cache file
-                cache::delete_cached_auth();
-                // If the URL cannot be opened, return an error
-                return Err(AuthError::InvalidResponse("Failed to open URL".to_string()));
+                if let AuthError::LoginPageUnavailable = e {
+                    println!("==> Note: cached auth file will be deleted.");
+                }
+                return Err(e);
             }
-            let mut auth = redirect::Server::new(config.get_user_agent(), auth)
-                .go()
-                .unwrap();
+            Ok(auth)
+        }
+    }
 
-            auth.get_access_token_async().await.unwrap();
+    /// Auth with only the client id, enough for the public endpoints
+    pub fn anonymous(config: &AuthConfig) -> OAuth {
+        OAuth::new(
+            config.get_user_agent(),
+            config.client_id.clone(),
+            None,
+            config.get_redirect_uri(),
+        )
+    }
 
-            cache::cache_auth(&auth);
+    /// true when there is no user token (client id only)
+    pub fn is_anonymous(&self) -> bool {
+        self.token.is_none()
+    }
 
-            Ok(auth)
+    /// Runs the browser login flow and caches the token
+    pub async fn login_async(&mut self) -> Result<(), AuthError> {
+        self.open_login_page().await?;
+        // the redirect server blocks until the browser comes back
+        let auth = self.clone();
+        let redirect = tokio::task::spawn_blocking(move || auth.wait_login_redirect())
+            .await
+            .map_err(|e| AuthError::InvalidResponse(e.to_string()))?;
+        self.finish_login_async(redirect?).await
+    }
+
+    /// Opens the login page in the browser
+    pub async fn open_login_page(&self) -> Result<(), AuthError> {
+        let url = self.get_auth_url();
+
+        if test_oauth_url(&url).await {
+            open(&url).map_err(|e| AuthError::InvalidResponse(e.to_string()))
+        } else {
+            // delete oauth cache file
+            cache::delete_cached_auth();
+            // If the URL cannot be opened, return an error
+            Err(AuthError::LoginPageUnavailable)
         }
     }
 
+    /// Waits for the browser redirect of the login page, blocking
+    pub fn wait_login_redirect(&self) -> Result<OAuth, AuthError> {
+        redirect::Server::new(self.user_agent.clone(), self.clone())
+            .go()
+            .map_err(|e| match e {
+                redirect::ServerError::AuthError(e) => e,
+                e => AuthError::InvalidResponse(format!("{:?}", e)),
+            })
+    }
+
+    /// Gets the token of the redirect and caches it
+    pub async fn finish_login_async(&mut self, mut auth: OAuth) -> Result<(), AuthError> {
+        auth.get_access_token_async().await?;
+
+        cache::cache_auth(&auth);
+        *self = auth;
+        Ok(())
+    }
+
     // for tests
     pub fn get_auth(config: AuthConfig) -> Result<OAuth, AuthError> {
         if let Some(mut auth) = cache::load_cached_auth() {
@@ -405,6 +451,20 @@ impl OAuth {
     }
 }
 
+impl TokenProvider for OAuth {
+    fn access_token(&self) -> Option<&str> {
+        self.token().map(|t| t.token.access_token.as_str())
+    }
+
+    fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    fn client_id(&self) -> Option<&str> {
+        Some(&self.client_id)
+    }
+}
+
 pub async fn test_oauth_url(url: &Url) -> bool {
     let res = reqwest::ClientBuilder::new()
         .user_agent(USER_AGENT)
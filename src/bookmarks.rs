@@ -0,0 +1,137 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, IntoStaticStr};
+
+use crate::config::ConfigError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, Display, IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MediaKind {
+    Anime,
+    Manga,
+}
+
+/// A title pinned locally, it never touches the MAL list status
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Bookmark {
+    pub kind: MediaKind,
+    pub id: u64,
+    pub title: String,
+    #[serde(default)]
+    pub note: String,
+    // like "to recommend to team", None is ungrouped
+    #[serde(default)]
+    pub group: Option<String>,
+}
+
+/// Bookmarks of the cache dir, in the order they were added
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Bookmarks {
+    pub entries: Vec<Bookmark>,
+}
+
+impl Bookmarks {
+    /// no file yet is no bookmarks
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| ConfigError::IOError(e.into()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let content =
+            serde_json::to_string_pretty(self).map_err(|e| ConfigError::IOError(e.into()))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    pub fn get(&self, kind: MediaKind, id: u64) -> Option<&Bookmark> {
+        self.entries.iter().find(|b| b.kind == kind && b.id == id)
+    }
+
+    /// adds a bookmark, or replaces the note and group of an existing one
+    pub fn add(&mut self, bookmark: Bookmark) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|b| b.kind == bookmark.kind && b.id == bookmark.id)
+        {
+            Some(existing) => *existing = bookmark,
+            None => self.entries.push(bookmark),
+        }
+    }
+
+    pub fn remove(&mut self, kind: MediaKind, id: u64) -> Option<Bookmark> {
+        let index = self
+            .entries
+            .iter()
+            .position(|b| b.kind == kind && b.id == id)?;
+        Some(self.entries.remove(index))
+    }
+
+    /// adds the bookmark or removes it when it exists, true when added
+    pub fn toggle(&mut self, bookmark: Bookmark) -> bool {
+        if self.remove(bookmark.kind, bookmark.id).is_some() {
+            return false;
+        }
+        self.entries.push(bookmark);
+        true
+    }
+
+    /// grouped bookmarks first, by group name, then the ungrouped ones
+    pub fn sorted(&self) -> Vec<&Bookmark> {
+        let mut sorted: Vec<&Bookmark> = self.entries.iter().collect();
+        // stable, a group keeps the order its bookmarks were added in
+        sorted.sort_by(|a, b| match (&a.group, &b.group) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+        sorted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(kind: MediaKind, id: u64, group: Option<&str>) -> Bookmark {
+        Bookmark {
+            kind,
+            id,
+            title: format!("{} {}", kind, id),
+            note: String::new(),
+            group: group.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_toggle_and_sort_bookmarks() {
+        let mut bookmarks = Bookmarks::default();
+        assert!(bookmarks.toggle(bookmark(MediaKind::Anime, 1, None)));
+        bookmarks.add(bookmark(MediaKind::Manga, 1, Some("watch with friends")));
+        bookmarks.add(bookmark(MediaKind::Anime, 2, Some("recommend")));
+        // same id, other kind
+        assert!(bookmarks.get(MediaKind::Manga, 1).is_some());
+
+        let sorted: Vec<(MediaKind, u64)> =
+            bookmarks.sorted().iter().map(|b| (b.kind, b.id)).collect();
+        assert_eq!(
+            sorted,
+            vec![
+                (MediaKind::Anime, 2),
+                (MediaKind::Manga, 1),
+                (MediaKind::Anime, 1)
+            ]
+        );
+
+        assert!(!bookmarks.toggle(bookmark(MediaKind::Anime, 1, None)));
+        assert!(bookmarks.get(MediaKind::Anime, 1).is_none());
+        assert_eq!(bookmarks.entries.len(), 2);
+    }
+}
@@ -0,0 +1,320 @@
+use time::Date;
+
+use crate::{
+    api::{
+        model::{Anime, Manga, UserReadStatus, UserWatchStatus},
+        UpdateUserAnimeListStatusQuery, UpdateUserMangaStatus,
+    },
+    app::{RATING_OPTIONS, USER_READ_STATUS, USER_WATCH_STATUS},
+    config::app_config::ListUpdatePolicy,
+    progress,
+};
+
+pub const BULK_ACTIONS: [&str; 4] = ["Change status", "Set score", "Add tag", "Delete from list"];
+
+pub const WATCH_STATUSES: [UserWatchStatus; 5] = [
+    UserWatchStatus::Watching,
+    UserWatchStatus::Completed,
+    UserWatchStatus::OnHold,
+    UserWatchStatus::Dropped,
+    UserWatchStatus::PlanToWatch,
+];
+
+pub const READ_STATUSES: [UserReadStatus; 5] = [
+    UserReadStatus::Reading,
+    UserReadStatus::Completed,
+    UserReadStatus::OnHold,
+    UserReadStatus::Dropped,
+    UserReadStatus::PlanToRead,
+];
+
+/// What is done to every marked entry
+#[derive(Clone, Debug, PartialEq)]
+pub enum BulkAction {
+    WatchStatus(UserWatchStatus),
+    ReadStatus(UserReadStatus),
+    Score(u8),
+    AddTag(String),
+    Delete,
+}
+
+/// One list call of a bulk edit
+#[derive(Clone, Debug)]
+pub enum BulkRequest {
+    UpdateAnime(u64, UpdateUserAnimeListStatusQuery),
+    UpdateManga(u64, UpdateUserMangaStatus),
+    DeleteAnime(u64),
+    DeleteManga(u64),
+}
+
+/// Step of the bulk edit popup
+#[derive(Clone, Debug, PartialEq)]
+pub enum BulkPopup {
+    Actions,
+    Status,
+    Score,
+    // the tag being typed
+    Tag(String),
+    ConfirmDelete,
+    Running(BulkProgress),
+}
+
+impl BulkPopup {
+    /// options of the step, None when it has no list
+    pub fn options(&self, is_anime: bool) -> Option<Vec<&'static str>> {
+        match self {
+            BulkPopup::Actions => Some(BULK_ACTIONS.to_vec()),
+            BulkPopup::Status if is_anime => Some(USER_WATCH_STATUS.to_vec()),
+            BulkPopup::Status => Some(USER_READ_STATUS.to_vec()),
+            BulkPopup::Score => Some(RATING_OPTIONS.to_vec()),
+            _ => None,
+        }
+    }
+}
+
+/// Calls made so far, the popup shows a summary once they're all done
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BulkProgress {
+    pub total: usize,
+    pub done: usize,
+    // titles whose call failed
+    pub failed: Vec<String>,
+}
+
+impl BulkProgress {
+    /// one call done, successful or not
+    pub fn record(&mut self, title: String, success: bool) {
+        self.done += 1;
+        if !success {
+            self.failed.push(title);
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.done >= self.total
+    }
+
+    pub fn summary(&self) -> String {
+        let updated = self.done - self.failed.len();
+        match self.failed.len() {
+            0 => format!("{} entries updated", updated),
+            failed => format!("{} entries updated, {} failed", updated, failed),
+        }
+    }
+}
+
+/// Marked entries of the displayed list and the bulk edit popup
+#[derive(Clone, Debug, Default)]
+pub struct BulkEdit {
+    // ids in the marking order
+    pub marked: Vec<u64>,
+    pub popup: Option<BulkPopup>,
+    // highlighted option of the popup
+    pub selected: usize,
+}
+
+impl BulkEdit {
+    /// marks the entry, or unmarks it, true when it ends up marked
+    pub fn toggle(&mut self, id: u64) -> bool {
+        match self.marked.iter().position(|m| *m == id) {
+            Some(index) => {
+                self.marked.remove(index);
+                false
+            }
+            None => {
+                self.marked.push(id);
+                true
+            }
+        }
+    }
+
+    pub fn is_marked(&self, id: u64) -> bool {
+        self.marked.contains(&id)
+    }
+
+    pub fn is_typing(&self) -> bool {
+        matches!(self.popup, Some(BulkPopup::Tag(_)))
+    }
+
+    pub fn open(&mut self, popup: BulkPopup) {
+        self.popup = Some(popup);
+        self.selected = 0;
+    }
+}
+
+/// calls the action makes on the marked anime, entries already as asked are skipped
+pub fn anime_requests(
+    action: &BulkAction,
+    anime: &[&Anime],
+    policy: &ListUpdatePolicy,
+    today: Date,
+) -> Vec<(String, BulkRequest)> {
+    anime
+        .iter()
+        .filter_map(|anime| {
+            let my_list = anime.my_list_status.as_ref();
+            let mut query = UpdateUserAnimeListStatusQuery::default();
+            match action {
+                BulkAction::WatchStatus(status) => {
+                    if my_list.is_some_and(|s| &s.status == status) {
+                        return None;
+                    }
+                    query.status = Some(status.clone());
+                    progress::apply_anime_policy(policy, anime, &mut query, today);
+                }
+                BulkAction::Score(score) => {
+                    if my_list.is_some_and(|s| s.score == *score) {
+                        return None;
+                    }
+                    query.score = Some(*score);
+                }
+                BulkAction::AddTag(tag) => {
+                    query.tags = Some(with_tag(my_list.and_then(|s| s.tags.as_ref()), tag)?);
+                }
+                BulkAction::Delete => {
+                    return Some((anime.title.clone(), BulkRequest::DeleteAnime(anime.id)))
+                }
+                BulkAction::ReadStatus(_) => return None,
+            }
+            Some((
+                anime.title.clone(),
+                BulkRequest::UpdateAnime(anime.id, query),
+            ))
+        })
+        .collect()
+}
+
+pub fn manga_requests(
+    action: &BulkAction,
+    manga: &[&Manga],
+    policy: &ListUpdatePolicy,
+    today: Date,
+) -> Vec<(String, BulkRequest)> {
+    manga
+        .iter()
+        .filter_map(|manga| {
+            let my_list = manga.my_list_status.as_ref();
+            let mut query = UpdateUserMangaStatus::default();
+            match action {
+                BulkAction::ReadStatus(status) => {
+                    if my_list.is_some_and(|s| &s.status == status) {
+                        return None;
+                    }
+                    query.status = Some(status.clone());
+                    progress::apply_manga_policy(policy, manga, &mut query, today);
+                }
+                BulkAction::Score(score) => {
+                    if my_list.is_some_and(|s| s.score == *score) {
+                        return None;
+                    }
+                    query.score = Some(*score);
+                }
+                BulkAction::AddTag(tag) => {
+                    query.tags = Some(with_tag(my_list.and_then(|s| s.tags.as_ref()), tag)?);
+                }
+                BulkAction::Delete => {
+                    return Some((manga.title.clone(), BulkRequest::DeleteManga(manga.id)))
+                }
+                BulkAction::WatchStatus(_) => return None,
+            }
+            Some((
+                manga.title.clone(),
+                BulkRequest::UpdateManga(manga.id, query),
+            ))
+        })
+        .collect()
+}
+
+/// the tags to send, None when the entry already has it
+fn with_tag(tags: Option<&Vec<String>>, tag: &str) -> Option<String> {
+    let mut tags = tags.cloned().unwrap_or_default();
+    if tags.iter().any(|t| t == tag) {
+        return None;
+    }
+    tags.push(tag.to_string());
+    Some(tags.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anime(id: u64, status: &str, tags: &[&str]) -> Anime {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "title": format!("anime {}", id),
+            "num_episodes": 12,
+            "my_list_status": {
+                "status": status,
+                "score": 0,
+                "num_episodes_watched": 3,
+                "is_rewatching": false,
+                "tags": tags,
+                "updated_at": "2025-01-01T00:00:00+00:00",
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_anime_requests() {
+        let policy = ListUpdatePolicy::default();
+        let today = progress::today();
+        let (first, second) = (anime(1, "watching", &["fall"]), anime(2, "completed", &[]));
+        let marked = [&first, &second];
+
+        // the completed entry is skipped, the other one follows the policy
+        let requests = anime_requests(
+            &BulkAction::WatchStatus(UserWatchStatus::Completed),
+            &marked,
+            &policy,
+            today,
+        );
+        assert_eq!(requests.len(), 1);
+        match &requests[0].1 {
+            BulkRequest::UpdateAnime(id, query) => {
+                assert_eq!(*id, 1);
+                assert_eq!(query.num_watched_episodes, Some(12));
+            }
+            request => panic!("unexpected request {:?}", request),
+        }
+
+        // tags are added to the ones already there
+        let requests = anime_requests(
+            &BulkAction::AddTag("fall".to_string()),
+            &marked,
+            &policy,
+            today,
+        );
+        assert_eq!(requests.len(), 1);
+        match &requests[0].1 {
+            BulkRequest::UpdateAnime(id, query) => {
+                assert_eq!(*id, 2);
+                assert_eq!(query.tags.as_deref(), Some("fall"));
+            }
+            request => panic!("unexpected request {:?}", request),
+        }
+
+        let requests = anime_requests(&BulkAction::Delete, &marked, &policy, today);
+        assert!(matches!(requests[1].1, BulkRequest::DeleteAnime(2)));
+    }
+
+    #[test]
+    fn test_marking_and_progress() {
+        let mut bulk = BulkEdit::default();
+        assert!(bulk.toggle(3));
+        assert!(bulk.toggle(1));
+        assert!(!bulk.toggle(3));
+        assert_eq!(bulk.marked, vec![1]);
+
+        let mut progress = BulkProgress {
+            total: 2,
+            ..Default::default()
+        };
+        progress.record("a".to_string(), true);
+        assert!(!progress.is_finished());
+        progress.record("b".to_string(), false);
+        assert!(progress.is_finished());
+        assert_eq!(progress.summary(), "1 entries updated, 1 failed");
+    }
+}
@@ -1,27 +1,338 @@
-use clap::Parser;
+use crate::{
+    airing::{self, Schedule, ScheduleSource},
+    api::{self, model::UserWatchStatus, GetAnimeDetailQuery, GetMangaDetailQuery},
+    auth::OAuth,
+    bookmarks::{Bookmark, Bookmarks, MediaKind},
+    compare,
+    config::app_config::AppConfig,
+    notifier,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use color_eyre::{eyre::eyre, Result};
 use figlet_rs::FIGfont;
+use std::path::PathBuf;
+
 #[derive(Debug, Parser)]
 #[command(name = "mal", version, about = "A TUI client for myanimelist.net", long_about = None)]
-struct Cli {
+pub struct Cli {
     /// Show extra information about the tool
     #[arg(short = 'i', long = "info", action = clap::ArgAction::SetTrue)]
-    info: bool,
+    pub info: bool,
     /// Show configuration file structure and all available options
     #[arg(short = 'c', long = "config", action = clap::ArgAction::SetTrue)]
-    config: bool,
+    pub config: bool,
+    /// Browse without logging in (read-only, uses only the client id)
+    #[arg(short = 'a', long = "anonymous", action = clap::ArgAction::SetTrue)]
+    pub anonymous: bool,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Compare your list with another user's and export the result
+    Compare {
+        /// Username to compare with
+        user: String,
+        /// Compare manga lists instead of anime lists
+        #[arg(short = 'm', long = "manga", action = clap::ArgAction::SetTrue)]
+        manga: bool,
+        /// Output format
+        #[arg(short = 'f', long = "format", value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+        /// Write to a file instead of stdout
+        #[arg(short = 'o', long = "output")]
+        output: Option<PathBuf>,
+    },
+    /// List, add or remove the local bookmarks
+    Bookmarks {
+        #[command(subcommand)]
+        action: BookmarksAction,
+    },
+    /// Print the weekly airing schedule of your list in local time
+    Schedule {
+        /// Export weekly recurring events as an iCalendar file instead
+        #[arg(long = "ics", action = clap::ArgAction::SetTrue)]
+        ics: bool,
+        /// Write to a file instead of stdout
+        #[arg(short = 'o', long = "output")]
+        output: Option<PathBuf>,
+    },
+    /// Notify new episodes of your watching list as they air, until stopped
+    WatchDaemon {
+        /// Check once and exit, for cron or a systemd timer
+        #[arg(long = "once", action = clap::ArgAction::SetTrue)]
+        once: bool,
+    },
+}
+
+impl Command {
+    /// false for the subcommands only reading or writing local files
+    pub fn needs_api(&self) -> bool {
+        !matches!(
+            self,
+            Command::Bookmarks {
+                action: BookmarksAction::List { .. } | BookmarksAction::Rm { .. }
+            }
+        )
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BookmarksAction {
+    /// Print the bookmarks, grouped
+    List {
+        /// Only print the bookmarks of this group
+        #[arg(short = 'g', long = "group")]
+        group: Option<String>,
+    },
+    /// Bookmark a title by its MAL id
+    Add {
+        id: u64,
+        /// The id is a manga id
+        #[arg(short = 'm', long = "manga", action = clap::ArgAction::SetTrue)]
+        manga: bool,
+        #[arg(short = 'n', long = "note", default_value = "")]
+        note: String,
+        #[arg(short = 'g', long = "group")]
+        group: Option<String>,
+    },
+    /// Remove the bookmark of a title
+    Rm {
+        id: u64,
+        /// The id is a manga id
+        #[arg(short = 'm', long = "manga", action = clap::ArgAction::SetTrue)]
+        manga: bool,
+    },
 }
 
-pub fn handle_args() -> bool {
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+/// returns None when the args were handled and the app should exit
+pub fn handle_args() -> Option<Cli> {
     let cli = Cli::parse();
 
     if cli.info {
         print_info();
-        return true;
+        return None;
     } else if cli.config {
         print_config_structure();
-        return true;
+        return None;
     }
-    false
+    Some(cli)
+}
+
+/// Runs a subcommand that doesn't need the api, before any login
+pub async fn run_local_command(command: Command, app_config: &AppConfig) -> Result<()> {
+    match command {
+        Command::Bookmarks { action } => run_bookmarks(action, None, app_config).await,
+        _ => Err(eyre!("this command needs the api")),
+    }
+}
+
+/// Runs a subcommand without starting the tui
+pub async fn run_command(command: Command, oauth: &OAuth, app_config: &AppConfig) -> Result<()> {
+    match command {
+        Command::Compare {
+            user,
+            manga,
+            format,
+            output,
+        } => {
+            if oauth.is_anonymous() {
+                return Err(eyre!("comparing needs a logged in user"));
+            }
+            let comparison = compare::compare_with(&user, !manga, app_config.nsfw, oauth)
+                .await
+                .map_err(|e| eyre!("failed to compare lists: {:?}", e))?;
+            let content = match format {
+                ExportFormat::Json => serde_json::to_string_pretty(&comparison)?,
+                ExportFormat::Csv => comparison.to_csv(),
+            };
+            match output {
+                Some(path) => std::fs::write(path, content)?,
+                None => println!("{}", content),
+            }
+        }
+        Command::Bookmarks { action } => run_bookmarks(action, Some(oauth), app_config).await?,
+        Command::Schedule { ics, output } => {
+            if oauth.is_anonymous() {
+                return Err(eyre!("the schedule needs a logged in user"));
+            }
+            let anime = airing::fetch_my_airing(
+                &[UserWatchStatus::Watching, UserWatchStatus::PlanToWatch],
+                app_config.nsfw,
+                oauth,
+            )
+            .await
+            .map_err(|e| eyre!("failed to get the airing titles: {:?}", e))?;
+            let now = chrono::Utc::now();
+            let content = if ics {
+                airing::to_ics(&anime, now)
+            } else {
+                schedule_text(anime, now, app_config)
+            };
+            match output {
+                Some(path) => std::fs::write(path, content)?,
+                None => print!("{}", content),
+            }
+        }
+        Command::WatchDaemon { once } => {
+            if oauth.is_anonymous() {
+                return Err(eyre!("notifications need a logged in user"));
+            }
+            let mut oauth = oauth.clone();
+            let interval = app_config.notifications.check_interval();
+            loop {
+                // a failed refresh or check is retried at the next one
+                if let Err(e) = oauth.refresh_async().await {
+                    if once {
+                        return Err(eyre!("failed to refresh the token: {}", e));
+                    }
+                    eprintln!("Failed to refresh the token: {}", e);
+                    tokio::time::sleep(interval).await;
+                    continue;
+                }
+                match notifier::check_watching(app_config, &oauth).await {
+                    Ok(new_episodes) => {
+                        for episode in new_episodes {
+                            println!("{}", episode.message());
+                        }
+                    }
+                    Err(e) => eprintln!("{}", e),
+                }
+                if once {
+                    break;
+                }
+                tokio::time::sleep(interval).await;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// one line per title under its local weekday
+fn schedule_text(
+    anime: Vec<api::model::Anime>,
+    now: chrono::DateTime<chrono::Utc>,
+    app_config: &AppConfig,
+) -> String {
+    let schedule = Schedule::new(ScheduleSource::MyList, anime, now, &chrono::Local);
+    let mut content = String::new();
+    for (day, anime) in schedule.days.iter().enumerate() {
+        if anime.is_empty() {
+            continue;
+        }
+        let weekday = chrono::Weekday::try_from(day as u8).unwrap();
+        content.push_str(&format!("{}\n", weekday));
+        for anime in anime {
+            let Some(next) = airing::next_episode_airing(anime, now) else {
+                continue;
+            };
+            content.push_str(&format!(
+                "  {}  {}  (in {})\n",
+                next.with_timezone(&chrono::Local).format("%H:%M"),
+                anime.get_title(&app_config.title_language, false)[0],
+                airing::format_countdown(next, now)
+            ));
+        }
+    }
+    content
+}
+
+// the oauth is only needed to add a bookmark
+async fn run_bookmarks(
+    action: BookmarksAction,
+    oauth: Option<&OAuth>,
+    app_config: &AppConfig,
+) -> Result<()> {
+    let path = &app_config.paths.bookmarks_file_path;
+    let mut bookmarks =
+        Bookmarks::load(path).map_err(|e| eyre!("failed to read the bookmarks: {}", e))?;
+    match action {
+        BookmarksAction::List { group } => {
+            let mut current_group = None;
+            for bookmark in bookmarks.sorted() {
+                if group.is_some() && bookmark.group != group {
+                    continue;
+                }
+                if current_group != Some(&bookmark.group) {
+                    current_group = Some(&bookmark.group);
+                    println!("{}:", bookmark.group.as_deref().unwrap_or("ungrouped"));
+                }
+                let note = if bookmark.note.is_empty() {
+                    String::new()
+                } else {
+                    format!(" - {}", bookmark.note)
+                };
+                println!(
+                    "  {:<5} {:>6}  {}{}",
+                    bookmark.kind, bookmark.id, bookmark.title, note
+                );
+            }
+            return Ok(());
+        }
+        BookmarksAction::Add {
+            id,
+            manga,
+            note,
+            group,
+        } => {
+            let oauth = oauth.ok_or_else(|| eyre!("adding a bookmark needs the api"))?;
+            let fields = Some("title,alternative_titles".to_string());
+            let (kind, title) = if manga {
+                let query = GetMangaDetailQuery {
+                    fields,
+                    nsfw: app_config.nsfw,
+                };
+                let manga = api::get_manga_details(id, &query, oauth)
+                    .await
+                    .map_err(|e| eyre!("failed to get manga {}: {:?}", id, e))?;
+                (
+                    MediaKind::Manga,
+                    manga.get_title(&app_config.title_language, false)[0].clone(),
+                )
+            } else {
+                let query = GetAnimeDetailQuery {
+                    fields,
+                    nsfw: app_config.nsfw,
+                };
+                let anime = api::get_anime_details(id, &query, oauth)
+                    .await
+                    .map_err(|e| eyre!("failed to get anime {}: {:?}", id, e))?;
+                (
+                    MediaKind::Anime,
+                    anime.get_title(&app_config.title_language, false)[0].clone(),
+                )
+            };
+            println!("Bookmarked {}", title);
+            bookmarks.add(Bookmark {
+                kind,
+                id,
+                title,
+                note,
+                group,
+            });
+        }
+        BookmarksAction::Rm { id, manga } => {
+            let kind = if manga {
+                MediaKind::Manga
+            } else {
+                MediaKind::Anime
+            };
+            match bookmarks.remove(kind, id) {
+                Some(bookmark) => println!("Removed the bookmark of {}", bookmark.title),
+                None => return Err(eyre!("no {} bookmark with id {}", kind, id)),
+            }
+        }
+    }
+    bookmarks
+        .save(path)
+        .map_err(|e| eyre!("failed to save the bookmarks: {}", e))
 }
 
 fn print_info() {
@@ -53,13 +364,44 @@ CONFIGURATION KEYS:
 ===================
 
 KEYBINDINGS:
-  keys:
-    help: '?'                    # Show help menu
-    back: 'q'                    # Go back/quit current view
-    search: '/'                  # Open search
-    toggle: 's'                  # Toggle between anime/manga or switch states
-    next_state: Ctrl+p           # Navigate to next state/page
-    open_popup: 'r'              # Open rating/status popup
+  keymap:                        # action: [keys], a section only needs the actions it changes
+    global:                      # everywhere, unless a context section binds the action
+      quit: [q, Ctrl+c]          # Ask to quit
+      back: [Esc]                # Go back to the previous view
+      help: ['?']                # Show help menu
+      search: ['/']              # Open search
+      next_state: [Ctrl+p]       # Go forward to the next view
+      next_block: [Tab]          # Focus the next block
+      previous_block: [Shift+Tab]
+      up/down/left/right: [arrows, k/j/h/l]
+      select: [Enter]            # Open the selected entry
+      toggle: ['s']              # Toggle between anime/manga or switch states
+      open_popup: ['r']          # Open rating/status popup
+      switch_media: ['m']        # Anime/manga statistics and lists
+      stats_dashboard: ['d']     # Statistics dashboard from the profile
+      command_palette: [:, Ctrl+k] # Command palette with every view and action
+      toggle_bookmark: ['b']     # Bookmark the opened title, or remove it
+      increment_progress: ['+']  # +1 episode/chapter on details and my list cards
+      undo: ['u']                # Undo the last change of your list
+    grid:                        # result cards and tables
+      filter: ['f']              # Fuzzy filter the displayed cards
+      toggle_layout: ['t']       # Switch the current view between cards and table
+      compare_mode: ['c']        # Compare another user's list with yours
+      view_comparison: ['v']
+      clear_history: ['x']       # Clear the viewed history
+      mark: [' ']                # Mark an entry of your list for a bulk edit
+      bulk_edit: ['e']           # Edit the marked entries
+    details: {{}}                  # detail pages
+    popup:
+      confirm: ['y']             # Quit from the exit popup
+      cancel: ['n']
+    input:                       # search box and filter line
+      cursor_left/cursor_right/cursor_start/cursor_end
+      delete_backward/delete_forward/delete_word/delete_to_start/delete_to_end/clear_input
+      submit: [Enter]
+      cancel: [Esc]
+  keys are written `!char 'x'`, `!ctrl 'x'`, `!alt 'x'` or by name (enter, esc, tab, up...)
+  a key bound to two actions reachable from the same context is refused at startup
 
 THEME COLORS:
   theme:
@@ -117,6 +459,58 @@ PERFORMANCE SETTINGS:
   search_limit: 30               # Max search results per page
   max_cached_images: 15          # Max images to cache for faster loading
 
+TABLE LAYOUT:
+  table_layout:
+    by_default: false            # Show result views as a table instead of cards
+    columns:                     # Table columns, in display order
+      - title
+      - media_type
+      - episodes                 # Episodes or chapters
+      - status                   # My list status
+      - my_score
+      - mean
+      - progress
+
+SESSION:
+  session:
+    restore: true                # Reopen the views of the last run on startup
+    max_age_hours: 24            # Start from home when the last run is older
+    # saved to $HOME/.cache/mal-cli/session.json on exit
+
+HISTORY:
+  history:
+    limit: 200                   # Opened detail pages kept in the history
+    # saved to $HOME/.cache/mal-cli/history.json
+
+HOME DASHBOARD:
+  home:
+    widgets:                     # Widgets of the home screen, in display order
+      - watching                 # Currently watching, with the next episode countdown
+      - airing_this_season       # Titles of your list airing this season
+      - recent_updates           # Last updated entries of your list
+      - recently_viewed          # Last opened detail pages
+      - quick_actions            # Shortcuts to common views
+    entries_per_widget: 10       # Entries fetched for the list widgets
+
+LIST UPDATES:
+  list_updates:
+    auto_status: true            # Watching/reading on the first episode, completed on the last one
+    auto_dates: true             # Today as start date when started, finish date when completed
+    ask_score_on_completion: true # Open the score popup when an unscored entry is completed
+
+NOTIFICATIONS:
+  notifications:
+    enabled: false               # Check for new episodes from the TUI too
+    method: desktop              # desktop (notify-send), bell or command
+    command: ''                  # Run with sh, MAL_ID, MAL_TITLE, MAL_EPISODE, MAL_MESSAGE set
+    check_interval_minutes: 15   # Time between two checks
+    # notified episodes saved to $HOME/.cache/mal-cli/notified.json
+    # `mal watch-daemon [--once]` checks without the TUI
+
+BOOKMARKS:
+  # saved to $HOME/.cache/mal-cli/bookmarks.json, no config keys
+  # `mal bookmarks list|add|rm` manages them from the shell
+
 EXAMPLE CONFIG FILE:
 ====================
 Copy the example configuration from: config.example.yml
@@ -0,0 +1,273 @@
+use crate::{
+    api::{
+        self,
+        model::{Anime, ListNode, Manga, UserAnimeListStatus, UserMangaListStatus},
+        TokenProvider,
+    },
+    stats,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const LIST_FIELDS: &str = "list_status,mean,media_type";
+// their score needed for a title to be recommended
+pub const RECOMMENDATION_MIN_SCORE: u8 = 8;
+pub const MAX_DISAGREEMENTS: usize = 15;
+pub const MAX_RECOMMENDATIONS: usize = 20;
+
+/// One title of a user's list, only what's needed to compare
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CompareEntry {
+    pub id: u64,
+    pub title: String,
+    // 0 is not scored
+    pub score: u8,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SharedEntry {
+    pub id: u64,
+    pub title: String,
+    pub my_score: u8,
+    pub their_score: u8,
+}
+
+impl SharedEntry {
+    pub fn difference(&self) -> i16 {
+        self.their_score as i16 - self.my_score as i16
+    }
+
+    fn both_scored(&self) -> bool {
+        self.my_score != 0 && self.their_score != 0
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Comparison {
+    pub user: String,
+    pub is_anime: bool,
+    pub my_count: usize,
+    pub their_count: usize,
+    pub my_mean: Option<f64>,
+    pub their_mean: Option<f64>,
+    // their mean - my mean
+    pub mean_difference: Option<f64>,
+    pub shared: Vec<SharedEntry>,
+    // biggest score differences first
+    pub disagreements: Vec<SharedEntry>,
+    // titles they rated highly that are not on my list
+    pub recommendations: Vec<CompareEntry>,
+}
+
+impl Comparison {
+    pub fn new(
+        user: String,
+        is_anime: bool,
+        mine: &[CompareEntry],
+        theirs: &[CompareEntry],
+    ) -> Self {
+        let my_scores: HashMap<u64, u8> = mine.iter().map(|e| (e.id, e.score)).collect();
+
+        let shared: Vec<SharedEntry> = theirs
+            .iter()
+            .filter_map(|e| {
+                my_scores.get(&e.id).map(|&my_score| SharedEntry {
+                    id: e.id,
+                    title: e.title.clone(),
+                    my_score,
+                    their_score: e.score,
+                })
+            })
+            .collect();
+
+        let mut disagreements: Vec<SharedEntry> = shared
+            .iter()
+            .filter(|e| e.both_scored() && e.difference() != 0)
+            .cloned()
+            .collect();
+        disagreements.sort_by_key(|e| std::cmp::Reverse(e.difference().abs()));
+        disagreements.truncate(MAX_DISAGREEMENTS);
+
+        let mut recommendations: Vec<CompareEntry> = theirs
+            .iter()
+            .filter(|e| e.score >= RECOMMENDATION_MIN_SCORE && !my_scores.contains_key(&e.id))
+            .cloned()
+            .collect();
+        recommendations.sort_by_key(|e| std::cmp::Reverse(e.score));
+        recommendations.truncate(MAX_RECOMMENDATIONS);
+
+        let my_mean = mean_score(mine);
+        let their_mean = mean_score(theirs);
+        let mean_difference = match (my_mean, their_mean) {
+            (Some(mine), Some(theirs)) => Some(theirs - mine),
+            _ => None,
+        };
+
+        Self {
+            user,
+            is_anime,
+            my_count: mine.len(),
+            their_count: theirs.len(),
+            my_mean,
+            their_mean,
+            mean_difference,
+            shared,
+            disagreements,
+            recommendations,
+        }
+    }
+
+    /// one row per title: shared titles then recommendations
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("kind,id,title,my_score,their_score,difference\n");
+        for e in &self.shared {
+            csv.push_str(&format!(
+                "shared,{},{},{},{},{}\n",
+                e.id,
+                escape_csv(&e.title),
+                e.my_score,
+                e.their_score,
+                e.difference()
+            ));
+        }
+        for e in &self.recommendations {
+            csv.push_str(&format!(
+                "recommendation,{},{},,{},\n",
+                e.id,
+                escape_csv(&e.title),
+                e.score
+            ));
+        }
+        csv
+    }
+}
+
+fn mean_score(entries: &[CompareEntry]) -> Option<f64> {
+    let scores: Vec<u8> = entries
+        .iter()
+        .map(|e| e.score)
+        .filter(|&s| s != 0)
+        .collect();
+    if scores.is_empty() {
+        return None;
+    }
+    Some(scores.iter().map(|&s| s as f64).sum::<f64>() / scores.len() as f64)
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn anime_entries(list: Vec<ListNode<Anime, UserAnimeListStatus>>) -> Vec<CompareEntry> {
+    list.into_iter()
+        .map(|e| CompareEntry {
+            id: e.node.id,
+            title: e.node.title,
+            score: e.list_status.map_or(0, |s| s.score),
+        })
+        .collect()
+}
+
+fn manga_entries(list: Vec<ListNode<Manga, UserMangaListStatus>>) -> Vec<CompareEntry> {
+    list.into_iter()
+        .map(|e| CompareEntry {
+            id: e.node.id,
+            title: e.node.title,
+            score: e.list_status.map_or(0, |s| s.score),
+        })
+        .collect()
+}
+
+/// Fetches the whole anime list of `user`
+pub async fn fetch_anime_entries(
+    user: &str,
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<Vec<CompareEntry>, api::Error> {
+    let list = stats::fetch_full_anime_list(user, LIST_FIELDS, nsfw, auth).await?;
+    Ok(anime_entries(list))
+}
+
+/// Fetches the whole manga list of `user`
+pub async fn fetch_manga_entries(
+    user: &str,
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<Vec<CompareEntry>, api::Error> {
+    let list = stats::fetch_full_manga_list(user, LIST_FIELDS, nsfw, auth).await?;
+    Ok(manga_entries(list))
+}
+
+/// Compares the logged in user's list with `user`'s
+pub async fn compare_with(
+    user: &str,
+    is_anime: bool,
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<Comparison, api::Error> {
+    let (mine, theirs) = if is_anime {
+        (
+            fetch_anime_entries("@me", nsfw, auth).await?,
+            fetch_anime_entries(user, nsfw, auth).await?,
+        )
+    } else {
+        (
+            fetch_manga_entries("@me", nsfw, auth).await?,
+            fetch_manga_entries(user, nsfw, auth).await?,
+        )
+    };
+    Ok(Comparison::new(user.to_string(), is_anime, &mine, &theirs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u64, title: &str, score: u8) -> CompareEntry {
+        CompareEntry {
+            id,
+            title: title.to_string(),
+            score,
+        }
+    }
+
+    #[test]
+    fn test_comparison() {
+        let mine = vec![entry(1, "a", 9), entry(2, "b", 4), entry(3, "c", 0)];
+        let theirs = vec![
+            entry(1, "a", 8),
+            entry(2, "b", 10),
+            entry(3, "c", 7),
+            entry(4, "d", 9),
+            entry(5, "e", 6),
+        ];
+        let comparison = Comparison::new("friend".to_string(), true, &mine, &theirs);
+
+        assert_eq!(comparison.shared.len(), 3);
+        // unscored entries are not disagreements
+        assert_eq!(comparison.disagreements.len(), 2);
+        assert_eq!(comparison.disagreements[0].id, 2);
+        assert_eq!(comparison.disagreements[0].difference(), 6);
+        assert_eq!(comparison.recommendations.len(), 1);
+        assert_eq!(comparison.recommendations[0].id, 4);
+        assert_eq!(comparison.my_mean, Some(6.5));
+        assert_eq!(comparison.their_mean, Some(8.0));
+        assert_eq!(comparison.mean_difference, Some(1.5));
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        let comparison = Comparison::new(
+            "friend".to_string(),
+            true,
+            &[entry(1, "Kaguya-sama: \"Love\", War", 7)],
+            &[entry(1, "Kaguya-sama: \"Love\", War", 9)],
+        );
+        let csv = comparison.to_csv();
+        assert!(csv.contains("shared,1,\"Kaguya-sama: \"\"Love\"\", War\",7,9,2"));
+    }
+}
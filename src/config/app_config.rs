@@ -1,22 +1,34 @@
 use std::{
     fs,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 use super::*;
 use crate::{
     api::model::{AnimeRankingType, MangaRankingType},
+    config::keymap::{Action, KeyContext, Keymap},
     event::key::Key,
+    list_view::ListViews,
 };
 use log::LevelFilter;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::{EnumIter, IntoStaticStr};
+
+pub use crate::api::model::{MangaDisplayType, TitleLanguage};
 
 #[derive(Clone, Deserialize, Serialize)]
 pub struct AppConfig {
     #[serde(skip_deserializing, skip_serializing)]
     pub paths: CachePaths,
-    pub keys: KeyBindings,
+    // keys of every action, per context
+    #[serde(default)]
+    pub keymap: Keymap,
+    // single keys of older config files, moved into the keymap on load
+    #[serde(default, skip_serializing)]
+    keys: Option<KeyBindings>,
     pub theme: Theme,
     pub behavior: BehaviorConfig,
     pub nsfw: bool,
@@ -29,12 +41,27 @@ pub struct AppConfig {
     pub search_limit: u64,
     pub log_level: LevelFilter,
     pub max_cached_images: u16,
-}
-
-#[derive(Clone, Deserialize, Serialize, Debug)]
-pub enum TitleLanguage {
-    Japanese,
-    English,
+    // sort and filters of the user lists, saved from the list views
+    #[serde(default)]
+    pub list_views: ListViews,
+    // dense table alternative to the card grid
+    #[serde(default)]
+    pub table_layout: TableLayout,
+    // navigation history saved on exit
+    #[serde(default)]
+    pub session: SessionConfig,
+    // log of the opened detail pages
+    #[serde(default)]
+    pub history: HistoryConfig,
+    // widgets of the home screen
+    #[serde(default)]
+    pub home: HomeConfig,
+    // new episodes of the watching list
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+    // status and dates filled in on list updates
+    #[serde(default)]
+    pub list_updates: ListUpdatePolicy,
 }
 
 #[derive(Copy, Deserialize, Serialize, Clone, Debug)]
@@ -78,6 +105,7 @@ impl Default for Theme {
     }
 }
 
+/// Keys of older config files, before the keymap
 #[derive(Clone, Deserialize, Serialize)]
 pub struct KeyBindings {
     pub help: Key,
@@ -86,6 +114,203 @@ pub struct KeyBindings {
     pub toggle: Key,
     pub next_state: Key,
     pub open_popup: Key,
+    // filter line of the card views
+    #[serde(default = "default_filter_key")]
+    pub filter: Key,
+    // switches the current view between cards and table
+    #[serde(default = "default_toggle_layout_key")]
+    pub toggle_layout: Key,
+}
+
+fn default_filter_key() -> Key {
+    Key::Char('f')
+}
+
+fn default_toggle_layout_key() -> Key {
+    Key::Char('t')
+}
+
+impl KeyBindings {
+    /// keys changed from their old default replace the keys of their action,
+    /// `back` is left out as it was always shadowed by quit
+    fn migrate(&self, keymap: &mut Keymap) {
+        let defaults = Keymap::default();
+        for (context, action, key) in [
+            (KeyContext::Global, Action::Help, self.help),
+            (KeyContext::Global, Action::Search, self.search),
+            (KeyContext::Global, Action::Toggle, self.toggle),
+            (KeyContext::Global, Action::NextState, self.next_state),
+            (KeyContext::Global, Action::OpenPopup, self.open_popup),
+            (KeyContext::Grid, Action::Filter, self.filter),
+            (KeyContext::Grid, Action::ToggleLayout, self.toggle_layout),
+        ] {
+            if defaults.first_key(context, action) != Some(key) {
+                keymap.section_mut(context).insert(action, vec![key]);
+            }
+        }
+    }
+}
+
+/// Columns of the table layout, in the order they are displayed
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize, EnumIter, IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+pub enum TableColumn {
+    Title,
+    #[strum(serialize = "Type")]
+    MediaType,
+    // episodes or chapters
+    Episodes,
+    Status,
+    #[strum(serialize = "My Score")]
+    MyScore,
+    Mean,
+    Progress,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TableLayout {
+    // start the result views as a table instead of cards
+    pub by_default: bool,
+    pub columns: Vec<TableColumn>,
+}
+
+impl Default for TableLayout {
+    fn default() -> Self {
+        Self {
+            by_default: false,
+            columns: TableColumn::iter().collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SessionConfig {
+    // restore the views of the last run on startup
+    pub restore: bool,
+    // older sessions are dropped, their data would be outdated
+    pub max_age_hours: u32,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            restore: true,
+            max_age_hours: 24,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HistoryConfig {
+    // oldest entries are dropped past it
+    pub limit: usize,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self { limit: 200 }
+    }
+}
+
+/// Widgets of the home dashboard, laid out in two columns in the configured order
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize, EnumIter, IntoStaticStr)]
+#[serde(rename_all = "snake_case")]
+pub enum HomeWidget {
+    #[strum(serialize = "Currently Watching")]
+    Watching,
+    // titles of my list airing this season
+    #[strum(serialize = "Airing This Season")]
+    AiringThisSeason,
+    #[strum(serialize = "Recent Updates")]
+    RecentUpdates,
+    #[strum(serialize = "Recently Viewed")]
+    RecentlyViewed,
+    #[strum(serialize = "Quick Actions")]
+    QuickActions,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HomeConfig {
+    // an empty list brings back the banner alone
+    pub widgets: Vec<HomeWidget>,
+    // entries fetched for the list widgets
+    pub entries_per_widget: u64,
+}
+
+impl Default for HomeConfig {
+    fn default() -> Self {
+        Self {
+            widgets: HomeWidget::iter().collect(),
+            entries_per_widget: 10,
+        }
+    }
+}
+
+/// What a list update implies, like the MAL website does
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListUpdatePolicy {
+    // watching/reading on the first episode or chapter, completed on the last one
+    pub auto_status: bool,
+    // today as the start date when started, as the finish date when completed
+    pub auto_dates: bool,
+    // open the score popup when an unscored entry is completed
+    pub ask_score_on_completion: bool,
+}
+
+impl Default for ListUpdatePolicy {
+    fn default() -> Self {
+        Self {
+            auto_status: true,
+            auto_dates: true,
+            ask_score_on_completion: true,
+        }
+    }
+}
+
+/// How a new episode is announced
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyMethod {
+    // notify-send, through D-Bus
+    Desktop,
+    // terminal bell
+    Bell,
+    // the configured shell command
+    Command,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    // check from the tui too, `mal watch-daemon` always checks
+    pub enabled: bool,
+    pub method: NotifyMethod,
+    // run with sh, with MAL_ID, MAL_TITLE, MAL_EPISODE and MAL_MESSAGE set
+    pub command: String,
+    pub check_interval_minutes: u64,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            method: NotifyMethod::Desktop,
+            command: String::new(),
+            check_interval_minutes: 15,
+        }
+    }
+}
+
+impl NotificationsConfig {
+    /// time between two checks, at least a minute
+    pub fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.check_interval_minutes.max(1) * 60)
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
@@ -96,13 +321,6 @@ pub struct BehaviorConfig {
     pub show_logger: bool,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub enum MangaDisplayType {
-    Vol,
-    Ch,
-    Both,
-}
-
 impl AppConfig {
     pub fn new() -> Result<Self, ConfigError> {
         let paths = get_cache_dir()?;
@@ -110,14 +328,8 @@ impl AppConfig {
         Ok(Self {
             paths,
             theme: Theme::default(),
-            keys: KeyBindings {
-                help: Key::Char('?'),
-                back: Key::Char('q'),
-                search: Key::Char('/'),
-                toggle: Key::Char('s'),
-                open_popup: Key::Char('r'),
-                next_state: Key::Ctrl('p'),
-            },
+            keymap: Keymap::default(),
+            keys: None,
             behavior: BehaviorConfig {
                 tick_rate_milliseconds: 500,
                 show_logger: false,
@@ -151,17 +363,20 @@ impl AppConfig {
             search_limit: 30,
             max_cached_images: 15,
             log_level: LevelFilter::Debug,
+            list_views: ListViews::default(),
+            table_layout: TableLayout::default(),
+            session: SessionConfig::default(),
+            history: HistoryConfig::default(),
+            home: HomeConfig::default(),
+            notifications: NotificationsConfig::default(),
+            list_updates: ListUpdatePolicy::default(),
         })
     }
 
     pub fn load() -> Result<Self, ConfigError> {
         // check file exists
         // do not get paths from config file,always use the default paths
-        let config_file = dirs::home_dir()
-            .ok_or(ConfigError::PathError)?
-            .join(CONFIG_DIR)
-            .join(APP_CONFIG_DIR)
-            .join(_CONFIG_FILE);
+        let config_file = get_config_file_path()?;
         if !config_file.exists() {
             // if config file doesn't exist, create default config
             fs::create_dir_all(config_file.parent().unwrap())?;
@@ -172,11 +387,46 @@ impl AppConfig {
         } else {
             // if config file exists, read it
             let content = fs::read_to_string(&config_file).map_err(|_| ConfigError::ReadError)?;
-            let config: Self = serde_yaml::from_str(&content).map_err(ConfigError::ParseError)?;
+            let mut config: Self =
+                serde_yaml::from_str(&content).map_err(ConfigError::ParseError)?;
+
+            if let Some(keys) = config.keys.take() {
+                keys.migrate(&mut config.keymap);
+            }
+            let conflicts = config.keymap.conflicts();
+            if !conflicts.is_empty() {
+                return Err(ConfigError::KeyConflicts(conflicts));
+            }
 
             Ok(config)
         }
     }
+
+    /// writes one setting changed from the app into the config file, the other settings
+    /// of the file are left as they are
+    pub fn save_setting(key: &str, value: &impl Serialize) -> Result<(), ConfigError> {
+        let config_file = get_config_file_path()?;
+        let mut config = if config_file.exists() {
+            let content = fs::read_to_string(&config_file).map_err(|_| ConfigError::ReadError)?;
+            serde_yaml::from_str::<Option<serde_yaml::Mapping>>(&content)
+                .map_err(ConfigError::ParseError)?
+                .unwrap_or_default()
+        } else {
+            serde_yaml::Mapping::new()
+        };
+        config.insert(key.into(), serde_yaml::to_value(value)?);
+        fs::create_dir_all(config_file.parent().unwrap())?;
+        fs::write(&config_file, serde_yaml::to_string(&config)?)?;
+        Ok(())
+    }
+}
+
+fn get_config_file_path() -> Result<PathBuf, ConfigError> {
+    Ok(dirs::home_dir()
+        .ok_or(ConfigError::PathError)?
+        .join(CONFIG_DIR)
+        .join(APP_CONFIG_DIR)
+        .join(_CONFIG_FILE))
 }
 
 fn get_cache_dir() -> Result<CachePaths, ConfigError> {
@@ -193,6 +443,16 @@ fn get_cache_dir() -> Result<CachePaths, ConfigError> {
 
             let data_file_path = cache_dir.join(DATA_FILE);
 
+            let session_file_path = cache_dir.join(SESSION_FILE);
+
+            let bookmarks_file_path = cache_dir.join(BOOKMARKS_FILE);
+
+            let history_file_path = cache_dir.join(HISTORY_FILE);
+
+            let notified_file_path = cache_dir.join(NOTIFIED_FILE);
+
+            let export_dir_path = cache_dir.join(EXPORT_DIR);
+
             if !home_cache_dir.exists() {
                 fs::create_dir(&home_cache_dir)?;
             }
@@ -207,6 +467,11 @@ fn get_cache_dir() -> Result<CachePaths, ConfigError> {
             let paths = CachePaths {
                 picture_cache_dir_path: picture_cache_dir.to_path_buf(),
                 data_file_path,
+                session_file_path,
+                bookmarks_file_path,
+                history_file_path,
+                notified_file_path,
+                export_dir_path,
             };
 
             Ok(paths)
@@ -219,9 +484,31 @@ fn get_cache_dir() -> Result<CachePaths, ConfigError> {
 pub struct CachePaths {
     pub picture_cache_dir_path: PathBuf,
     pub data_file_path: PathBuf,
+    pub session_file_path: PathBuf,
+    pub bookmarks_file_path: PathBuf,
+    pub history_file_path: PathBuf,
+    pub notified_file_path: PathBuf,
+    // created on the first export
+    pub export_dir_path: PathBuf,
 }
 impl Default for CachePaths {
     fn default() -> Self {
         get_cache_dir().ok().unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_interval() {
+        let mut notifications = NotificationsConfig {
+            check_interval_minutes: 0,
+            ..Default::default()
+        };
+        assert_eq!(notifications.check_interval(), Duration::from_secs(60));
+        notifications.check_interval_minutes = 15;
+        assert_eq!(notifications.check_interval(), Duration::from_secs(15 * 60));
+    }
+}
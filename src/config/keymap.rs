@@ -0,0 +1,440 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::{EnumIter, IntoStaticStr};
+
+use crate::event::Key;
+
+/// Everything a key can be bound to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Deserialize,
+    Serialize,
+    EnumIter,
+    IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Action {
+    // global
+    Quit,
+    Back,
+    Help,
+    Search,
+    NextState,
+    NextBlock,
+    PreviousBlock,
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Toggle,
+    OpenPopup,
+    // anime/manga statistics on the profile, anime/manga list on the user lists
+    SwitchMedia,
+    StatsDashboard,
+    CommandPalette,
+    ToggleBookmark,
+    // +1 episode or chapter
+    IncrementProgress,
+    // reverts the last change of my list
+    Undo,
+    // grid
+    Filter,
+    ToggleLayout,
+    CompareMode,
+    ViewComparison,
+    ClearHistory,
+    // bulk edits of my lists
+    Mark,
+    BulkEdit,
+    // popup
+    Confirm,
+    Cancel,
+    // input
+    CursorLeft,
+    CursorRight,
+    CursorStart,
+    CursorEnd,
+    DeleteBackward,
+    DeleteForward,
+    DeleteWord,
+    DeleteToStart,
+    DeleteToEnd,
+    ClearInput,
+    Submit,
+}
+
+impl Action {
+    /// what the action does, shown in the help screen
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Quit => "Ask to quit",
+            Action::Back => "Go back to the previous view",
+            Action::Help => "Show this help",
+            Action::Search => "Search anime and manga (@user opens a list)",
+            Action::NextState => "Go forward to the next view",
+            Action::NextBlock => "Focus the next block",
+            Action::PreviousBlock => "Focus the previous block",
+            Action::Up => "Move up",
+            Action::Down => "Move down",
+            Action::Left => "Move left",
+            Action::Right => "Move right",
+            Action::Select => "Open or confirm the selection",
+            Action::Toggle => "Switch anime/manga, status, tab or popup list",
+            Action::OpenPopup => "Open the popup of the view",
+            Action::SwitchMedia => "Switch between anime and manga",
+            Action::StatsDashboard => "Open the statistics dashboard",
+            Action::CommandPalette => "Open the command palette",
+            Action::ToggleBookmark => "Bookmark the title, or remove its bookmark",
+            Action::IncrementProgress => "Watch one more episode, or read one more chapter",
+            Action::Undo => "Undo the last change of your list",
+            Action::Filter => "Filter the cards by title",
+            Action::ToggleLayout => "Switch between cards and table",
+            Action::CompareMode => "Compare the list with yours",
+            Action::ViewComparison => "Show the comparison",
+            Action::ClearHistory => "Clear the viewed history",
+            Action::Mark => "Mark the entry for a bulk edit, or unmark it",
+            Action::BulkEdit => "Edit the marked entries",
+            Action::Confirm => "Confirm",
+            Action::Cancel => "Cancel",
+            Action::CursorLeft => "Move the cursor left",
+            Action::CursorRight => "Move the cursor right",
+            Action::CursorStart => "Move the cursor to the start",
+            Action::CursorEnd => "Move the cursor to the end",
+            Action::DeleteBackward => "Delete the character before the cursor",
+            Action::DeleteForward => "Delete the character under the cursor",
+            Action::DeleteWord => "Delete the word before the cursor",
+            Action::DeleteToStart => "Delete everything before the cursor",
+            Action::DeleteToEnd => "Delete everything after the cursor",
+            Action::ClearInput => "Clear the input",
+            Action::Submit => "Submit",
+        }
+    }
+}
+
+/// Where a key is pressed, each context has its own section in the keymap
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+pub enum KeyContext {
+    Global,
+    // result cards and tables
+    Grid,
+    Details,
+    Popup,
+    // search box and filter line
+    Input,
+}
+
+pub type Bindings = BTreeMap<Action, Vec<Key>>;
+
+/// Keys of every action, per context. A context section only holds its own actions and
+/// overrides of the global ones, anything else falls back to the global section.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "KeymapSections")]
+pub struct Keymap {
+    pub global: Bindings,
+    pub grid: Bindings,
+    pub details: Bindings,
+    pub popup: Bindings,
+    pub input: Bindings,
+}
+
+/// Sections as written in the config file, merged over the default keymap
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct KeymapSections {
+    global: Bindings,
+    grid: Bindings,
+    details: Bindings,
+    popup: Bindings,
+    input: Bindings,
+}
+
+/// A key reaching two actions of the same context
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyConflict {
+    pub context: KeyContext,
+    pub key: Key,
+    pub actions: (Action, Action),
+}
+
+impl std::fmt::Display for KeyConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "'{}' is bound to both {} and {} in the {} keys",
+            self.key,
+            Into::<&str>::into(self.actions.0),
+            Into::<&str>::into(self.actions.1),
+            Into::<&str>::into(self.context),
+        )
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        use Action::*;
+        Self {
+            global: BTreeMap::from([
+                (Quit, vec![Key::Char('q'), Key::Ctrl('c')]),
+                (Back, vec![Key::Esc]),
+                (Help, vec![Key::Char('?')]),
+                (Search, vec![Key::Char('/')]),
+                (NextState, vec![Key::Ctrl('p')]),
+                (NextBlock, vec![Key::Tab]),
+                (PreviousBlock, vec![Key::BackTab]),
+                (Up, vec![Key::Up, Key::Char('k')]),
+                (Down, vec![Key::Down, Key::Char('j'), Key::Ctrl('n')]),
+                (Left, vec![Key::Left, Key::Char('h'), Key::Ctrl('b')]),
+                (Right, vec![Key::Right, Key::Char('l'), Key::Ctrl('f')]),
+                (Select, vec![Key::Enter]),
+                (Toggle, vec![Key::Char('s')]),
+                (OpenPopup, vec![Key::Char('r')]),
+                (SwitchMedia, vec![Key::Char('m')]),
+                (StatsDashboard, vec![Key::Char('d')]),
+                (CommandPalette, vec![Key::Char(':'), Key::Ctrl('k')]),
+                (ToggleBookmark, vec![Key::Char('b')]),
+                (IncrementProgress, vec![Key::Char('+')]),
+                (Undo, vec![Key::Char('u')]),
+            ]),
+            grid: BTreeMap::from([
+                (Filter, vec![Key::Char('f')]),
+                (ToggleLayout, vec![Key::Char('t')]),
+                (CompareMode, vec![Key::Char('c')]),
+                (ViewComparison, vec![Key::Char('v')]),
+                (ClearHistory, vec![Key::Char('x')]),
+                (Mark, vec![Key::Char(' ')]),
+                (BulkEdit, vec![Key::Char('e')]),
+            ]),
+            details: BTreeMap::new(),
+            popup: BTreeMap::from([
+                (Confirm, vec![Key::Char('y')]),
+                (Cancel, vec![Key::Char('n')]),
+            ]),
+            input: BTreeMap::from([
+                (CursorLeft, vec![Key::Left, Key::Ctrl('b')]),
+                (CursorRight, vec![Key::Right, Key::Ctrl('f')]),
+                (CursorStart, vec![Key::Ctrl('a')]),
+                (CursorEnd, vec![Key::Ctrl('e')]),
+                (DeleteBackward, vec![Key::Backspace, Key::Ctrl('h')]),
+                (DeleteForward, vec![Key::Delete, Key::Ctrl('d')]),
+                (DeleteWord, vec![Key::Ctrl('w')]),
+                (DeleteToStart, vec![Key::Ctrl('u')]),
+                (DeleteToEnd, vec![Key::Ctrl('k')]),
+                (ClearInput, vec![Key::Ctrl('l')]),
+                (Submit, vec![Key::Enter]),
+                (Cancel, vec![Key::Esc]),
+            ]),
+        }
+    }
+}
+
+impl From<KeymapSections> for Keymap {
+    fn from(sections: KeymapSections) -> Self {
+        let mut keymap = Self::default();
+        for (context, bindings) in [
+            (KeyContext::Global, sections.global),
+            (KeyContext::Grid, sections.grid),
+            (KeyContext::Details, sections.details),
+            (KeyContext::Popup, sections.popup),
+            (KeyContext::Input, sections.input),
+        ] {
+            // keys are matched lowercased, see main
+            let bindings = bindings.into_iter().map(|(action, keys)| {
+                (
+                    action,
+                    keys.into_iter()
+                        .map(crate::handlers::common::get_lowercase_key)
+                        .collect(),
+                )
+            });
+            keymap.section_mut(context).extend(bindings);
+        }
+        keymap
+    }
+}
+
+impl Keymap {
+    pub fn section(&self, context: KeyContext) -> &Bindings {
+        match context {
+            KeyContext::Global => &self.global,
+            KeyContext::Grid => &self.grid,
+            KeyContext::Details => &self.details,
+            KeyContext::Popup => &self.popup,
+            KeyContext::Input => &self.input,
+        }
+    }
+
+    pub fn section_mut(&mut self, context: KeyContext) -> &mut Bindings {
+        match context {
+            KeyContext::Global => &mut self.global,
+            KeyContext::Grid => &mut self.grid,
+            KeyContext::Details => &mut self.details,
+            KeyContext::Popup => &mut self.popup,
+            KeyContext::Input => &mut self.input,
+        }
+    }
+
+    /// keys of an action in a context, the global ones if the context doesn't bind it
+    pub fn keys(&self, context: KeyContext, action: Action) -> &[Key] {
+        self.section(context)
+            .get(&action)
+            .or_else(|| self.global.get(&action))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn matches(&self, context: KeyContext, action: Action, key: Key) -> bool {
+        self.keys(context, action).contains(&key)
+    }
+
+    /// first key of an action, to show it or to replay it
+    pub fn first_key(&self, context: KeyContext, action: Action) -> Option<Key> {
+        self.keys(context, action).first().copied()
+    }
+
+    /// every action a key press can reach in a context
+    fn reachable(&self, context: KeyContext) -> Bindings {
+        let mut bindings = match context {
+            // typing takes every key but the block navigation
+            KeyContext::Input => self
+                .global
+                .iter()
+                .filter(|(action, _)| matches!(action, Action::NextBlock | Action::PreviousBlock))
+                .map(|(action, keys)| (*action, keys.clone()))
+                .collect(),
+            _ => self.global.clone(),
+        };
+        if context != KeyContext::Global {
+            bindings.extend(self.section(context).clone());
+        }
+        bindings
+    }
+
+    /// bindings of a context with the section they come from, its own first,
+    /// or of every section when no context is given
+    pub fn bindings(&self, context: Option<KeyContext>) -> Vec<(KeyContext, Action, Vec<Key>)> {
+        let Some(context) = context else {
+            return KeyContext::iter()
+                .flat_map(|c| {
+                    self.section(c)
+                        .iter()
+                        .map(move |(action, keys)| (c, *action, keys.clone()))
+                })
+                .collect();
+        };
+        let own = self.section(context);
+        let mut bindings: Vec<(KeyContext, Action, Vec<Key>)> = vec![];
+        if context != KeyContext::Global {
+            bindings.extend(
+                own.iter()
+                    .map(|(action, keys)| (context, *action, keys.clone())),
+            );
+        }
+        bindings.extend(
+            self.reachable(context)
+                .into_iter()
+                .filter(|(action, _)| context == KeyContext::Global || !own.contains_key(action))
+                .map(|(action, keys)| (KeyContext::Global, action, keys)),
+        );
+        bindings
+    }
+
+    /// keys bound to more than one action of the same context
+    pub fn conflicts(&self) -> Vec<KeyConflict> {
+        let mut conflicts = vec![];
+        for context in KeyContext::iter() {
+            let mut seen: Vec<(Key, Action)> = vec![];
+            for (action, keys) in self.reachable(context) {
+                for key in keys {
+                    match seen.iter().find(|(k, a)| *k == key && *a != action) {
+                        Some((_, other)) => conflicts.push(KeyConflict {
+                            context,
+                            key,
+                            actions: (*other, action),
+                        }),
+                        None => seen.push((key, action)),
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_keymap_has_no_conflicts() {
+        assert_eq!(Keymap::default().conflicts(), vec![]);
+    }
+
+    #[test]
+    fn test_example_config_keymap() {
+        let example: serde_yaml::Value =
+            serde_yaml::from_str(include_str!("../../config.example.yml")).unwrap();
+        let keymap: Keymap = serde_yaml::from_value(example["keymap"].clone()).unwrap();
+        assert_eq!(keymap, Keymap::default());
+    }
+
+    #[test]
+    fn test_sections_are_merged_over_defaults() {
+        let keymap: Keymap = serde_yaml::from_str(
+            "
+grid:
+  filter: [!char 'G']
+details:
+  up: [!char 'w']
+",
+        )
+        .unwrap();
+        assert!(keymap.matches(KeyContext::Grid, Action::Filter, Key::Char('g')));
+        assert!(keymap.matches(KeyContext::Grid, Action::ToggleLayout, Key::Char('t')));
+        // the override only applies to its context
+        assert!(keymap.matches(KeyContext::Details, Action::Up, Key::Char('w')));
+        assert!(!keymap.matches(KeyContext::Grid, Action::Up, Key::Char('w')));
+        assert!(keymap.matches(KeyContext::Grid, Action::Up, Key::Char('k')));
+    }
+
+    #[test]
+    fn test_conflicts_with_global_keys() {
+        let keymap: Keymap = serde_yaml::from_str("grid:\n  filter: [!char '/']").unwrap();
+        let conflicts = keymap.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].context, KeyContext::Grid);
+        assert_eq!(conflicts[0].key, Key::Char('/'));
+
+        // typing doesn't reach the global keys
+        let keymap: Keymap = serde_yaml::from_str("input:\n  clear_input: [!char 'q']").unwrap();
+        assert_eq!(keymap.conflicts(), vec![]);
+    }
+
+    #[test]
+    fn test_help_bindings_of_a_context() {
+        let keymap: Keymap = serde_yaml::from_str("details:\n  up: [!char 'w']").unwrap();
+        let bindings = keymap.bindings(Some(KeyContext::Details));
+        assert_eq!(
+            bindings[0],
+            (KeyContext::Details, Action::Up, vec![Key::Char('w')])
+        );
+        // the global up is hidden by the details one
+        assert!(!bindings
+            .iter()
+            .any(|(c, a, _)| *c == KeyContext::Global && *a == Action::Up));
+
+        let input = keymap.bindings(Some(KeyContext::Input));
+        assert!(input.iter().all(|(c, a, _)| *c == KeyContext::Input
+            || matches!(a, Action::NextBlock | Action::PreviousBlock)));
+    }
+}
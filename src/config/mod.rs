@@ -4,6 +4,9 @@ pub mod oauth_config;
 // app config
 pub mod app_config;
 
+// action keys per context
+pub mod keymap;
+
 // pub use app_config::AppConfig;
 pub use oauth_config::AuthConfig;
 
@@ -15,6 +18,11 @@ const CACHE_DIR: &str = ".cache";
 const APP_CACHE_DIR: &str = "mal-cli";
 const PICTURE_CACHE_DIR: &str = "images";
 const DATA_FILE: &str = "mal_data.json";
+const SESSION_FILE: &str = "session.json";
+const BOOKMARKS_FILE: &str = "bookmarks.json";
+const HISTORY_FILE: &str = "history.json";
+const NOTIFIED_FILE: &str = "notified.json";
+const EXPORT_DIR: &str = "exports";
 
 const DEFAULT_PORT: u16 = 2006;
 const DEFAULT_USER_AGENT: &str = "mal-cli";
@@ -37,6 +45,9 @@ pub enum ConfigError {
     IOError(std::io::Error),
 
     InvalidClientIdError,
+
+    /// Represents keys bound to several actions of the same context
+    KeyConflicts(Vec<keymap::KeyConflict>),
 }
 
 impl std::error::Error for ConfigError {
@@ -48,6 +59,7 @@ impl std::error::Error for ConfigError {
             ConfigError::ParseError(_) => None,
             ConfigError::IOError(_) => None,
             ConfigError::InvalidClientIdError => None,
+            ConfigError::KeyConflicts(_) => None,
         }
     }
 }
@@ -61,6 +73,15 @@ impl std::fmt::Display for ConfigError {
             ConfigError::ParseError(ref err) => err.fmt(f),
             ConfigError::IOError(ref err) => err.fmt(f),
             ConfigError::InvalidClientIdError => write!(f, "Invalid client ID provided"),
+            ConfigError::KeyConflicts(ref conflicts) => write!(
+                f,
+                "Conflicting keys: {}",
+                conflicts
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::event::Key;
+use crate::event::{Key, Mouse};
 use crossterm::event::{self, Event as CEvent};
 use std::{
     sync::mpsc,
@@ -23,6 +23,7 @@ impl Default for EventConfig {
 
 pub enum Event<I> {
     Input(I),
+    Mouse(Mouse),
     Tick,
 }
 
@@ -53,9 +54,15 @@ impl Events {
                     .unwrap_or_else(|| Duration::from_secs(0));
 
                 if event::poll(timeout).unwrap() {
-                    if let CEvent::Key(key_event) = event::read().unwrap() {
-                        let key = Key::from(key_event);
-                        if event_tx.send(Event::Input(key)).is_err() {
+                    let event = match event::read().unwrap() {
+                        CEvent::Key(key_event) => Some(Event::Input(Key::from(key_event))),
+                        CEvent::Mouse(mouse_event) => {
+                            Mouse::from_event(mouse_event).map(Event::Mouse)
+                        }
+                        _ => None,
+                    };
+                    if let Some(event) = event {
+                        if event_tx.send(event).is_err() {
                             break;
                         }
                     }
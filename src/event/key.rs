@@ -140,3 +140,17 @@ impl From<KeyEvent> for Key {
         }
     }
 }
+
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Key::Char(' ') => write!(f, "Space"),
+            Key::Char(c) => write!(f, "{}", c),
+            Key::Ctrl(c) => write!(f, "Ctrl+{}", c),
+            Key::Alt(c) => write!(f, "Alt+{}", c),
+            Key::BackTab => write!(f, "Shift+Tab"),
+            Key::Ins => write!(f, "Insert"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
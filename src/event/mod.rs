@@ -1,6 +1,8 @@
 pub mod events;
 pub mod key;
+pub mod mouse;
 pub use self::{
     events::{Event, Events},
     key::Key,
+    mouse::{Mouse, MouseAction},
 };
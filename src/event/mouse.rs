@@ -0,0 +1,33 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MouseAction {
+    Click,
+    ScrollUp,
+    ScrollDown,
+}
+
+/// Mouse event the app reacts to, at a terminal cell
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Mouse {
+    pub action: MouseAction,
+    pub column: u16,
+    pub row: u16,
+}
+
+impl Mouse {
+    /// None for the events we ignore (moves, drags, releases, other buttons)
+    pub fn from_event(event: MouseEvent) -> Option<Self> {
+        let action = match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => MouseAction::Click,
+            MouseEventKind::ScrollUp => MouseAction::ScrollUp,
+            MouseEventKind::ScrollDown => MouseAction::ScrollDown,
+            _ => return None,
+        };
+        Some(Self {
+            action,
+            column: event.column,
+            row: event.row,
+        })
+    }
+}
@@ -0,0 +1,161 @@
+use crate::api::model::{Anime, Manga, Node, PageableData, RankingAnimePair, RankingMangaPair};
+use std::fmt::Debug;
+
+const MATCH_SCORE: i64 = 1;
+const CONSECUTIVE_BONUS: i64 = 5;
+const WORD_START_BONUS: i64 = 3;
+const GAP_PENALTY: i64 = 1;
+
+/// Query typed in the filter line of a list or grid view
+#[derive(Clone, Debug, Default)]
+pub struct FuzzyFilter {
+    pub query: String,
+    // keys go to the filter line
+    pub editing: bool,
+}
+
+impl FuzzyFilter {
+    pub fn is_active(&self) -> bool {
+        self.editing || !self.query.is_empty()
+    }
+}
+
+/// All the titles an entry can be found by
+pub trait Titled {
+    fn titles(&self) -> Vec<&str>;
+}
+
+impl Titled for Anime {
+    fn titles(&self) -> Vec<&str> {
+        let mut titles = vec![self.title.as_str()];
+        if let Some(alternative) = self.alternative_titles.as_ref() {
+            titles.extend(alternative.en.as_deref());
+            titles.extend(alternative.jp.as_deref());
+            titles.extend(alternative.synonyms.iter().flatten().map(String::as_str));
+        }
+        titles
+    }
+}
+
+impl Titled for Manga {
+    fn titles(&self) -> Vec<&str> {
+        let mut titles = vec![self.title.as_str()];
+        if let Some(alternative) = self.alternative_titles.as_ref() {
+            titles.extend(alternative.en.as_deref());
+            titles.extend(alternative.jp.as_deref());
+            titles.extend(alternative.synonyms.iter().flatten().map(String::as_str));
+        }
+        titles
+    }
+}
+
+impl<T: Titled + Clone + Debug> Titled for Node<T> {
+    fn titles(&self) -> Vec<&str> {
+        self.node.titles()
+    }
+}
+
+impl Titled for RankingAnimePair {
+    fn titles(&self) -> Vec<&str> {
+        self.node.titles()
+    }
+}
+
+impl Titled for RankingMangaPair {
+    fn titles(&self) -> Vec<&str> {
+        self.node.titles()
+    }
+}
+
+/// fzf-like score of `query` as a subsequence of `text`, None if it doesn't match
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = text[position..].iter().position(|&c| c == q)? + position;
+        score += MATCH_SCORE;
+        match previous {
+            Some(p) if p + 1 == found => score += CONSECUTIVE_BONUS,
+            Some(p) => score -= GAP_PENALTY * (found - p - 1).min(10) as i64,
+            None => {}
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += WORD_START_BONUS;
+        }
+        previous = Some(found);
+        position = found + 1;
+    }
+    Some(score)
+}
+
+/// best score over all the titles of an entry
+pub fn best_score(query: &str, entry: &impl Titled) -> Option<i64> {
+    entry
+        .titles()
+        .into_iter()
+        .filter_map(|title| fuzzy_score(query, title))
+        .max()
+}
+
+/// Entries matching `query`, best matches first, the order is kept for an empty query
+pub fn filter_page<T>(page: &PageableData<Vec<T>>, query: &str) -> PageableData<Vec<T>>
+where
+    T: Titled + Clone + Debug,
+{
+    if query.trim().is_empty() {
+        return page.clone();
+    }
+    let mut matches: Vec<(i64, &T)> = page
+        .data
+        .iter()
+        .filter_map(|entry| best_score(query, entry).map(|score| (score, entry)))
+        .collect();
+    // stable, ties keep the page order
+    matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    PageableData {
+        data: matches
+            .into_iter()
+            .map(|(_, entry)| entry.clone())
+            .collect(),
+        paging: page.paging.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("sgt", "Steins;Gate").is_some());
+        assert!(fuzzy_score("gts", "Steins;Gate").is_none());
+        // consecutive and word start matches rank higher
+        assert!(
+            fuzzy_score("gate", "Steins;Gate").unwrap()
+                > fuzzy_score("gate", "Gin no Tamashii e").unwrap_or(0)
+        );
+        assert!(fuzzy_score("", "anything").is_some());
+    }
+
+    #[test]
+    fn test_filter_page_alternative_titles() {
+        let page: PageableData<Vec<Node<Anime>>> = serde_json::from_value(serde_json::json!({
+            "data": [
+                { "node": { "id": 1, "title": "Shingeki no Kyojin",
+                    "alternative_titles": { "en": "Attack on Titan", "synonyms": ["AoT"] } } },
+                { "node": { "id": 2, "title": "Cowboy Bebop" } },
+            ],
+            "paging": {}
+        }))
+        .unwrap();
+
+        let filtered = filter_page(&page, "attack");
+        assert_eq!(filtered.data.len(), 1);
+        assert_eq!(filtered.data[0].node.id, 1);
+        assert_eq!(filter_page(&page, "aot").data[0].node.id, 1);
+        assert_eq!(filter_page(&page, "").data.len(), 2);
+    }
+}
@@ -1,5 +1,8 @@
 use super::common;
-use crate::app::{ActiveDisplayBlock, App, Data, ANIME_OPTIONS, ANIME_OPTIONS_RANGE};
+use crate::app::{
+    ActiveDisplayBlock, App, Data, ANIME_OPTIONS, ANIME_OPTIONS_RANGE, NO_MENU_SELECTION,
+};
+use crate::config::keymap::Action;
 
 use crate::event::Key;
 use crate::network::IoEvent;
@@ -7,7 +10,7 @@ use crate::network::IoEvent;
 pub fn handler(key: Key, app: &mut App) {
     match key {
         // k if common::right_event(k) => common::handle_right_event(app),
-        k if common::down_event(k) => {
+        k if app.is_action(Action::Down, k) => {
             // calculate the next index in the list
             let next_index = ANIME_OPTIONS_RANGE.start
                 + common::on_down_press(
@@ -16,7 +19,7 @@ pub fn handler(key: Key, app: &mut App) {
                 );
             app.library.selected_index = next_index;
         }
-        k if common::up_event(k) => {
+        k if app.is_action(Action::Up, k) => {
             // calculate the next index in the list
             let next_index = ANIME_OPTIONS_RANGE.start
                 + common::on_up_press(
@@ -26,7 +29,7 @@ pub fn handler(key: Key, app: &mut App) {
             app.library.selected_index = next_index;
         }
 
-        Key::Enter => {
+        k if app.is_action(Action::Select, k) => {
             match app.library.selected_index {
                 // Seasonal
                 0 => get_seasonal(app),
@@ -37,7 +40,7 @@ pub fn handler(key: Key, app: &mut App) {
                 // This is required because Rust can't tell if this pattern in exhaustive
                 _ => {}
             };
-            app.library.selected_index = 9;
+            app.library.selected_index = NO_MENU_SELECTION;
         }
 
         _ => (),
@@ -163,7 +166,13 @@ fn is_manga_ranking_data_available(app: &App) -> (bool, bool, Option<u16>) {
     (false, false, None)
 }
 
-fn get_suggestion(app: &mut App) {
+pub fn get_suggestion(app: &mut App) {
+    // suggestions are based on the user's list
+    if app.is_anonymous {
+        app.api_error = "Login to get suggestions".to_string();
+        app.active_display_block = ActiveDisplayBlock::Error;
+        return;
+    }
     app.reset_result_index();
 
     let (is_data_available, is_next, index) = is_suggestion_data_available(app);
@@ -1,22 +1,6 @@
 // use crate::app::{ActiveBlock, App};
 use crate::event::Key;
 
-pub fn down_event(key: Key) -> bool {
-    matches!(key, Key::Down | Key::Char('j') | Key::Ctrl('n'))
-}
-
-pub fn up_event(key: Key) -> bool {
-    matches!(key, Key::Up | Key::Char('k') | Key::Ctrl('p'))
-}
-
-pub fn left_event(key: Key) -> bool {
-    matches!(key, Key::Left | Key::Char('h') | Key::Ctrl('b'))
-}
-
-pub fn right_event(key: Key) -> bool {
-    matches!(key, Key::Right | Key::Char('l') | Key::Ctrl('f'))
-}
-
 pub fn on_down_press<T>(selection_data: &[T], selection_index: Option<usize>) -> usize {
     match selection_index {
         Some(selection_index) => {
@@ -50,10 +34,6 @@ pub fn on_up_press<T>(selection_data: &[T], selection_index: Option<usize>) -> u
     }
 }
 
-pub fn quit_event(key: Key) -> bool {
-    matches!(key, Key::Char('q') | Key::Ctrl('C') | Key::Ctrl('c'))
-}
-
 pub fn get_lowercase_key(key: Key) -> Key {
     match key {
         Key::Char(c) => Key::Char(c.to_ascii_lowercase()),
@@ -4,16 +4,24 @@ use crate::{
         UpdateUserAnimeListStatusQuery, UpdateUserMangaStatus,
     },
     app::{ActiveAnimeDetailBlock, ActiveDisplayBlock, ActiveMangaDetailBlock, App, DetailPopup},
+    bookmarks::MediaKind,
+    config::keymap::Action,
     event::Key,
-    handlers::common,
     network::IoEvent,
+    progress,
 };
 
 pub fn handler(key: Key, app: &mut App) {
     match key {
-        k if k == app.app_config.keys.toggle => change_tab(app),
+        k if app.is_action(Action::Toggle, k) => change_tab(app),
 
-        k if k == Key::Enter || k == app.app_config.keys.open_popup => {
+        k if app.is_action(Action::ToggleBookmark, k) && !app.popup => app.toggle_bookmark(),
+
+        k if app.is_action(Action::IncrementProgress, k) && !app.popup => app.increment_progress(),
+
+        k if app.is_action(Action::Undo, k) && !app.popup => app.revert_list_change(0),
+
+        k if app.is_action(Action::Select, k) || app.is_action(Action::OpenPopup, k) => {
             if app.popup {
                 handle_edit(app)
             } else {
@@ -21,7 +29,7 @@ pub fn handler(key: Key, app: &mut App) {
             }
         }
 
-        k if common::down_event(k) => match app.active_anime_detail_block {
+        k if app.is_action(Action::Down, k) => match app.active_anime_detail_block {
             ActiveAnimeDetailBlock::SideInfo => {
                 app.anime_details_info_scroll_view_state.scroll_down()
             }
@@ -44,7 +52,7 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             }
         },
-        k if common::up_event(k) => match app.active_anime_detail_block {
+        k if app.is_action(Action::Up, k) => match app.active_anime_detail_block {
             ActiveAnimeDetailBlock::SideInfo => {
                 app.anime_details_info_scroll_view_state.scroll_up()
             }
@@ -75,7 +83,7 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             }
         },
-        k if common::right_event(k) && !app.popup => {
+        k if app.is_action(Action::Right, k) && !app.popup => {
             app.active_anime_detail_block = match app.active_anime_detail_block {
                 ActiveAnimeDetailBlock::AddToList => ActiveAnimeDetailBlock::Rate,
                 ActiveAnimeDetailBlock::Rate => ActiveAnimeDetailBlock::Episodes,
@@ -84,7 +92,7 @@ pub fn handler(key: Key, app: &mut App) {
                 ActiveAnimeDetailBlock::Synopsis => ActiveAnimeDetailBlock::SideInfo,
             }
         }
-        k if common::left_event(k) && !app.popup => {
+        k if app.is_action(Action::Left, k) && !app.popup => {
             app.active_anime_detail_block = match app.active_anime_detail_block {
                 ActiveAnimeDetailBlock::AddToList => ActiveAnimeDetailBlock::Episodes,
                 ActiveAnimeDetailBlock::Rate => ActiveAnimeDetailBlock::AddToList,
@@ -111,6 +119,10 @@ fn change_tab(app: &mut App) {
 }
 
 fn open_popup(app: &mut App) {
+    // list popups need a logged in user
+    if app.is_anonymous {
+        return;
+    }
     app.result_popup = false;
     match app.active_anime_detail_block {
         ActiveAnimeDetailBlock::AddToList => {
@@ -233,14 +245,19 @@ pub fn handle_edit(app: &mut App) {
 
                     _ => Some(anime_query_with_one_field(None, None, None)),
                 };
-            if anime_update_query.is_none() {
+            let Some(mut query) = anime_update_query else {
                 return;
-            }
-            let anime_id = app.anime_details.as_ref().unwrap().id;
-            app.dispatch(IoEvent::UpdateAnimeListStatus(
-                anime_id,
-                anime_update_query.unwrap(),
-            ));
+            };
+            let anime = app.anime_details.as_ref().unwrap();
+            let anime_id = anime.id;
+            let ask_score = progress::apply_anime_policy(
+                &app.app_config.list_updates,
+                anime,
+                &mut query,
+                progress::today(),
+            );
+            app.score_prompt = ask_score.then_some((MediaKind::Anime, anime_id));
+            app.dispatch(IoEvent::UpdateAnimeListStatus(anime_id, query));
             app.popup_is_loading = true;
             app.result_popup = true;
         }
@@ -348,15 +365,19 @@ pub fn handle_edit(app: &mut App) {
                     }
                     _ => None,
                 };
-            if manga_update_query.is_none() {
+            let Some(mut query) = manga_update_query else {
                 return;
-            }
-
-            let manga_id = app.manga_details.as_ref().unwrap().id;
-            app.dispatch(IoEvent::UpdateMangaListStatus(
-                manga_id,
-                manga_update_query.unwrap(),
-            ));
+            };
+            let manga = app.manga_details.as_ref().unwrap();
+            let manga_id = manga.id;
+            let ask_score = progress::apply_manga_policy(
+                &app.app_config.list_updates,
+                manga,
+                &mut query,
+                progress::today(),
+            );
+            app.score_prompt = ask_score.then_some((MediaKind::Manga, manga_id));
+            app.dispatch(IoEvent::UpdateMangaListStatus(manga_id, query));
             app.result_popup = true;
             app.popup_is_loading = true;
         }
@@ -408,6 +429,8 @@ pub fn user_list_to_anime_query(
         priority: my_list.priority,
         rewatch_value: my_list.rewatch_value,
         tags: my_list.tags.clone().map(|v| v.join(",")),
+        start_date: None,
+        finish_date: None,
     }
 }
 
@@ -429,6 +452,8 @@ pub fn user_list_to_manga_query(
         comments: my_list.comments.clone(),
         is_rereading: Some(my_list.is_rereading),
         num_times_reread: my_list.num_times_reread,
+        start_date: None,
+        finish_date: None,
     }
 }
 
@@ -449,6 +474,8 @@ pub fn manga_query_with_one_field(
         is_rereading: None,
         priority: None,
         tags: None,
+        start_date: None,
+        finish_date: None,
     }
 }
 
@@ -467,5 +494,7 @@ pub fn anime_query_with_one_field(
         rewatch_value: None,
         tags: None,
         comments: None,
+        start_date: None,
+        finish_date: None,
     }
 }
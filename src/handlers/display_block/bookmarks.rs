@@ -0,0 +1,72 @@
+use crate::{
+    app::{ActiveBlock, ActiveDisplayBlock, App, Data, Route},
+    bookmarks::MediaKind,
+    config::keymap::Action,
+    event::Key,
+    network::IoEvent,
+};
+
+pub fn handler(key: Key, app: &mut App) {
+    let len = app.bookmarks.entries.len();
+    let selected = app.search_results.selected_display_card_index.unwrap_or(0);
+    match key {
+        k if app.is_action(Action::Down, k) => {
+            app.search_results.selected_display_card_index =
+                Some((selected + 1).min(len.saturating_sub(1)));
+        }
+
+        k if app.is_action(Action::Up, k) => {
+            app.search_results.selected_display_card_index = Some(selected.saturating_sub(1));
+        }
+
+        k if app.is_action(Action::Select, k) => {
+            let Some(bookmark) = app.bookmarks.sorted().get(selected).map(|b| (b.kind, b.id))
+            else {
+                return;
+            };
+            app.active_display_block = ActiveDisplayBlock::Loading;
+            app.dispatch(match bookmark {
+                (MediaKind::Anime, id) => IoEvent::GetAnime(id),
+                (MediaKind::Manga, id) => IoEvent::GetManga(id),
+            });
+        }
+
+        k if app.is_action(Action::ToggleBookmark, k) => {
+            let Some((kind, id)) = app.bookmarks.sorted().get(selected).map(|b| (b.kind, b.id))
+            else {
+                return;
+            };
+            if let Some(bookmark) = app.bookmarks.remove(kind, id) {
+                app.status_message = Some(match app.save_bookmarks() {
+                    Ok(()) => format!("Removed the bookmark of {}", bookmark.title),
+                    Err(e) => e,
+                });
+            }
+            let last = app.bookmarks.entries.len().saturating_sub(1);
+            app.search_results.selected_display_card_index = Some(selected.min(last));
+        }
+
+        _ => {}
+    }
+}
+
+/// opens the bookmarks view, they are read from the app so the route has no data
+pub fn get_bookmarks(app: &mut App) {
+    let is_current_route = app
+        .get_current_route()
+        .is_some_and(|r| r.block == ActiveDisplayBlock::Bookmarks);
+    app.active_block = ActiveBlock::DisplayBlock;
+    if is_current_route {
+        app.active_display_block = ActiveDisplayBlock::Bookmarks;
+        return;
+    }
+    app.reset_result_index();
+    app.push_navigation_stack(Route {
+        data: Some(Data::Bookmarks),
+        block: ActiveDisplayBlock::Bookmarks,
+        title: "Bookmarks".to_string(),
+        image: None,
+    });
+    app.active_display_block = ActiveDisplayBlock::Bookmarks;
+    app.display_block_title = "Bookmarks".to_string();
+}
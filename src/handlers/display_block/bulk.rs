@@ -0,0 +1,130 @@
+use crate::{
+    app::{ActiveDisplayBlock, App},
+    bulk::{BulkAction, BulkPopup, READ_STATUSES, WATCH_STATUSES},
+    config::keymap::Action,
+    event::Key,
+    handlers::common,
+};
+
+/// marks the selected card of my list, or unmarks it
+pub fn toggle_mark(app: &mut App) {
+    let Some(id) = app.selected_my_list_entry() else {
+        return;
+    };
+    app.bulk.toggle(id);
+    app.status_message = Some(format!("{} marked", app.bulk.marked.len()));
+}
+
+pub fn open(app: &mut App) {
+    if app.list_user.is_some() || app.is_anonymous {
+        return;
+    }
+    if app.bulk.marked.is_empty() {
+        let key = app
+            .app_config
+            .keymap
+            .first_key(app.display_key_context(), Action::Mark)
+            .map_or_else(|| "the mark key".to_string(), |key| key.to_string());
+        app.status_message = Some(format!("Mark entries with {} first", key));
+        return;
+    }
+    if app.bulk_running {
+        app.status_message = Some("A bulk edit is still running".to_string());
+        return;
+    }
+    app.bulk.open(BulkPopup::Actions);
+}
+
+/// bulk edit popup of my lists, from the action to the summary of the calls
+pub fn handle_popup(key: Key, app: &mut App) {
+    let is_anime = app.active_display_block == ActiveDisplayBlock::UserAnimeList;
+    let Some(popup) = app.bulk.popup.clone() else {
+        return;
+    };
+    if let Some(options) = popup.options(is_anime) {
+        match key {
+            k if app.is_action(Action::Down, k) => {
+                app.bulk.selected = common::on_down_press(&options, Some(app.bulk.selected));
+            }
+            k if app.is_action(Action::Up, k) => {
+                app.bulk.selected = common::on_up_press(&options, Some(app.bulk.selected));
+            }
+            k if app.is_action(Action::Cancel, k) => app.bulk.popup = None,
+            k if app.is_action(Action::Select, k) => select(app, popup, is_anime),
+            _ => {}
+        }
+        return;
+    }
+    match popup {
+        BulkPopup::ConfirmDelete => match key {
+            k if app.is_action(Action::Confirm, k) || app.is_action(Action::Select, k) => {
+                app.start_bulk(BulkAction::Delete)
+            }
+            k if app.is_action(Action::Cancel, k) => app.bulk.popup = None,
+            _ => {}
+        },
+        // the calls go on when the popup is closed with the back key
+        BulkPopup::Running(progress)
+            if progress.is_finished()
+                && (app.is_action(Action::Select, key) || app.is_action(Action::Cancel, key)) =>
+        {
+            app.bulk.popup = None
+        }
+        _ => {}
+    }
+}
+
+fn select(app: &mut App, popup: BulkPopup, is_anime: bool) {
+    let selected = app.bulk.selected;
+    match popup {
+        BulkPopup::Actions => app.bulk.open(match selected {
+            0 => BulkPopup::Status,
+            1 => BulkPopup::Score,
+            2 => BulkPopup::Tag(String::new()),
+            _ => BulkPopup::ConfirmDelete,
+        }),
+        BulkPopup::Status if is_anime => {
+            app.start_bulk(BulkAction::WatchStatus(WATCH_STATUSES[selected].clone()))
+        }
+        BulkPopup::Status => {
+            app.start_bulk(BulkAction::ReadStatus(READ_STATUSES[selected].clone()))
+        }
+        BulkPopup::Score => app.start_bulk(BulkAction::Score(selected as u8)),
+        _ => {}
+    }
+}
+
+/// keys typed in the tag line of the popup
+pub fn tag_handler(key: Key, app: &mut App) {
+    let Some(BulkPopup::Tag(mut tag)) = app.bulk.popup.clone() else {
+        return;
+    };
+    match key {
+        k if app.is_action(Action::Cancel, k) => {
+            app.bulk.popup = None;
+            return;
+        }
+
+        k if app.is_action(Action::Submit, k) => {
+            // MAL separates the tags with commas
+            let tag = tag.trim().replace(',', " ");
+            if !tag.is_empty() {
+                app.start_bulk(BulkAction::AddTag(tag));
+            }
+            return;
+        }
+
+        k if app.is_action(Action::DeleteBackward, k) => {
+            tag.pop();
+        }
+
+        k if app.is_action(Action::DeleteToStart, k) || app.is_action(Action::ClearInput, k) => {
+            tag.clear();
+        }
+
+        Key::Char(c) => tag.push(c),
+
+        _ => return,
+    }
+    app.bulk.popup = Some(BulkPopup::Tag(tag));
+}
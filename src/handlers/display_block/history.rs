@@ -0,0 +1,76 @@
+use crate::{
+    app::{ActiveBlock, ActiveDisplayBlock, App, Data, Route},
+    bookmarks::MediaKind,
+    config::keymap::Action,
+    event::Key,
+    handlers::fuzzy_filter,
+    network::IoEvent,
+};
+
+pub fn handler(key: Key, app: &mut App) {
+    let len = app.history.matching(&app.fuzzy_filter.query).len();
+    let selected = app.search_results.selected_display_card_index.unwrap_or(0);
+    match key {
+        k if app.is_action(Action::Down, k) => {
+            app.search_results.selected_display_card_index =
+                Some((selected + 1).min(len.saturating_sub(1)));
+        }
+
+        k if app.is_action(Action::Up, k) => {
+            app.search_results.selected_display_card_index = Some(selected.saturating_sub(1));
+        }
+
+        k if app.is_action(Action::Select, k) => open_viewed(app, selected),
+
+        k if app.is_action(Action::Filter, k) => fuzzy_filter::open(app),
+
+        k if app.is_action(Action::ClearHistory, k) => {
+            app.history.clear();
+            app.status_message = Some(match app.save_history() {
+                Ok(()) => "Cleared the viewed history".to_string(),
+                Err(e) => e,
+            });
+            app.search_results.selected_display_card_index = Some(0);
+        }
+
+        _ => {}
+    }
+}
+
+/// opens the entry at `index` of the history narrowed by the filter
+pub fn open_viewed(app: &mut App, index: usize) {
+    let Some(viewed) = app
+        .history
+        .matching(&app.fuzzy_filter.query)
+        .get(index)
+        .map(|v| (v.kind, v.id))
+    else {
+        return;
+    };
+    app.active_display_block = ActiveDisplayBlock::Loading;
+    app.dispatch(match viewed {
+        (MediaKind::Anime, id) => IoEvent::GetAnime(id),
+        (MediaKind::Manga, id) => IoEvent::GetManga(id),
+    });
+}
+
+/// opens the history view, read from the app like the bookmarks
+pub fn get_history(app: &mut App) {
+    let is_current_route = app
+        .get_current_route()
+        .is_some_and(|r| r.block == ActiveDisplayBlock::History);
+    app.active_block = ActiveBlock::DisplayBlock;
+    if is_current_route {
+        app.active_display_block = ActiveDisplayBlock::History;
+        return;
+    }
+    app.reset_result_index();
+    app.push_navigation_stack(Route {
+        data: Some(Data::History),
+        block: ActiveDisplayBlock::History,
+        title: "History".to_string(),
+        image: None,
+    });
+    app.active_display_block = ActiveDisplayBlock::History;
+    app.display_block_title = "History".to_string();
+}
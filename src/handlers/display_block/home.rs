@@ -0,0 +1,66 @@
+use crate::{
+    app::{ActiveDisplayBlock, App},
+    config::{app_config::HomeWidget, keymap::Action},
+    event::Key,
+    handlers::palette,
+    network::IoEvent,
+};
+
+use super::history::open_viewed;
+
+/// Left/Right move between the widgets, Up/Down between the entries of the focused one
+pub fn handler(key: Key, app: &mut App) {
+    let widgets = app.app_config.home.widgets.clone();
+    let Some(&widget) = widgets.get(app.home.focused) else {
+        return;
+    };
+    let len = app.home_widget_len(widget);
+    match key {
+        k if app.is_action(Action::Right, k) => {
+            app.home.focused = (app.home.focused + 1).min(widgets.len() - 1);
+            app.home.selected = 0;
+        }
+
+        k if app.is_action(Action::Left, k) => {
+            app.home.focused = app.home.focused.saturating_sub(1);
+            app.home.selected = 0;
+        }
+
+        k if app.is_action(Action::Down, k) => {
+            app.home.selected = (app.home.selected + 1).min(len.saturating_sub(1));
+        }
+
+        k if app.is_action(Action::Up, k) => {
+            app.home.selected = app.home.selected.saturating_sub(1);
+        }
+
+        k if app.is_action(Action::Select, k) && app.home.selected < len => {
+            open_entry(app, widget, app.home.selected)
+        }
+
+        _ => {}
+    }
+}
+
+fn open_entry(app: &mut App, widget: HomeWidget, index: usize) {
+    let entries = match widget {
+        HomeWidget::Watching => &app.home.watching,
+        HomeWidget::AiringThisSeason => &app.home.airing,
+        HomeWidget::RecentUpdates => &app.home.recent_updates,
+        HomeWidget::RecentlyViewed => return open_viewed(app, index),
+        HomeWidget::QuickActions => {
+            if let Some(command) = app.home_quick_actions().get(index).cloned() {
+                palette::run(command, app);
+            }
+            return;
+        }
+    };
+    if let Some(id) = entries
+        .as_ref()
+        .and_then(|entries| entries.get(index))
+        .map(|anime| anime.id)
+    {
+        app.active_display_block = ActiveDisplayBlock::Loading;
+        app.dispatch(IoEvent::GetAnime(id));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    app::{ActiveBlock, ActiveDisplayBlock, App, Data, Route},
+    config::keymap::Action,
+    event::Key,
+};
+
+pub fn handler(key: Key, app: &mut App) {
+    let len = app.list_changes.entries.len();
+    let selected = app.search_results.selected_display_card_index.unwrap_or(0);
+    match key {
+        k if app.is_action(Action::Down, k) => {
+            app.search_results.selected_display_card_index =
+                Some((selected + 1).min(len.saturating_sub(1)));
+        }
+
+        k if app.is_action(Action::Up, k) => {
+            app.search_results.selected_display_card_index = Some(selected.saturating_sub(1));
+        }
+
+        k if app.is_action(Action::Select, k) => revert(app, selected),
+
+        k if app.is_action(Action::Undo, k) => revert(app, 0),
+
+        _ => {}
+    }
+}
+
+fn revert(app: &mut App, index: usize) {
+    let selected = app.search_results.selected_display_card_index.unwrap_or(0);
+    app.revert_list_change(index);
+    let last = app.list_changes.entries.len().saturating_sub(1);
+    app.search_results.selected_display_card_index = Some(selected.min(last));
+}
+
+/// opens the list changes view, read from the app like the viewed history
+pub fn get_list_changes(app: &mut App) {
+    let is_current_route = app
+        .get_current_route()
+        .is_some_and(|r| r.block == ActiveDisplayBlock::ListChanges);
+    app.active_block = ActiveBlock::DisplayBlock;
+    if is_current_route {
+        app.active_display_block = ActiveDisplayBlock::ListChanges;
+        return;
+    }
+    app.reset_result_index();
+    app.push_navigation_stack(Route {
+        data: Some(Data::ListChanges),
+        block: ActiveDisplayBlock::ListChanges,
+        title: "List Changes".to_string(),
+        image: None,
+    });
+    app.active_display_block = ActiveDisplayBlock::ListChanges;
+    app.display_block_title = "List Changes".to_string();
+}
@@ -0,0 +1,122 @@
+use crate::{
+    app::{App, Data, LIST_VIEW_OPTIONS},
+    config::keymap::Action,
+    event::Key,
+    handlers::common,
+    list_view::{collect_options, ListEntry, ListView},
+};
+
+pub fn open_popup(app: &mut App) {
+    app.popup = true;
+    app.list_view_popup_index = 0;
+}
+
+/// sort & filter popup of the user lists, changes are applied right away and saved on close
+pub fn handle_popup(key: Key, app: &mut App, is_anime: bool) {
+    match key {
+        k if app.is_action(Action::Down, k) => {
+            app.list_view_popup_index =
+                common::on_down_press(&LIST_VIEW_OPTIONS, Some(app.list_view_popup_index));
+        }
+        k if app.is_action(Action::Up, k) => {
+            app.list_view_popup_index =
+                common::on_up_press(&LIST_VIEW_OPTIONS, Some(app.list_view_popup_index));
+        }
+        k if app.is_action(Action::Right, k) || app.is_action(Action::Toggle, k) => {
+            change_option(app, is_anime, true)
+        }
+        k if app.is_action(Action::Left, k) => change_option(app, is_anime, false),
+        k if app.is_action(Action::Select, k) => {
+            if app.list_view_popup_index == LIST_VIEW_OPTIONS.len() - 1 {
+                change_option(app, is_anime, true);
+            } else {
+                app.popup = false;
+                app.save_list_views();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn change_option(app: &mut App, is_anime: bool, forward: bool) {
+    let options = get_filter_options(app, is_anime);
+    let view = if is_anime {
+        &mut app.app_config.list_views.anime
+    } else {
+        &mut app.app_config.list_views.manga
+    };
+    let filter = &mut view.filter;
+
+    match app.list_view_popup_index {
+        0 => {
+            view.sort = if forward {
+                view.sort.next()
+            } else {
+                view.sort.previous()
+            }
+        }
+        1 => view.reverse = !view.reverse,
+        2 => filter.genre = cycle(&filter.genre, &options.genres, forward),
+        3 => filter.media_type = cycle(&filter.media_type, &options.media_types, forward),
+        4 => filter.status = cycle(&filter.status, &options.statuses, forward),
+        5 => filter.min_score = cycle_score(filter.min_score, forward).min(filter.max_score),
+        6 => filter.max_score = cycle_score(filter.max_score, forward).max(filter.min_score),
+        7 => filter.tag = cycle(&filter.tag, &options.tags, forward),
+        _ => *view = ListView::default(),
+    }
+    app.apply_list_view();
+}
+
+/// None (all) then each option in order
+fn cycle(current: &Option<String>, options: &[String], forward: bool) -> Option<String> {
+    let len = options.len() + 1;
+    let index = current
+        .as_ref()
+        .and_then(|c| options.iter().position(|o| o == c))
+        .map_or(0, |i| i + 1);
+    let next = if forward {
+        (index + 1) % len
+    } else {
+        (index + len - 1) % len
+    };
+    next.checked_sub(1).map(|i| options[i].clone())
+}
+
+fn cycle_score(score: u8, forward: bool) -> u8 {
+    if forward {
+        (score + 1) % 11
+    } else {
+        (score + 10) % 11
+    }
+}
+
+#[derive(Default)]
+struct FilterOptions {
+    genres: Vec<String>,
+    media_types: Vec<String>,
+    statuses: Vec<String>,
+    tags: Vec<String>,
+}
+
+fn filter_options<T>(page: &crate::api::model::Page<T>) -> FilterOptions
+where
+    T: ListEntry + Clone + std::fmt::Debug,
+{
+    FilterOptions {
+        genres: collect_options(page, |n| n.genres().into_iter().map(String::from).collect()),
+        media_types: collect_options(page, |n| {
+            n.media_type().into_iter().map(String::from).collect()
+        }),
+        statuses: collect_options(page, |n| n.status().into_iter().map(String::from).collect()),
+        tags: collect_options(page, |n| n.tags().into_iter().map(String::from).collect()),
+    }
+}
+
+/// values found in the loaded list, not the filtered one
+fn get_filter_options(app: &App, is_anime: bool) -> FilterOptions {
+    match app.get_current_route().and_then(|r| r.data.as_ref()) {
+        Some(Data::UserAnimeList(d)) if is_anime => filter_options(&d.anime_list),
+        Some(Data::UserMangaList(d)) if !is_anime => filter_options(&d.manga_list),
+        _ => FilterOptions::default(),
+    }
+}
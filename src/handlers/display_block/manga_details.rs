@@ -1,22 +1,26 @@
 use crate::{
     app::{ActiveMangaDetailBlock, App, DetailPopup},
+    config::keymap::Action,
     event::Key,
-    handlers::common,
 };
 
 use super::anime_details::{get_user_status_index, handle_edit};
 
 pub fn handler(key: Key, app: &mut App) {
     match key {
-        k if k == app.app_config.keys.toggle => change_tab(app),
-        k if k == app.app_config.keys.open_popup => {
+        k if app.is_action(Action::Toggle, k) => change_tab(app),
+        k if app.is_action(Action::ToggleBookmark, k) && !app.popup => app.toggle_bookmark(),
+
+        k if app.is_action(Action::IncrementProgress, k) && !app.popup => app.increment_progress(),
+        k if app.is_action(Action::Undo, k) && !app.popup => app.revert_list_change(0),
+        k if app.is_action(Action::OpenPopup, k) => {
             if app.popup {
                 handle_edit(app)
             } else {
                 open_popup(app)
             }
         }
-        k if common::down_event(k) => match app.active_manga_detail_block {
+        k if app.is_action(Action::Down, k) => match app.active_manga_detail_block {
             ActiveMangaDetailBlock::SideInfo => {
                 app.manga_details_info_scroll_view_state.scroll_down()
             }
@@ -52,7 +56,7 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             }
         },
-        k if common::up_event(k) => match app.active_manga_detail_block {
+        k if app.is_action(Action::Up, k) => match app.active_manga_detail_block {
             ActiveMangaDetailBlock::SideInfo => {
                 app.manga_details_info_scroll_view_state.scroll_up();
             }
@@ -104,7 +108,7 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             }
         },
-        k if common::right_event(k) => {
+        k if app.is_action(Action::Right, k) => {
             if app.popup {
                 return;
             }
@@ -124,7 +128,7 @@ pub fn handler(key: Key, app: &mut App) {
                 _ => {}
             };
         }
-        k if common::left_event(k) => {
+        k if app.is_action(Action::Left, k) => {
             if app.popup {
                 return;
             }
@@ -144,7 +148,7 @@ pub fn handler(key: Key, app: &mut App) {
                 _ => {}
             }
         }
-        k if k == Key::Enter || k == app.app_config.keys.open_popup => {
+        k if app.is_action(Action::Select, k) || app.is_action(Action::OpenPopup, k) => {
             if app.popup {
                 handle_edit(app)
             } else {
@@ -182,6 +186,10 @@ fn change_tab(app: &mut App) {
 }
 
 fn open_popup(app: &mut App) {
+    // list popups need a logged in user
+    if app.is_anonymous {
+        return;
+    }
     match app.active_manga_detail_block {
         ActiveMangaDetailBlock::AddToList => {
             app.active_detail_popup = DetailPopup::AddToList;
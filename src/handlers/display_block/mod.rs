@@ -3,20 +3,29 @@ use crate::{
     event::Key,
 };
 mod anime_details;
+pub mod bookmarks;
+pub mod bulk;
+pub mod history;
+mod home;
+pub mod list_changes;
+mod list_view;
 mod manga_details;
-mod ranking;
+pub mod ranking;
 mod result;
-mod seasonal;
+pub mod schedule;
+pub mod seasonal;
+mod stats_dashboard;
 pub mod top_three;
 mod user_anime_list;
+pub mod user_info;
 mod user_manga_list;
 pub fn handle_display_block(key: Key, app: &mut App) {
     // todo: add handlers for each.
     match &app.active_display_block {
         ActiveDisplayBlock::SearchResultBlock => result::handler(key, app),
         ActiveDisplayBlock::Suggestions => result::handler(key, app),
-        ActiveDisplayBlock::Help => {}
-        ActiveDisplayBlock::UserInfo => {}
+        ActiveDisplayBlock::Help => super::help::handler(key, app),
+        ActiveDisplayBlock::UserInfo => user_info::handler(key, app),
         ActiveDisplayBlock::UserAnimeList => user_anime_list::handler(key, app),
         ActiveDisplayBlock::UserMangaList => user_manga_list::handler(key, app),
         ActiveDisplayBlock::Seasonal => seasonal::handler(key, app),
@@ -24,11 +33,18 @@ pub fn handle_display_block(key: Key, app: &mut App) {
         ActiveDisplayBlock::MangaRanking => ranking::handler(key, app),
         ActiveDisplayBlock::AnimeDetails => anime_details::handler(key, app),
         ActiveDisplayBlock::MangaDetails => manga_details::handler(key, app),
+        ActiveDisplayBlock::Comparison => {}
+        ActiveDisplayBlock::StatsDashboard => stats_dashboard::handler(key, app),
+        ActiveDisplayBlock::Bookmarks => bookmarks::handler(key, app),
+        ActiveDisplayBlock::History => history::handler(key, app),
+        ActiveDisplayBlock::ListChanges => list_changes::handler(key, app),
+        ActiveDisplayBlock::Schedule => schedule::handler(key, app),
         ActiveDisplayBlock::Loading => {}
         ActiveDisplayBlock::Error => {}
         ActiveDisplayBlock::Empty => {
             //? add toggle color for fun
             //? hard one: add playing the banner and moving it around
+            home::handler(key, app)
         }
     }
 }
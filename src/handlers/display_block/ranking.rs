@@ -1,23 +1,21 @@
 use crate::{
     api::model::{AnimeRankingType, MangaRankingType},
     app::{ActiveDisplayBlock, App},
+    config::keymap::Action,
     event::Key,
     network::IoEvent,
 };
 
 use super::result::handle_result_block;
 
-use crate::handlers::{
-    anime::{get_anime_ranking, get_manga_ranking},
-    common,
-};
+use crate::handlers::anime::{get_anime_ranking, get_manga_ranking};
 
 pub fn handler(key: Key, app: &mut App) {
     if app.popup {
         handle_popup(key, app);
     } else {
         match key {
-            k if k == app.app_config.keys.toggle => {
+            k if app.is_action(Action::Toggle, k) => {
                 if app.active_display_block == ActiveDisplayBlock::AnimeRanking {
                     get_manga_ranking(app)
                 } else {
@@ -25,7 +23,7 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             }
 
-            k if k == app.app_config.keys.open_popup => {
+            k if app.is_action(Action::OpenPopup, k) => {
                 app.popup = true;
             }
 
@@ -36,7 +34,7 @@ pub fn handler(key: Key, app: &mut App) {
 
 fn handle_popup(key: Key, app: &mut App) {
     match key {
-        k if common::up_event(k) => {
+        k if app.is_action(Action::Up, k) => {
             if app.active_display_block == ActiveDisplayBlock::AnimeRanking {
                 if app.anime_ranking_type_index > 0 {
                     app.anime_ranking_type_index -= 1;
@@ -50,14 +48,14 @@ fn handle_popup(key: Key, app: &mut App) {
             }
         }
 
-        k if common::down_event(k) => {
+        k if app.is_action(Action::Down, k) => {
             if app.active_display_block == ActiveDisplayBlock::AnimeRanking {
                 app.anime_ranking_type_index = (app.anime_ranking_type_index + 1) % 9;
             } else {
                 app.manga_ranking_type_index = (app.manga_ranking_type_index + 1) % 9;
             }
         }
-        Key::Enter => {
+        k if app.is_action(Action::Select, k) => {
             if app.active_display_block == ActiveDisplayBlock::AnimeRanking {
                 app.popup = false;
                 app.active_display_block = ActiveDisplayBlock::Loading;
@@ -74,7 +72,7 @@ fn handle_popup(key: Key, app: &mut App) {
     }
 }
 
-fn get_anime_rank(i: u8) -> AnimeRankingType {
+pub fn get_anime_rank(i: u8) -> AnimeRankingType {
     match i {
         0 => AnimeRankingType::All,
         1 => AnimeRankingType::Airing,
@@ -89,7 +87,7 @@ fn get_anime_rank(i: u8) -> AnimeRankingType {
     }
 }
 
-fn get_manga_rank(i: u8) -> MangaRankingType {
+pub fn get_manga_rank(i: u8) -> MangaRankingType {
     match i {
         0 => MangaRankingType::All,
         1 => MangaRankingType::Manga,
@@ -0,0 +1,94 @@
+use crate::{
+    airing::ScheduleSource,
+    app::{ActiveDisplayBlock, App, Data},
+    config::keymap::Action,
+    event::Key,
+    network::IoEvent,
+};
+
+/// Left/Right move between the days, Up/Down between the titles of the day
+pub fn handler(key: Key, app: &mut App) {
+    let Some(schedule) = app.schedule.as_ref() else {
+        return;
+    };
+    let len = schedule.days[app.schedule_day].len();
+    let source = schedule.source;
+    let selected = app.search_results.selected_display_card_index.unwrap_or(0);
+    match key {
+        k if app.is_action(Action::Right, k) => {
+            app.schedule_day = (app.schedule_day + 1) % 7;
+            app.search_results.selected_display_card_index = Some(0);
+        }
+
+        k if app.is_action(Action::Left, k) => {
+            app.schedule_day = (app.schedule_day + 6) % 7;
+            app.search_results.selected_display_card_index = Some(0);
+        }
+
+        k if app.is_action(Action::Down, k) => {
+            app.search_results.selected_display_card_index =
+                Some((selected + 1).min(len.saturating_sub(1)));
+        }
+
+        k if app.is_action(Action::Up, k) => {
+            app.search_results.selected_display_card_index = Some(selected.saturating_sub(1));
+        }
+
+        k if app.is_action(Action::Select, k) => {
+            let Some(id) = app
+                .schedule
+                .as_ref()
+                .and_then(|s| s.days[app.schedule_day].get(selected))
+                .map(|anime| anime.id)
+            else {
+                return;
+            };
+            app.active_display_block = ActiveDisplayBlock::Loading;
+            app.dispatch(IoEvent::GetAnime(id));
+        }
+
+        // my list or the whole season
+        k if app.is_action(Action::Toggle, k) && !app.is_anonymous => {
+            let other = match source {
+                ScheduleSource::MyList => ScheduleSource::CurrentSeason,
+                ScheduleSource::CurrentSeason => ScheduleSource::MyList,
+            };
+            get_schedule(app, other);
+        }
+
+        _ => {}
+    }
+}
+
+/// opens the weekly calendar, without login only the season is available
+pub fn get_schedule(app: &mut App, source: ScheduleSource) {
+    let source = if app.is_anonymous {
+        ScheduleSource::CurrentSeason
+    } else {
+        source
+    };
+    let (is_data_available, is_next, index) = is_schedule_data_available(app, source);
+    if is_next {
+        app.load_next_route();
+        return;
+    }
+    if is_data_available {
+        app.load_route(index.unwrap());
+    } else {
+        app.active_display_block = ActiveDisplayBlock::Loading;
+        app.dispatch(IoEvent::GetSchedule(source));
+    }
+}
+
+fn is_schedule_data_available(app: &App, source: ScheduleSource) -> (bool, bool, Option<u16>) {
+    for i in 0..(app.navigator.history.len()) {
+        let id = app.navigator.history[i];
+        if let Some(Data::Schedule(schedule)) = &app.navigator.data[&id].data {
+            if schedule.source == source {
+                let is_next = app.navigator.index + 1 == i;
+                return (true, is_next, Some(id));
+            }
+        }
+    }
+    (false, false, None)
+}
@@ -4,19 +4,18 @@ use super::result::handle_result_block;
 use crate::{
     api::model::Season,
     app::{ActiveDisplayBlock, App},
+    config::keymap::Action,
     event::Key,
     network::IoEvent,
 };
 
-use crate::handlers::common;
-
 pub fn handler(key: Key, app: &mut App) {
     if app.popup {
         handle_popup(key, app);
     } else {
         match key {
             // Key::Enter => open anime detail),
-            k if k == app.app_config.keys.toggle => app.popup = true,
+            k if app.is_action(Action::Toggle, k) => app.popup = true,
 
             // Key::Char('s') => app.active_display_block = ActiveDisplayBlock::,
             _ => handle_result_block(key, app),
@@ -24,7 +23,7 @@ pub fn handler(key: Key, app: &mut App) {
     }
 }
 
-fn reload_seasonal(app: &mut App) {
+pub fn reload_seasonal(app: &mut App) {
     app.reset_result_index();
     app.active_display_block = ActiveDisplayBlock::Loading;
     app.popup = false;
@@ -36,11 +35,11 @@ fn reload_seasonal(app: &mut App) {
 fn handle_popup(key: Key, app: &mut App) {
     let is_season_selected = app.anime_season.popup_season_highlight;
     match key {
-        k if k == app.app_config.keys.toggle => {
+        k if app.is_action(Action::Toggle, k) => {
             app.anime_season.popup_season_highlight = !is_season_selected;
         }
 
-        k if common::down_event(k) => {
+        k if app.is_action(Action::Down, k) => {
             if is_season_selected {
                 app.anime_season.selected_season = (app.anime_season.selected_season + 1) % 4;
             } else if app.anime_season.selected_year > 1917 {
@@ -51,7 +50,7 @@ fn handle_popup(key: Key, app: &mut App) {
             }
         }
 
-        k if common::up_event(k) => {
+        k if app.is_action(Action::Up, k) => {
             if is_season_selected {
                 if app.anime_season.selected_season == 0 {
                     app.anime_season.selected_season = 3;
@@ -65,7 +64,7 @@ fn handle_popup(key: Key, app: &mut App) {
             }
         }
 
-        Key::Enter => {
+        k if app.is_action(Action::Select, k) => {
             app.popup = false;
             reload_seasonal(app);
         }
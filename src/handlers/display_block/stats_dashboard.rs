@@ -0,0 +1,18 @@
+use crate::{
+    app::{App, DASHBOARD_TABS},
+    config::keymap::Action,
+    event::Key,
+};
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if app.is_action(Action::Toggle, k) || app.is_action(Action::Right, k) => {
+            app.stats_dashboard_tab = (app.stats_dashboard_tab + 1) % DASHBOARD_TABS.len();
+        }
+        k if app.is_action(Action::Left, k) => {
+            app.stats_dashboard_tab =
+                (app.stats_dashboard_tab + DASHBOARD_TABS.len() - 1) % DASHBOARD_TABS.len();
+        }
+        _ => {}
+    }
+}
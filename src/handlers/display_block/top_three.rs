@@ -1,7 +1,8 @@
-use crate::handlers::{common, get_media_detail_page};
+use crate::handlers::get_media_detail_page;
 use crate::{
     api::model::{AnimeRankingType, MangaRankingType, RankingType},
     app::{App, TopThreeBlock},
+    config::keymap::Action,
     event::Key,
     network::IoEvent,
 };
@@ -9,7 +10,7 @@ use crate::{
 pub fn handler(key: Key, app: &mut App) {
     let mut index = app.selected_top_three;
     match key {
-        k if common::up_event(k) => {
+        k if app.is_action(Action::Up, k) => {
             if index > 0 {
                 index -= 1;
             } else {
@@ -17,7 +18,7 @@ pub fn handler(key: Key, app: &mut App) {
             }
         }
 
-        k if common::down_event(k) => {
+        k if app.is_action(Action::Down, k) => {
             if index < 2 {
                 index += 1;
             } else {
@@ -25,7 +26,7 @@ pub fn handler(key: Key, app: &mut App) {
             }
         }
 
-        k if k == app.app_config.keys.toggle => match &app.active_top_three {
+        k if app.is_action(Action::Toggle, k) => match &app.active_top_three {
             TopThreeBlock::Anime(_) => {
                 let data_available = is_manga_data_available(
                     app,
@@ -108,7 +109,7 @@ pub fn handler(key: Key, app: &mut App) {
             _ => {}
         },
         // switch between ranking types
-        k if common::left_event(k) => match &app.active_top_three {
+        k if app.is_action(Action::Left, k) => match &app.active_top_three {
             TopThreeBlock::Anime(_) => {
                 let mut index = app.active_anime_rank_index;
                 let max = app.available_anime_ranking_types.len() as u32;
@@ -141,7 +142,7 @@ pub fn handler(key: Key, app: &mut App) {
 
             _ => {}
         },
-        k if common::right_event(k) => match &app.active_top_three {
+        k if app.is_action(Action::Right, k) => match &app.active_top_three {
             TopThreeBlock::Anime(_) => {
                 let mut index = app.active_anime_rank_index;
                 let max = app.available_anime_ranking_types.len() as u32;
@@ -173,7 +174,7 @@ pub fn handler(key: Key, app: &mut App) {
             _ => {}
         },
 
-        Key::Enter => get_media_detail_page(app),
+        k if app.is_action(Action::Select, k) => get_media_detail_page(app),
         _ => {}
     }
     app.selected_top_three = index;
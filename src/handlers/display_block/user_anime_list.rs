@@ -1,37 +1,62 @@
 use crate::{
     app::{ActiveDisplayBlock, App},
+    config::keymap::Action,
     event::Key,
-    handlers::user::is_user_anime_list_data_available,
+    handlers::user::{get_user_anime_list, get_user_manga_list},
     network::IoEvent,
 };
 
-use super::result;
+use super::{bulk, list_view, result};
 
 pub fn handler(key: Key, app: &mut App) {
+    if app.bulk.popup.is_some() {
+        bulk::handle_popup(key, app);
+        return;
+    }
+    if app.popup {
+        list_view::handle_popup(key, app, true);
+        return;
+    }
     match key {
-        k if k == app.app_config.keys.toggle => change_tab(app),
-        k if k == app.app_config.keys.open_popup => {} // i don't remember what is the popup for
+        k if app.is_action(Action::Toggle, k) => change_tab(app),
+        k if app.is_action(Action::OpenPopup, k) => list_view::open_popup(app),
+        k if app.is_action(Action::IncrementProgress, k) => app.increment_progress(),
+        k if app.is_action(Action::Mark, k) => bulk::toggle_mark(app),
+        k if app.is_action(Action::BulkEdit, k) => bulk::open(app),
+        k if app.is_action(Action::Undo, k) => app.revert_list_change(0),
+        k if app.is_action(Action::SwitchMedia, k) => {
+            app.reset_result_index();
+            get_user_manga_list(app);
+        }
+        k if app.is_action(Action::CompareMode, k) => toggle_compare_mode(app),
+        k if app.is_action(Action::ViewComparison, k) => get_comparison(app, true),
         _ => result::handler(key, app),
     }
 }
 
 fn change_tab(app: &mut App) {
-    // we need to checkif the next route is the same as the the next status route then we call load_next_route() else we call load_route()
+    // get_user_anime_list checks if the next route is the same as the the next status route then we call load_next_route() else we call load_route()
     // this way we won't overide the next route if it's the same as the next status route
-    let next_status = app.next_anime_list_status();
-    app.anime_list_status = next_status.clone();
-
-    let (is_data_available, is_next, index) = is_user_anime_list_data_available(app);
+    app.anime_list_status = app.next_anime_list_status();
     app.reset_result_index();
-    if is_next {
-        app.load_next_route();
+    get_user_anime_list(app);
+}
+
+/// comparing only makes sense on other users' lists
+pub fn toggle_compare_mode(app: &mut App) {
+    if app.list_user.is_some() && !app.is_anonymous {
+        app.compare_mode = !app.compare_mode;
+    }
+}
+
+/// full comparison of another user's list with mine
+pub fn get_comparison(app: &mut App, is_anime: bool) {
+    if app.is_anonymous {
         return;
     }
-    if is_data_available {
-        app.load_route(index.unwrap());
-    } else {
+    if let Some(user) = app.list_user.clone() {
         app.active_display_block = ActiveDisplayBlock::Loading;
-        app.dispatch(IoEvent::GetAnimeList(next_status));
+        app.dispatch(IoEvent::GetComparison(user, is_anime));
     }
 }
 
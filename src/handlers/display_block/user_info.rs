@@ -0,0 +1,55 @@
+use crate::{
+    app::{ActiveDisplayBlock, App},
+    config::keymap::Action,
+    event::Key,
+    network::IoEvent,
+};
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if app.is_action(Action::SwitchMedia, k) => toggle_statistics(app),
+        k if app.is_action(Action::StatsDashboard, k) => get_stats_dashboard(app),
+        _ => {}
+    }
+}
+
+/// switches between anime and manga statistics,
+/// manga ones are computed on first use
+fn toggle_statistics(app: &mut App) {
+    if !app.profile_show_manga && app.manga_statistics.is_none() {
+        // they wouldn't be fetched behind the running call
+        if app.is_loading {
+            app.status_message = Some("Still loading, try again".to_string());
+            return;
+        }
+        app.dispatch(IoEvent::GetMangaStatistics);
+    }
+    app.profile_show_manga = !app.profile_show_manga;
+}
+
+pub fn get_stats_dashboard(app: &mut App) {
+    let (is_data_available, is_next, index) = is_stats_dashboard_data_available(app);
+    if is_next {
+        app.load_next_route();
+        return;
+    }
+    if is_data_available {
+        app.load_route(index.unwrap());
+    } else {
+        app.active_display_block = ActiveDisplayBlock::Loading;
+        app.dispatch(IoEvent::GetStatsDashboard);
+    }
+}
+
+fn is_stats_dashboard_data_available(app: &App) -> (bool, bool, Option<u16>) {
+    for i in 0..(app.navigator.history.len()) {
+        let id = app.navigator.history[i];
+        if app.navigator.data[&id].block == ActiveDisplayBlock::StatsDashboard
+            && app.navigator.data[&id].data.is_some()
+        {
+            let is_next = app.navigator.index + 1 == i;
+            return (true, is_next, Some(id));
+        }
+    }
+    (false, false, None)
+}
@@ -1,16 +1,37 @@
 use crate::{
-    app::{ActiveDisplayBlock, App},
+    app::App,
+    config::keymap::Action,
     event::Key,
-    handlers::user::is_user_manga_list_data_available,
-    network::IoEvent,
+    handlers::user::{get_user_anime_list, get_user_manga_list},
 };
 
-use super::result;
+use super::{
+    bulk, list_view, result,
+    user_anime_list::{get_comparison, toggle_compare_mode},
+};
 
 pub fn handler(key: Key, app: &mut App) {
+    if app.bulk.popup.is_some() {
+        bulk::handle_popup(key, app);
+        return;
+    }
+    if app.popup {
+        list_view::handle_popup(key, app, false);
+        return;
+    }
     match key {
-        k if k == app.app_config.keys.toggle => change_tab(app),
-        k if k == app.app_config.keys.open_popup => {} // i don't remember what is the popup for
+        k if app.is_action(Action::Toggle, k) => change_tab(app),
+        k if app.is_action(Action::OpenPopup, k) => list_view::open_popup(app),
+        k if app.is_action(Action::IncrementProgress, k) => app.increment_progress(),
+        k if app.is_action(Action::Mark, k) => bulk::toggle_mark(app),
+        k if app.is_action(Action::BulkEdit, k) => bulk::open(app),
+        k if app.is_action(Action::Undo, k) => app.revert_list_change(0),
+        k if app.is_action(Action::SwitchMedia, k) => {
+            app.reset_result_index();
+            get_user_anime_list(app);
+        }
+        k if app.is_action(Action::CompareMode, k) => toggle_compare_mode(app),
+        k if app.is_action(Action::ViewComparison, k) => get_comparison(app, false),
         _ => result::handler(key, app),
     }
 }
@@ -18,20 +39,7 @@ pub fn handler(key: Key, app: &mut App) {
 // fn open_popup(app: &mut App) {}
 
 fn change_tab(app: &mut App) {
-    let next_status = app.next_anime_list_status();
-    app.anime_list_status = next_status.clone();
-    let (is_data_available, is_next, index) = is_user_manga_list_data_available(app);
-
+    app.manga_list_status = app.next_manga_list_status();
     app.reset_result_index();
-
-    if is_next {
-        app.load_next_route();
-        return;
-    }
-    if is_data_available {
-        app.load_route(index.unwrap());
-    } else {
-        app.active_display_block = ActiveDisplayBlock::Loading;
-        app.dispatch(IoEvent::GetAnimeList(next_status));
-    }
+    get_user_manga_list(app);
 }
@@ -0,0 +1,36 @@
+use crate::{app::App, config::keymap::Action, event::Key, fuzzy::FuzzyFilter};
+
+pub fn open(app: &mut App) {
+    app.fuzzy_filter.editing = true;
+}
+
+/// keys typed in the filter line, the cards are narrowed on every change
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        // closes the filter line and brings back every card
+        k if app.is_action(Action::Cancel, k) => {
+            app.fuzzy_filter = FuzzyFilter::default();
+            app.apply_fuzzy_filter();
+        }
+
+        // keeps the narrowed cards to navigate them
+        k if app.is_action(Action::Submit, k) => app.fuzzy_filter.editing = false,
+
+        k if app.is_action(Action::DeleteBackward, k) => {
+            app.fuzzy_filter.query.pop();
+            app.apply_fuzzy_filter();
+        }
+
+        k if app.is_action(Action::DeleteToStart, k) || app.is_action(Action::ClearInput, k) => {
+            app.fuzzy_filter.query.clear();
+            app.apply_fuzzy_filter();
+        }
+
+        Key::Char(c) => {
+            app.fuzzy_filter.query.push(c);
+            app.apply_fuzzy_filter();
+        }
+
+        _ => {}
+    }
+}
@@ -1,38 +1,70 @@
-// use super::common;
-// use crate::app::App;
-// use crate::event::Key;
-
-// #[derive(PartialEq)]
-// enum Direction {
-//     UP,
-//     DOWN,
-// }
-
-// pub fn handler(key: Key, app: &mut App) {
-//     match key {
-//         k if common::down_event(k) => {
-//             move_page(Direction::DOWN, app);
-//         }
-//         k if common::up_event(k) => {
-//             move_page(Direction::UP, app);
-//         }
-//         Key::Ctrl('d') => {
-//             move_page(Direction::DOWN, app);
-//         }
-//         Key::Ctrl('u') => {
-//             move_page(Direction::UP, app);
-//         }
-//         _ => {}
-//     };
-// }
-
-// fn move_page(direction: Direction, app: &mut App) {
-//     if direction == Direction::UP {
-//         if app.help_menu_page > 0 {
-//             app.help_menu_page -= 1;
-//         }
-//     } else if direction == Direction::DOWN {
-//         app.help_menu_page += 1;
-//     }
-//     app.calculate_help_menu_offset();
-// }
+use crate::{
+    app::{ActiveDisplayBlock, App},
+    config::keymap::Action,
+    event::Key,
+    fuzzy::FuzzyFilter,
+};
+
+pub fn open(app: &mut App) {
+    // the help follows the context it is opened from
+    app.help_context = app.key_context();
+    app.help_show_all = false;
+    app.help_filter = FuzzyFilter::default();
+    app.help_menu_offset = 0;
+    app.active_display_block = ActiveDisplayBlock::Help;
+}
+
+pub fn handler(key: Key, app: &mut App) {
+    if app.help_filter.editing {
+        handle_filter(key, app);
+        return;
+    }
+    match key {
+        k if app.is_action(Action::Back, k) || app.is_action(Action::Help, k) => {
+            app.help_filter = FuzzyFilter::default();
+            app.load_previous_route();
+        }
+
+        k if app.is_action(Action::Down, k) => {
+            let last = app.help_rows().len().saturating_sub(1) as u32;
+            app.help_menu_offset = (app.help_menu_offset + 1).min(last);
+        }
+
+        k if app.is_action(Action::Up, k) => {
+            app.help_menu_offset = app.help_menu_offset.saturating_sub(1);
+        }
+
+        k if app.is_action(Action::Search, k) => {
+            app.help_filter.editing = true;
+        }
+
+        k if app.is_action(Action::Toggle, k) => {
+            app.help_show_all = !app.help_show_all;
+            app.help_menu_offset = 0;
+        }
+
+        _ => {}
+    }
+}
+
+/// keys typed in the search line of the help screen
+fn handle_filter(key: Key, app: &mut App) {
+    match key {
+        k if app.is_action(Action::Cancel, k) => app.help_filter = FuzzyFilter::default(),
+
+        k if app.is_action(Action::Submit, k) => app.help_filter.editing = false,
+
+        k if app.is_action(Action::DeleteBackward, k) => {
+            app.help_filter.query.pop();
+        }
+
+        k if app.is_action(Action::DeleteToStart, k) || app.is_action(Action::ClearInput, k) => {
+            app.help_filter.query.clear();
+        }
+
+        Key::Char(c) => app.help_filter.query.push(c),
+
+        _ => return,
+    }
+    app.help_menu_offset = 0;
+}
@@ -1,5 +1,7 @@
 use crate::app::{ActiveBlock, ActiveDisplayBlock, App};
+use crate::config::keymap::Action;
 use crate::event::Key;
+use crate::handlers::user::get_user_anime_list;
 use crate::network::IoEvent;
 use std::convert::TryInto;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
@@ -7,25 +9,25 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 pub fn handler(key: Key, app: &mut App) {
     match key {
         // Delete everything after the cursor including selected character
-        Key::Ctrl('k') => {
+        k if app.is_action(Action::DeleteToEnd, k) => {
             app.input.drain(app.input_idx..app.input.len());
         }
 
         // Delete everything before the cursor not including selected character
-        Key::Ctrl('u') => {
+        k if app.is_action(Action::DeleteToStart, k) => {
             app.input.drain(..app.input_idx);
             app.input_idx = 0;
             app.input_cursor_position = 0;
         }
 
         // Deletes everything in input
-        Key::Ctrl('l') => {
+        k if app.is_action(Action::ClearInput, k) => {
             app.input = vec![];
             app.input_idx = 0;
             app.input_cursor_position = 0;
         }
         // Delete word before cursor
-        Key::Ctrl('w') => {
+        k if app.is_action(Action::DeleteWord, k) => {
             if app.input_cursor_position == 0 {
                 return;
             }
@@ -45,7 +47,7 @@ pub fn handler(key: Key, app: &mut App) {
         }
 
         // Move cursor to the end of the input
-        Key::Ctrl('e') => {
+        k if app.is_action(Action::CursorEnd, k) => {
             app.input_idx = app.input.len();
             let input_string: String = app.input.iter().collect();
             app.input_cursor_position = UnicodeWidthStr::width(input_string.as_str())
@@ -54,13 +56,13 @@ pub fn handler(key: Key, app: &mut App) {
         }
 
         // Move cursor to the start of the input
-        Key::Ctrl('a') => {
+        k if app.is_action(Action::CursorStart, k) => {
             app.input_idx = 0;
             app.input_cursor_position = 0;
         }
 
         // Move cursor to left
-        Key::Left | Key::Ctrl('b') => {
+        k if app.is_action(Action::CursorLeft, k) => {
             if !app.input.is_empty() && app.input_idx > 0 {
                 let last_c = app.input[app.input_idx - 1];
                 app.input_idx -= 1;
@@ -69,7 +71,7 @@ pub fn handler(key: Key, app: &mut App) {
         }
 
         // Move cursor to right
-        Key::Right | Key::Ctrl('f') => {
+        k if app.is_action(Action::CursorRight, k) => {
             if app.input_idx < app.input.len() {
                 let next_c = app.input[app.input_idx];
                 app.input_idx += 1;
@@ -78,18 +80,38 @@ pub fn handler(key: Key, app: &mut App) {
         }
 
         // end input mode
-        Key::Esc => {
+        k if app.is_action(Action::Cancel, k) => {
             app.active_block = ActiveBlock::DisplayBlock;
         }
 
         // Submit search query
-        Key::Enter => {
+        k if app.is_action(Action::Submit, k) => {
             let input_str: String = app.input.iter().collect();
 
             // Don't do anything if there is no input
             if input_str.is_empty() {
                 return;
             }
+            // "@username" opens the user's public anime list
+            if let Some(user) = input_str.strip_prefix('@') {
+                let user = user.trim();
+                if user.is_empty() {
+                    return;
+                }
+                // "@me" is the logged in user
+                let list_user = (user != "me").then(|| user.to_string());
+                if list_user.is_none() && app.is_anonymous {
+                    app.status_message = Some("Login to see your list".to_string());
+                    return;
+                }
+                app.active_block = ActiveBlock::DisplayBlock;
+                app.reset_result_index();
+                app.list_user = list_user;
+                app.anime_list_status = None;
+                get_user_anime_list(app);
+                return;
+            }
+
             app.active_display_block = ActiveDisplayBlock::Loading;
             app.active_block = ActiveBlock::DisplayBlock;
             app.reset_result_index();
@@ -100,15 +122,8 @@ pub fn handler(key: Key, app: &mut App) {
             // On searching for a track, clear the playlist selection
         }
 
-        // add character to input
-        Key::Char(c) => {
-            app.input.insert(app.input_idx, c);
-            app.input_idx += 1;
-            app.input_cursor_position += compute_character_width(c);
-        }
-
         // delete character before cursor
-        Key::Backspace | Key::Ctrl('h') => {
+        k if app.is_action(Action::DeleteBackward, k) => {
             if !app.input.is_empty() && app.input_idx > 0 {
                 let last_c = app.input.remove(app.input_idx - 1);
                 app.input_idx -= 1;
@@ -117,12 +132,19 @@ pub fn handler(key: Key, app: &mut App) {
         }
 
         // ! not working ??
-        Key::Delete | Key::Ctrl('d') => {
+        k if app.is_action(Action::DeleteForward, k) => {
             if !app.input.is_empty() && app.input_idx < app.input.len() {
                 app.input.remove(app.input_idx);
             }
         }
 
+        // add character to input
+        Key::Char(c) => {
+            app.input.insert(app.input_idx, c);
+            app.input_idx += 1;
+            app.input_cursor_position += compute_character_width(c);
+        }
+
         _ => {}
     }
 }
@@ -1,43 +1,57 @@
 mod anime;
 pub mod common;
 mod display_block;
+pub mod fuzzy_filter;
 mod help;
 mod input;
+mod mouse;
 mod option;
+mod palette;
 mod user;
 use crate::api::model::{AnimeRankingType, MangaRankingType, Media};
 use crate::app::{
     ActiveBlock, ActiveDisplayBlock, App, Data, SelectedSearchTab, TopThreeBlock,
-    ANIME_OPTIONS_RANGE, GENERAL_OPTIONS_RANGE, USER_OPTIONS_RANGE,
+    ANIME_OPTIONS_RANGE, GENERAL_OPTIONS_RANGE, NO_MENU_SELECTION, USER_OPTIONS_RANGE,
 };
+use crate::config::keymap::Action;
 use crate::event::Key;
 use crate::network::IoEvent;
 
-use common::get_lowercase_key;
+pub use display_block::bulk::tag_handler as bulk_tag_handler;
+pub use fuzzy_filter::handler as fuzzy_filter_handler;
+pub use help::handler as help_handler;
 pub use input::handler as input_handler;
 use log::warn;
+pub use mouse::handler as mouse_handler;
+pub use palette::handler as palette_handler;
 
 pub fn handle_app(key: Key, app: &mut App) {
     // First handle any global event and then move to block event
     if app.exit_confirmation_popup {
-        if key == Key::Esc || key == Key::Char('n') {
+        if app.is_action(Action::Back, key) || app.is_action(Action::Cancel, key) {
             app.exit_confirmation_popup = false;
             return;
-        } else if key == Key::Enter || get_lowercase_key(key) == Key::Char('y') {
+        } else if app.is_action(Action::Select, key) || app.is_action(Action::Confirm, key) {
             app.exit_flag = true;
-            return;
         }
+        // the popup takes every key until it is answered
+        return;
+    }
+    // the help screen takes every key until it is closed
+    if app.active_display_block == ActiveDisplayBlock::Help {
+        help::handler(key, app);
+        return;
     }
     match key {
-        Key::Esc => app.load_previous_route(),
+        k if app.is_action(Action::Back, k) => app.load_previous_route(),
 
-        _ if key == app.app_config.keys.next_state => app.load_next_route(),
+        k if app.is_action(Action::NextState, k) => app.load_next_route(),
 
-        _ if key == app.app_config.keys.help => {
-            app.active_display_block = ActiveDisplayBlock::Help;
-        }
+        k if app.is_action(Action::Help, k) => help::open(app),
+
+        k if app.is_action(Action::CommandPalette, k) => palette::open(app),
 
-        _ if key == app.app_config.keys.search => {
+        k if app.is_action(Action::Search, k) => {
             app.input = vec![];
             app.input_idx = 0;
             app.input_cursor_position = 0;
@@ -87,7 +101,7 @@ pub fn handle_tab(app: &mut App) {
         }
 
         ActiveBlock::Option => {
-            app.library.selected_index = 10; // out of range to not display anything
+            app.library.selected_index = NO_MENU_SELECTION;
             app.active_block = ActiveBlock::TopThree;
         }
 
@@ -107,7 +121,7 @@ pub fn handle_tab(app: &mut App) {
 pub fn handle_back_tab(app: &mut App) {
     match app.active_block {
         ActiveBlock::Input => {
-            app.library.selected_index = 10; // out of range to not display anything
+            app.library.selected_index = NO_MENU_SELECTION;
             app.active_block = ActiveBlock::DisplayBlock;
         }
 
@@ -133,7 +147,7 @@ pub fn handle_back_tab(app: &mut App) {
         }
 
         ActiveBlock::Anime => {
-            app.library.selected_index = 10; // out of range to not display anything
+            app.library.selected_index = NO_MENU_SELECTION;
             app.active_block = ActiveBlock::Input;
         }
         _ => {}
@@ -524,3 +538,45 @@ fn is_media_data_available(app: &App, data: &Media) -> (bool, bool, Option<u16>)
     }
     (false, false, None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::test::get_app;
+    use crate::undo::Change;
+
+    #[test]
+    fn test_undo_with_popup_open() {
+        let mut app = get_app();
+        app.list_changes.record(
+            1,
+            "added".to_string(),
+            Change::Anime {
+                before: None,
+                applied: None,
+            },
+            0,
+        );
+        app.active_block = ActiveBlock::DisplayBlock;
+        app.active_display_block = ActiveDisplayBlock::AnimeDetails;
+
+        app.popup = true;
+        handle_app(Key::Char('u'), &mut app);
+        assert_eq!(app.list_changes.entries.len(), 1);
+        app.popup = false;
+
+        app.exit_confirmation_popup = true;
+        handle_app(Key::Char('u'), &mut app);
+        assert_eq!(app.list_changes.entries.len(), 1);
+        app.exit_confirmation_popup = false;
+
+        // not a list view
+        app.active_display_block = ActiveDisplayBlock::Seasonal;
+        handle_app(Key::Char('u'), &mut app);
+        assert_eq!(app.list_changes.entries.len(), 1);
+
+        app.active_display_block = ActiveDisplayBlock::AnimeDetails;
+        handle_app(Key::Char('u'), &mut app);
+        assert!(app.list_changes.entries.is_empty());
+    }
+}
@@ -0,0 +1,274 @@
+use chrono::Datelike;
+
+use crate::{
+    api::model::{UserReadStatus, UserWatchStatus},
+    app::{
+        ActiveAnimeDetailBlock, ActiveBlock, ActiveDisplayBlock, ActiveMangaDetailBlock, App,
+        ClickTarget, DetailPopup, SelectedSearchTab, ANIME_OPTIONS_RANGE, USER_OPTIONS_RANGE,
+    },
+    config::keymap::Action,
+    event::{Mouse, MouseAction},
+};
+
+use super::{
+    anime::{get_anime_ranking, get_manga_ranking},
+    display_block::handle_display_block,
+    handle_block_events,
+    user::{get_user_anime_list, get_user_manga_list},
+};
+
+// the oldest season available
+const FIRST_SEASON_YEAR: u16 = 1917;
+
+pub fn handler(mouse: Mouse, app: &mut App) {
+    if app.exit_confirmation_popup || app.palette.open {
+        return;
+    }
+    match mouse.action {
+        MouseAction::ScrollUp => handle_scroll(app, true),
+        MouseAction::ScrollDown => handle_scroll(app, false),
+        MouseAction::Click => {
+            if let Some(target) = app.get_click_target(mouse.column, mouse.row) {
+                handle_click(target, app);
+            }
+        }
+    }
+}
+
+/// the wheel scrolls the details text, and moves through the cards everywhere else
+fn handle_scroll(app: &mut App, up: bool) {
+    match app.active_display_block {
+        ActiveDisplayBlock::AnimeDetails if !app.popup => {
+            let state = if app.active_anime_detail_block == ActiveAnimeDetailBlock::SideInfo {
+                &mut app.anime_details_info_scroll_view_state
+            } else {
+                &mut app.anime_details_synopsys_scroll_view_state
+            };
+            if up {
+                state.scroll_up()
+            } else {
+                state.scroll_down()
+            }
+        }
+        ActiveDisplayBlock::MangaDetails if !app.popup => {
+            let state = if app.active_manga_detail_block == ActiveMangaDetailBlock::SideInfo {
+                &mut app.manga_details_info_scroll_view_state
+            } else {
+                &mut app.manga_details_synopsys_scroll_view_state
+            };
+            if up {
+                state.scroll_up()
+            } else {
+                state.scroll_down()
+            }
+        }
+        ActiveDisplayBlock::Empty => {}
+        _ => {
+            app.fuzzy_filter.editing = false;
+            app.active_block = ActiveBlock::DisplayBlock;
+            replay_display_action(if up { Action::Up } else { Action::Down }, app);
+        }
+    }
+}
+
+/// clicks and the wheel replay the keys of their action, so they go through the same handlers
+fn replay_block_action(action: Action, app: &mut App) {
+    if let Some(key) = app.action_key(action) {
+        handle_block_events(key, app);
+    }
+}
+
+fn replay_display_action(action: Action, app: &mut App) {
+    if let Some(key) = app.action_key(action) {
+        handle_display_block(key, app);
+    }
+}
+
+fn handle_click(target: ClickTarget, app: &mut App) {
+    app.fuzzy_filter.editing = false;
+    // an open popup takes every click
+    if (app.popup || app.bulk.popup.is_some())
+        && !matches!(
+            target,
+            ClickTarget::PopupOption(_) | ClickTarget::PopupSideOption(_)
+        )
+    {
+        return;
+    }
+    match target {
+        ClickTarget::SearchInput => {
+            app.input = vec![];
+            app.input_idx = 0;
+            app.input_cursor_position = 0;
+            app.active_block = ActiveBlock::Input;
+        }
+
+        ClickTarget::MenuItem(index) => {
+            app.library.selected_index = index;
+            app.active_block = if ANIME_OPTIONS_RANGE.contains(&index) {
+                ActiveBlock::Anime
+            } else if USER_OPTIONS_RANGE.contains(&index) {
+                ActiveBlock::User
+            } else {
+                ActiveBlock::Option
+            };
+            replay_block_action(Action::Select, app);
+        }
+
+        ClickTarget::TopThree(index) => {
+            app.selected_top_three = index as u32;
+            app.active_block = ActiveBlock::TopThree;
+            replay_block_action(Action::Select, app);
+        }
+
+        ClickTarget::Tab(index) => {
+            app.active_block = ActiveBlock::DisplayBlock;
+            select_tab(index, app);
+        }
+
+        ClickTarget::Card(index) => {
+            app.active_block = ActiveBlock::DisplayBlock;
+            app.search_results.selected_display_card_index = Some(index);
+            replay_display_action(Action::Select, app);
+        }
+
+        ClickTarget::Entry(column, index) => {
+            app.active_block = ActiveBlock::DisplayBlock;
+            if app.active_display_block == ActiveDisplayBlock::Schedule {
+                app.schedule_day = column;
+                app.search_results.selected_display_card_index = Some(index);
+            } else {
+                app.home.focused = column;
+                app.home.selected = index;
+            }
+            replay_display_action(Action::Select, app);
+        }
+
+        ClickTarget::PopupOption(index) => select_popup_option(index, app),
+
+        ClickTarget::PopupSideOption(index) => {
+            // the years are shown as [year + 1, year, year - 1]
+            if app.active_display_block == ActiveDisplayBlock::Seasonal {
+                let season = &mut app.anime_season;
+                season.popup_season_highlight = false;
+                let current_year = chrono::Utc::now().year_ce().1 as u16;
+                season.selected_year = (season.selected_year + 1)
+                    .saturating_sub(index as u16)
+                    .clamp(FIRST_SEASON_YEAR, current_year);
+            }
+        }
+    }
+}
+
+fn select_tab(index: usize, app: &mut App) {
+    match app.active_display_block {
+        ActiveDisplayBlock::SearchResultBlock => {
+            let tab = if index == 0 {
+                SelectedSearchTab::Anime
+            } else {
+                SelectedSearchTab::Manga
+            };
+            if app.search_results.selected_tab != tab {
+                app.reset_result_index();
+                app.search_results.selected_tab = tab;
+            }
+        }
+
+        ActiveDisplayBlock::AnimeRanking | ActiveDisplayBlock::MangaRanking => {
+            if index == 0 {
+                get_anime_ranking(app)
+            } else {
+                get_manga_ranking(app)
+            }
+        }
+
+        // tabs follow the order of the status enums, after the whole list
+        ActiveDisplayBlock::UserAnimeList => {
+            let status = match index {
+                1 => Some(UserWatchStatus::Watching),
+                2 => Some(UserWatchStatus::Completed),
+                3 => Some(UserWatchStatus::OnHold),
+                4 => Some(UserWatchStatus::Dropped),
+                5 => Some(UserWatchStatus::PlanToWatch),
+                _ => None,
+            };
+            if app.anime_list_status != status {
+                app.anime_list_status = status;
+                app.reset_result_index();
+                get_user_anime_list(app);
+            }
+        }
+
+        ActiveDisplayBlock::UserMangaList => {
+            let status = match index {
+                1 => Some(UserReadStatus::Reading),
+                2 => Some(UserReadStatus::Completed),
+                3 => Some(UserReadStatus::OnHold),
+                4 => Some(UserReadStatus::Dropped),
+                5 => Some(UserReadStatus::PlanToRead),
+                _ => None,
+            };
+            if app.manga_list_status != status {
+                app.manga_list_status = status;
+                app.reset_result_index();
+                get_user_manga_list(app);
+            }
+        }
+
+        ActiveDisplayBlock::StatsDashboard => app.stats_dashboard_tab = index,
+
+        ActiveDisplayBlock::Schedule => {
+            app.schedule_day = index;
+            app.search_results.selected_display_card_index = Some(0);
+        }
+
+        _ => {}
+    }
+}
+
+fn select_popup_option(index: usize, app: &mut App) {
+    match app.active_display_block {
+        ActiveDisplayBlock::AnimeRanking => {
+            app.anime_ranking_type_index = index as u8;
+            replay_display_action(Action::Select, app);
+        }
+
+        ActiveDisplayBlock::MangaRanking => {
+            app.manga_ranking_type_index = index as u8;
+            replay_display_action(Action::Select, app);
+        }
+
+        ActiveDisplayBlock::Seasonal => {
+            app.anime_season.popup_season_highlight = true;
+            app.anime_season.selected_season = index as u8;
+        }
+
+        ActiveDisplayBlock::AnimeDetails | ActiveDisplayBlock::MangaDetails => {
+            if app.result_popup {
+                return;
+            }
+            match app.active_detail_popup {
+                DetailPopup::AddToList => app.selected_popup_status = index as u8,
+                DetailPopup::Rate => app.selected_popup_rate = index as u8,
+                // the count popups have no list to click
+                _ => return,
+            }
+            replay_display_action(Action::Select, app);
+        }
+
+        ActiveDisplayBlock::UserAnimeList | ActiveDisplayBlock::UserMangaList
+            if app.bulk.popup.is_some() =>
+        {
+            app.bulk.selected = index;
+            replay_display_action(Action::Select, app);
+        }
+
+        // sort & filter popup, a click changes the option like the toggle key
+        ActiveDisplayBlock::UserAnimeList | ActiveDisplayBlock::UserMangaList => {
+            app.list_view_popup_index = index;
+            replay_display_action(Action::Toggle, app);
+        }
+
+        _ => {}
+    }
+}
@@ -1,42 +1,55 @@
 use super::common;
-use crate::app::{App, GENERAL_OPTIONS, GENERAL_OPTIONS_RANGE};
+use crate::app::{App, GENERAL_OPTIONS, GENERAL_OPTIONS_RANGE, NO_MENU_SELECTION};
+use crate::config::keymap::Action;
 use crate::event::Key;
 
 pub fn handler(key: Key, app: &mut App) {
     match key {
-        k if common::down_event(k) => {
+        k if app.is_action(Action::Down, k) => {
             // calculate the next index in the list
             let next_index = GENERAL_OPTIONS_RANGE.start
                 + common::on_down_press(
                     &GENERAL_OPTIONS,
-                    Some(app.library.selected_index % GENERAL_OPTIONS_RANGE.len()),
+                    Some(
+                        app.library
+                            .selected_index
+                            .saturating_sub(GENERAL_OPTIONS_RANGE.start),
+                    ),
                 );
             app.library.selected_index = next_index;
         }
 
-        k if common::up_event(k) => {
+        k if app.is_action(Action::Up, k) => {
             // calculate the next index in the list
             let next_index = GENERAL_OPTIONS_RANGE.start
                 + common::on_up_press(
                     &GENERAL_OPTIONS,
-                    Some(app.library.selected_index % GENERAL_OPTIONS_RANGE.len()),
+                    Some(
+                        app.library
+                            .selected_index
+                            .saturating_sub(GENERAL_OPTIONS_RANGE.start),
+                    ),
                 );
 
             app.library.selected_index = next_index;
         }
 
-        Key::Enter => {
-            match app.library.selected_index {
+        k if app.is_action(Action::Select, k) => {
+            match app
+                .library
+                .selected_index
+                .saturating_sub(GENERAL_OPTIONS_RANGE.start)
+            {
                 // Help
-                6 => {}
+                0 => {}
                 // About
-                7 => {}
+                1 => {}
                 // Quit
-                8 => {}
+                2 => {}
 
                 _ => {}
             };
-            app.library.selected_index = 9;
+            app.library.selected_index = NO_MENU_SELECTION;
         }
         _ => (),
     };
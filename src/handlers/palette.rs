@@ -0,0 +1,187 @@
+use crate::{
+    airing::ScheduleSource,
+    api::model::{Season, TitleLanguage},
+    app::{ActiveBlock, ActiveDisplayBlock, App},
+    config::{app_config::AppConfig, keymap::Action},
+    event::Key,
+    network::IoEvent,
+    palette::{Command, Palette},
+};
+
+use super::{
+    anime::get_suggestion,
+    display_block::{
+        bookmarks::get_bookmarks,
+        history::get_history,
+        list_changes::get_list_changes,
+        ranking::{get_anime_rank, get_manga_rank},
+        schedule::get_schedule,
+        seasonal::reload_seasonal,
+        user_info::get_stats_dashboard,
+    },
+    help,
+    user::{get_user_anime_list, get_user_manga_list, get_user_profile},
+};
+
+pub fn open(app: &mut App) {
+    app.palette = Palette {
+        open: true,
+        ..Palette::default()
+    };
+}
+
+/// keys typed in the palette, it takes every key until it is closed
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if app.is_action(Action::Cancel, k) => app.palette = Palette::default(),
+
+        k if app.is_action(Action::Submit, k) => {
+            let command = app.palette_commands().get(app.palette.selected).cloned();
+            app.palette = Palette::default();
+            if let Some(command) = command {
+                run(command, app);
+            }
+        }
+
+        k if app.is_action(Action::DeleteBackward, k) => {
+            app.palette.query.pop();
+            app.palette.selected = 0;
+        }
+
+        k if app.is_action(Action::DeleteToStart, k) || app.is_action(Action::ClearInput, k) => {
+            app.palette.query.clear();
+            app.palette.selected = 0;
+        }
+
+        Key::Char(c) => {
+            app.palette.query.push(c);
+            app.palette.selected = 0;
+        }
+
+        k if app.is_action(Action::Down, k) => {
+            let last = app.palette_commands().len().saturating_sub(1);
+            app.palette.selected = (app.palette.selected + 1).min(last);
+        }
+
+        k if app.is_action(Action::Up, k) => {
+            app.palette.selected = app.palette.selected.saturating_sub(1);
+        }
+
+        _ => {}
+    }
+}
+
+pub fn run(command: Command, app: &mut App) {
+    app.fuzzy_filter.editing = false;
+    match command {
+        Command::Seasonal(season, year) => {
+            // same order as the season popup
+            app.anime_season.selected_season = match season {
+                Season::Spring => 1,
+                Season::Summer => 2,
+                Season::Fall => 3,
+                _ => 0,
+            };
+            app.anime_season.selected_year = year;
+            reload_seasonal(app);
+        }
+
+        Command::AnimeRanking(i) => {
+            app.anime_ranking_type_index = i;
+            app.anime_ranking_type = get_anime_rank(i);
+            // a cached ranking may be of another type, like the ranking popup
+            app.reset_result_index();
+            app.active_display_block = ActiveDisplayBlock::Loading;
+            app.dispatch(IoEvent::GetAnimeRanking(app.anime_ranking_type.clone()));
+        }
+
+        Command::MangaRanking(i) => {
+            app.manga_ranking_type_index = i;
+            app.manga_ranking_type = get_manga_rank(i);
+            app.reset_result_index();
+            app.active_display_block = ActiveDisplayBlock::Loading;
+            app.dispatch(IoEvent::GetMangaRanking(app.manga_ranking_type.clone()));
+        }
+
+        Command::Suggestions => get_suggestion(app),
+
+        Command::Profile => get_user_profile(app),
+
+        Command::StatsDashboard => get_stats_dashboard(app),
+
+        Command::AnimeList(status) => {
+            app.list_user = None;
+            app.anime_list_status = status;
+            app.reset_result_index();
+            get_user_anime_list(app);
+        }
+
+        Command::MangaList(status) => {
+            app.list_user = None;
+            app.manga_list_status = status;
+            app.reset_result_index();
+            get_user_manga_list(app);
+        }
+
+        Command::Bookmarks => get_bookmarks(app),
+
+        Command::History => get_history(app),
+
+        Command::ListChanges => get_list_changes(app),
+
+        Command::Schedule => get_schedule(app, ScheduleSource::MyList),
+
+        Command::OpenAnime(id) => {
+            app.active_display_block = ActiveDisplayBlock::Loading;
+            app.dispatch(IoEvent::GetAnime(id));
+        }
+
+        Command::OpenManga(id) => {
+            app.active_display_block = ActiveDisplayBlock::Loading;
+            app.dispatch(IoEvent::GetManga(id));
+        }
+
+        Command::Help => {
+            if app.active_display_block != ActiveDisplayBlock::Help {
+                help::open(app);
+            }
+            return;
+        }
+
+        Command::ToggleLogger => {
+            let behavior = &mut app.app_config.behavior;
+            behavior.show_logger = !behavior.show_logger;
+            return;
+        }
+
+        Command::SwitchTitleLanguage => {
+            app.app_config.title_language = match app.app_config.title_language {
+                TitleLanguage::English => TitleLanguage::Japanese,
+                TitleLanguage::Japanese => TitleLanguage::English,
+            };
+            if let Err(e) =
+                AppConfig::save_setting("title_language", &app.app_config.title_language)
+            {
+                app.status_message = Some(format!("Could not save the title language: {}", e));
+            }
+            return;
+        }
+
+        Command::ClearCache => {
+            app.status_message = Some(match app.clear_image_cache() {
+                Ok(removed) => format!("Removed {} cached images", removed),
+                Err(e) => format!("Could not clear the image cache: {}", e),
+            });
+            return;
+        }
+
+        Command::ExportList => {
+            app.status_message = Some(match app.export_current_list() {
+                Ok(file) => format!("List exported to {}", file),
+                Err(e) => format!("Could not export the list: {}", e),
+            });
+            return;
+        }
+    }
+    app.active_block = ActiveBlock::DisplayBlock;
+}
@@ -1,46 +1,109 @@
 use super::common;
-use crate::app::{ActiveDisplayBlock, App, Data, USER_OPTIONS, USER_OPTIONS_RANGE};
+use super::display_block::{bookmarks::get_bookmarks, history::get_history};
+use crate::app::{
+    ActiveDisplayBlock, App, Data, ANONYMOUS_USER_OPTIONS, NO_MENU_SELECTION, USER_OPTIONS,
+    USER_OPTIONS_RANGE,
+};
+use crate::config::keymap::Action;
 
 use crate::event::Key;
 use crate::network::IoEvent;
 
 pub fn handler(key: Key, app: &mut App) {
+    if app.is_anonymous {
+        anonymous_handler(key, app);
+        return;
+    }
     match key {
-        k if common::down_event(k) => {
+        k if app.is_action(Action::Down, k) => {
             let next_index = USER_OPTIONS_RANGE.start
                 + common::on_down_press(
                     &USER_OPTIONS,
-                    Some(app.library.selected_index % USER_OPTIONS_RANGE.len()),
+                    Some(
+                        app.library
+                            .selected_index
+                            .saturating_sub(USER_OPTIONS_RANGE.start),
+                    ),
                 );
             app.library.selected_index = next_index;
         }
-        k if common::up_event(k) => {
+        k if app.is_action(Action::Up, k) => {
             let next_index = USER_OPTIONS_RANGE.start
                 + common::on_up_press(
                     &USER_OPTIONS,
-                    Some(app.library.selected_index % USER_OPTIONS_RANGE.len()),
+                    Some(
+                        app.library
+                            .selected_index
+                            .saturating_sub(USER_OPTIONS_RANGE.start),
+                    ),
                 );
             app.library.selected_index = next_index;
         }
 
-        Key::Enter => {
-            match app.library.selected_index {
+        k if app.is_action(Action::Select, k) => {
+            match app
+                .library
+                .selected_index
+                .saturating_sub(USER_OPTIONS_RANGE.start)
+            {
                 // profile
-                3 => get_user_profile(app),
+                0 => get_user_profile(app),
                 // animeList
-                4 => get_user_anime_list(app),
+                1 => {
+                    app.list_user = None;
+                    get_user_anime_list(app)
+                }
                 // mangaList
-                5 => get_user_manga_list(app),
+                2 => {
+                    app.list_user = None;
+                    get_user_manga_list(app)
+                }
+                // bookmarks
+                3 => get_bookmarks(app),
+                // history
+                4 => get_history(app),
                 // This is required because Rust can't tell if this pattern in exhaustive
                 _ => {}
             };
-            app.library.selected_index = 9;
+            app.library.selected_index = NO_MENU_SELECTION;
+        }
+        _ => (),
+    };
+}
+
+// without login, only logging in and the local bookmarks and history are available
+fn anonymous_handler(key: Key, app: &mut App) {
+    let index = app
+        .library
+        .selected_index
+        .saturating_sub(USER_OPTIONS_RANGE.start);
+    match key {
+        k if app.is_action(Action::Down, k) => {
+            app.library.selected_index = USER_OPTIONS_RANGE.start
+                + common::on_down_press(&ANONYMOUS_USER_OPTIONS, Some(index));
+        }
+        k if app.is_action(Action::Up, k) => {
+            app.library.selected_index = USER_OPTIONS_RANGE.start
+                + common::on_up_press(&ANONYMOUS_USER_OPTIONS, Some(index));
+        }
+        k if app.is_action(Action::Select, k) => {
+            match index {
+                0 => {
+                    app.active_display_block = ActiveDisplayBlock::Loading;
+                    app.dispatch(IoEvent::Login);
+                }
+                1 => get_bookmarks(app),
+                2 => get_history(app),
+                _ => {}
+            }
+            app.library.selected_index = NO_MENU_SELECTION;
         }
         _ => (),
     };
 }
 
-fn get_user_anime_list(app: &mut App) {
+/// loads the anime list of `app.list_user` with `app.anime_list_status`
+pub fn get_user_anime_list(app: &mut App) {
     let (is_data_available, is_next, index) = is_user_anime_list_data_available(app);
     if is_next {
         app.load_next_route();
@@ -50,11 +113,15 @@ fn get_user_anime_list(app: &mut App) {
         app.load_route(index.unwrap());
     } else {
         app.active_display_block = ActiveDisplayBlock::Loading;
-        app.dispatch(IoEvent::GetAnimeList(app.anime_list_status.clone()));
+        app.dispatch(IoEvent::GetAnimeList(
+            app.list_user.clone(),
+            app.anime_list_status.clone(),
+        ));
     }
 }
 
-fn get_user_manga_list(app: &mut App) {
+/// loads the manga list of `app.list_user` with `app.manga_list_status`
+pub fn get_user_manga_list(app: &mut App) {
     let (is_data_available, is_next, index) = is_user_manga_list_data_available(app);
     if is_next {
         app.load_next_route();
@@ -64,11 +131,14 @@ fn get_user_manga_list(app: &mut App) {
         app.load_route(index.unwrap());
     } else {
         app.active_display_block = ActiveDisplayBlock::Loading;
-        app.dispatch(IoEvent::GetMangaList(app.manga_list_status.clone()));
+        app.dispatch(IoEvent::GetMangaList(
+            app.list_user.clone(),
+            app.manga_list_status.clone(),
+        ));
     }
 }
 
-fn get_user_profile(app: &mut App) {
+pub fn get_user_profile(app: &mut App) {
     let (is_data_available, is_next, index) = is_user_profile_data_available(app);
     if is_next {
         app.load_next_route();
@@ -89,7 +159,7 @@ pub fn is_user_anime_list_data_available(app: &App) -> (bool, bool, Option<u16>)
             && app.navigator.data[&id].data.is_some()
         {
             if let Data::UserAnimeList(d) = app.navigator.data[&id].data.as_ref().unwrap() {
-                if d.status == app.anime_list_status {
+                if d.status == app.anime_list_status && d.user == app.list_user {
                     let is_next = app.navigator.index + 1 == i;
                     return (true, is_next, Some(id));
                 }
@@ -106,7 +176,7 @@ pub fn is_user_manga_list_data_available(app: &App) -> (bool, bool, Option<u16>)
             && app.navigator.data[&id].data.is_some()
         {
             if let Data::UserMangaList(d) = app.navigator.data[&id].data.as_ref().unwrap() {
-                if d.status == app.manga_list_status {
+                if d.status == app.manga_list_status && d.user == app.list_user {
                     let is_next = app.navigator.index + 1 == i;
                     return (true, is_next, Some(id));
                 }
@@ -0,0 +1,122 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{bookmarks::MediaKind, config::ConfigError, fuzzy};
+
+/// A detail page opened at some point
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Viewed {
+    pub kind: MediaKind,
+    pub id: u64,
+    pub title: String,
+    // url of the medium picture
+    #[serde(default)]
+    pub thumbnail: Option<String>,
+    // unix timestamp of the last time it was opened
+    pub viewed_at: i64,
+}
+
+/// Opened detail pages of the cache dir, the last opened first
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct History {
+    pub entries: Vec<Viewed>,
+}
+
+impl History {
+    /// no file yet is no history
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| ConfigError::IOError(e.into()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let content =
+            serde_json::to_string_pretty(self).map_err(|e| ConfigError::IOError(e.into()))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// puts the title first, a title opened again is moved rather than repeated
+    pub fn record(&mut self, viewed: Viewed, limit: usize) {
+        self.entries
+            .retain(|v| !(v.kind == viewed.kind && v.id == viewed.id));
+        self.entries.insert(0, viewed);
+        self.entries.truncate(limit);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// entries whose title matches `query`, in the history order
+    pub fn matching(&self, query: &str) -> Vec<&Viewed> {
+        self.entries
+            .iter()
+            .filter(|v| query.is_empty() || fuzzy::fuzzy_score(query, &v.title).is_some())
+            .collect()
+    }
+}
+
+/// how long ago a title was opened, like "3h ago"
+pub fn viewed_ago(viewed_at: i64, now: i64) -> String {
+    let seconds = (now - viewed_at).max(0);
+    match seconds {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", seconds / 60),
+        3600..86400 => format!("{}h ago", seconds / 3600),
+        _ => format!("{}d ago", seconds / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewed(kind: MediaKind, id: u64, title: &str, viewed_at: i64) -> Viewed {
+        Viewed {
+            kind,
+            id,
+            title: title.to_string(),
+            thumbnail: None,
+            viewed_at,
+        }
+    }
+
+    #[test]
+    fn test_record_history() {
+        let mut history = History::default();
+        history.record(viewed(MediaKind::Anime, 1, "Monster", 10), 3);
+        history.record(viewed(MediaKind::Manga, 1, "Monster", 20), 3);
+        history.record(viewed(MediaKind::Anime, 2, "Mushishi", 30), 3);
+        // opened again, moved first with the new time
+        history.record(viewed(MediaKind::Anime, 1, "Monster", 40), 3);
+        let order: Vec<(MediaKind, u64)> = history.entries.iter().map(|v| (v.kind, v.id)).collect();
+        assert_eq!(
+            order,
+            vec![
+                (MediaKind::Anime, 1),
+                (MediaKind::Anime, 2),
+                (MediaKind::Manga, 1)
+            ]
+        );
+        assert_eq!(history.entries[0].viewed_at, 40);
+
+        history.record(viewed(MediaKind::Anime, 3, "Ping Pong", 50), 3);
+        assert_eq!(history.entries.len(), 3);
+        assert_eq!(history.entries[2].kind, MediaKind::Anime);
+
+        assert_eq!(history.matching("mush").len(), 1);
+        assert_eq!(history.matching("").len(), 3);
+    }
+
+    #[test]
+    fn test_viewed_ago() {
+        assert_eq!(viewed_ago(100, 130), "just now");
+        assert_eq!(viewed_ago(0, 7200), "2h ago");
+        assert_eq!(viewed_ago(0, 3 * 86400 + 5), "3d ago");
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+
+use crate::{
+    airing,
+    api::model::{Anime, Season, UserWatchStatus},
+    palette::Command,
+};
+
+/// Entries of the home dashboard widgets and the selection among them
+#[derive(Debug, Default)]
+pub struct Home {
+    // None until fetched, the widgets show a loading line meanwhile
+    pub watching: Option<Vec<Anime>>,
+    pub airing: Option<Vec<Anime>>,
+    pub recent_updates: Option<Vec<Anime>>,
+    // index of the focused widget in the configured ones
+    pub focused: usize,
+    // entry of the focused widget
+    pub selected: usize,
+}
+
+/// shortcuts of the quick actions widget, run like palette commands
+pub fn quick_actions(season: Season, year: u16, is_anonymous: bool) -> Vec<Command> {
+    let mut commands = vec![
+        Command::Seasonal(season, year),
+        Command::Schedule,
+        Command::AnimeRanking(0),
+    ];
+    if !is_anonymous {
+        commands.extend([
+            Command::AnimeList(Some(UserWatchStatus::Watching)),
+            Command::Suggestions,
+            Command::StatsDashboard,
+        ]);
+    }
+    commands.extend([Command::Bookmarks, Command::History]);
+    commands
+}
+
+/// titles of my list among the seasonal ones that are still airing, next to air first
+pub fn airing_from_my_list(seasonal: Vec<Anime>, now: DateTime<Utc>) -> Vec<Anime> {
+    let mut airing: Vec<(DateTime<Utc>, Anime)> = seasonal
+        .into_iter()
+        .filter(|anime| anime.my_list_status.is_some())
+        .filter_map(|anime| Some((airing::next_episode_airing(&anime, now)?, anime)))
+        .collect();
+    airing.sort_by_key(|(next, _)| *next);
+    airing.into_iter().map(|(_, anime)| anime).collect()
+}
@@ -23,8 +23,8 @@ Routing
 /// Authorization
 pub mod auth;
 
-/// API request functions
-pub mod api;
+/// API request functions, re-exported from the `mal-api-rs` crate
+pub use mal_api as api;
 
 /// UI
 pub mod ui;
@@ -47,4 +47,46 @@ pub mod handlers;
 /// Cli
 pub mod cli;
 
+/// List comparison
+pub mod compare;
+
+/// Statistics computed from whole user lists
+pub mod stats;
+
+/// Client-side sorting and filtering of user lists
+pub mod list_view;
+
+/// Fuzzy matching of titles for the filter line
+pub mod fuzzy;
+
+/// Command palette listing every view and action
+pub mod palette;
+
+/// Navigation history kept between runs
+pub mod session;
+
+/// Titles pinned locally, apart from the MAL lists
+pub mod bookmarks;
+
+/// Log of the opened detail pages
+pub mod history;
+
+/// Broadcast times of airing anime
+pub mod airing;
+
+/// Home dashboard widgets
+pub mod home;
+
+/// New episode notifications of the watching list
+pub mod notifier;
+
+/// One more episode or chapter in the list
+pub mod progress;
+
+/// Status, score, tag or delete on several list entries at once
+pub mod bulk;
+
+/// Changes of my list made in the session, to undo them
+pub mod undo;
+
 pub mod logging;
@@ -0,0 +1,319 @@
+use crate::api::model::{Anime, DateTimeWrapper, DateWrapper, Manga, Node, Page, PageableData};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use strum::IntoEnumIterator;
+use strum_macros::{EnumIter, IntoStaticStr};
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize, EnumIter, IntoStaticStr,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ListSort {
+    #[default]
+    Score,
+    Title,
+    #[strum(serialize = "Last Updated")]
+    LastUpdated,
+    #[strum(serialize = "Start Date")]
+    StartDate,
+    Progress,
+    #[strum(serialize = "MAL Mean")]
+    Mean,
+}
+
+impl ListSort {
+    pub fn next(self) -> Self {
+        let sorts: Vec<Self> = Self::iter().collect();
+        let index = sorts.iter().position(|&s| s == self).unwrap_or(0);
+        sorts[(index + 1) % sorts.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let sorts: Vec<Self> = Self::iter().collect();
+        let index = sorts.iter().position(|&s| s == self).unwrap_or(0);
+        sorts[(index + sorts.len() - 1) % sorts.len()]
+    }
+}
+
+/// Filters applied on a loaded user list, None matches everything
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListFilter {
+    pub genre: Option<String>,
+    pub media_type: Option<String>,
+    // airing/publishing status
+    pub status: Option<String>,
+    pub min_score: u8,
+    pub max_score: u8,
+    pub tag: Option<String>,
+}
+
+impl Default for ListFilter {
+    fn default() -> Self {
+        Self {
+            genre: None,
+            media_type: None,
+            status: None,
+            min_score: 0,
+            max_score: 10,
+            tag: None,
+        }
+    }
+}
+
+/// Sort and filters of a user list view, saved in the config
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListView {
+    pub sort: ListSort,
+    // reverse the natural order of `sort` (highest, newest or a-z first)
+    pub reverse: bool,
+    pub filter: ListFilter,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListViews {
+    pub anime: ListView,
+    pub manga: ListView,
+}
+
+/// What sorting and filtering need to know about a list entry
+pub trait ListEntry {
+    fn title(&self) -> &str;
+    // 0 is not scored
+    fn score(&self) -> u8;
+    fn updated_at(&self) -> Option<&DateTimeWrapper>;
+    fn start_date(&self) -> Option<&DateWrapper>;
+    // episodes watched or chapters read
+    fn progress(&self) -> u64;
+    fn mean(&self) -> Option<f64>;
+    fn genres(&self) -> Vec<&str>;
+    fn media_type(&self) -> Option<&'static str>;
+    fn status(&self) -> Option<&'static str>;
+    fn tags(&self) -> Vec<&str>;
+}
+
+impl ListEntry for Anime {
+    fn title(&self) -> &str {
+        &self.title
+    }
+    fn score(&self) -> u8 {
+        self.my_list_status.as_ref().map_or(0, |s| s.score)
+    }
+    fn updated_at(&self) -> Option<&DateTimeWrapper> {
+        self.my_list_status.as_ref().map(|s| &s.updated_at)
+    }
+    fn start_date(&self) -> Option<&DateWrapper> {
+        self.start_date.as_ref()
+    }
+    fn progress(&self) -> u64 {
+        self.my_list_status
+            .as_ref()
+            .map_or(0, |s| s.num_episodes_watched)
+    }
+    fn mean(&self) -> Option<f64> {
+        self.mean
+    }
+    fn genres(&self) -> Vec<&str> {
+        self.genres
+            .iter()
+            .flatten()
+            .map(|g| g.name.as_str())
+            .collect()
+    }
+    fn media_type(&self) -> Option<&'static str> {
+        self.media_type.clone().map(Into::into)
+    }
+    fn status(&self) -> Option<&'static str> {
+        self.status.clone().map(Into::into)
+    }
+    fn tags(&self) -> Vec<&str> {
+        self.my_list_status
+            .iter()
+            .flat_map(|s| s.tags.iter().flatten())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+impl ListEntry for Manga {
+    fn title(&self) -> &str {
+        &self.title
+    }
+    fn score(&self) -> u8 {
+        self.my_list_status.as_ref().map_or(0, |s| s.score)
+    }
+    fn updated_at(&self) -> Option<&DateTimeWrapper> {
+        self.my_list_status.as_ref().map(|s| &s.updated_at)
+    }
+    fn start_date(&self) -> Option<&DateWrapper> {
+        self.start_date.as_ref()
+    }
+    fn progress(&self) -> u64 {
+        self.my_list_status
+            .as_ref()
+            .map_or(0, |s| s.num_chapters_read)
+    }
+    fn mean(&self) -> Option<f64> {
+        self.mean
+    }
+    fn genres(&self) -> Vec<&str> {
+        self.genres
+            .iter()
+            .flatten()
+            .map(|g| g.name.as_str())
+            .collect()
+    }
+    fn media_type(&self) -> Option<&'static str> {
+        self.media_type.clone().map(Into::into)
+    }
+    fn status(&self) -> Option<&'static str> {
+        self.status.clone().map(Into::into)
+    }
+    fn tags(&self) -> Vec<&str> {
+        self.my_list_status
+            .iter()
+            .flat_map(|s| s.tags.iter().flatten())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+impl ListFilter {
+    pub fn matches(&self, entry: &impl ListEntry) -> bool {
+        let score = entry.score();
+        self.genre
+            .as_ref()
+            .is_none_or(|g| entry.genres().contains(&g.as_str()))
+            && self
+                .media_type
+                .as_ref()
+                .is_none_or(|m| entry.media_type() == Some(m.as_str()))
+            && self
+                .status
+                .as_ref()
+                .is_none_or(|s| entry.status() == Some(s.as_str()))
+            && self
+                .tag
+                .as_ref()
+                .is_none_or(|t| entry.tags().contains(&t.as_str()))
+            && score >= self.min_score
+            && score <= self.max_score
+    }
+}
+
+impl ListView {
+    /// natural order: highest, newest or a-z first
+    fn compare(&self, a: &impl ListEntry, b: &impl ListEntry) -> Ordering {
+        match self.sort {
+            ListSort::Score => b.score().cmp(&a.score()),
+            ListSort::Title => a.title().to_lowercase().cmp(&b.title().to_lowercase()),
+            ListSort::LastUpdated => b
+                .updated_at()
+                .map(|d| d.datetime)
+                .cmp(&a.updated_at().map(|d| d.datetime)),
+            ListSort::StartDate => b
+                .start_date()
+                .map(|d| d.date)
+                .cmp(&a.start_date().map(|d| d.date)),
+            ListSort::Progress => b.progress().cmp(&a.progress()),
+            ListSort::Mean => b.mean().unwrap_or(0.0).total_cmp(&a.mean().unwrap_or(0.0)),
+        }
+    }
+
+    /// Sorted and filtered copy of a loaded list page
+    pub fn apply<T>(&self, page: &Page<T>) -> Page<T>
+    where
+        T: ListEntry + Clone + std::fmt::Debug,
+    {
+        let mut data: Vec<Node<T>> = page
+            .data
+            .iter()
+            .filter(|n| self.filter.matches(&n.node))
+            .cloned()
+            .collect();
+        // stable, ties keep the api order
+        data.sort_by(|a, b| {
+            let ordering = self.compare(&a.node, &b.node);
+            if self.reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        PageableData {
+            data,
+            paging: page.paging.clone(),
+        }
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        self.filter != ListFilter::default()
+    }
+}
+
+/// Distinct values of a list field, to cycle through in the filter popup
+pub fn collect_options<T, F>(page: &Page<T>, values: F) -> Vec<String>
+where
+    T: Clone + std::fmt::Debug,
+    F: Fn(&T) -> Vec<String>,
+{
+    let mut options: Vec<String> = page.data.iter().flat_map(|n| values(&n.node)).collect();
+    options.sort();
+    options.dedup();
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(entries: serde_json::Value) -> Page<Anime> {
+        serde_json::from_value(serde_json::json!({ "data": entries, "paging": {} })).unwrap()
+    }
+
+    fn anime(id: u64, title: &str, score: u8, genre: &str) -> serde_json::Value {
+        serde_json::json!({
+            "node": {
+                "id": id,
+                "title": title,
+                "genres": [{ "id": 1, "name": genre }],
+                "media_type": "tv",
+                "my_list_status": {
+                    "status": "completed",
+                    "score": score,
+                    "num_episodes_watched": id,
+                    "is_rewatching": false,
+                    "updated_at": "2024-01-01T00:00:00+00:00"
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_sort_and_filter() {
+        let page = page(serde_json::json!([
+            anime(1, "b", 7, "Action"),
+            anime(2, "a", 9, "Drama"),
+            anime(3, "c", 5, "Action"),
+        ]));
+        let ids = |p: &Page<Anime>| -> Vec<u64> { p.data.iter().map(|n| n.node.id).collect() };
+
+        let mut view = ListView::default();
+        assert_eq!(ids(&view.apply(&page)), vec![2, 1, 3]);
+
+        view.sort = ListSort::Title;
+        assert_eq!(ids(&view.apply(&page)), vec![2, 1, 3]);
+        view.reverse = true;
+        assert_eq!(ids(&view.apply(&page)), vec![3, 1, 2]);
+
+        view.sort = ListSort::Progress;
+        view.reverse = false;
+        view.filter.genre = Some("Action".to_string());
+        assert_eq!(ids(&view.apply(&page)), vec![3, 1]);
+
+        view.filter.min_score = 6;
+        assert_eq!(ids(&view.apply(&page)), vec![1]);
+    }
+}
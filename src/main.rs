@@ -3,6 +3,7 @@ use crossterm::cursor;
 use crossterm::execute;
 use crossterm::terminal;
 use crossterm::{cursor::MoveTo, ExecutableCommand};
+use log::warn;
 use mal::api::model::RankingType;
 use mal::handlers::common;
 use mal::logging::initialize_logging;
@@ -19,9 +20,8 @@ use tokio::sync::Mutex;
 use mal::app::*;
 use mal::auth::OAuth;
 // use mal::cli::{Opt, StructOpt};
-use mal::config::{app_config::AppConfig, oauth_config::AuthConfig};
+use mal::config::{app_config::AppConfig, keymap::Action, oauth_config::AuthConfig};
 use mal::event;
-use mal::event::key::Key;
 use mal::handlers;
 use mal::network::{IoEvent, Network};
 use mal::ui;
@@ -67,24 +67,40 @@ fn setup_panic_hook() {
 async fn main() -> Result<()> {
     better_panic::install();
     setup_panic_hook();
-    let exit = mal::cli::handle_args();
-    if exit {
+    let Some(cli) = mal::cli::handle_args() else {
         return Ok(());
-    }
+    };
 
     // initialize logging
     initialize_logging();
     // Get config
-    println!("==> Loading Configiration");
+    eprintln!("==> Loading Configiration");
     let app_config = AppConfig::load()?;
-    println!("==> Auth Configuration Loading");
+    // the local subcommands run without logging in
+    let command = match cli.command {
+        Some(command) if !command.needs_api() => {
+            return mal::cli::run_local_command(command, &app_config).await;
+        }
+        command => command,
+    };
+    eprintln!("==> Auth Configuration Loading");
     let auth_config = AuthConfig::load()?;
-    println!("==> Refreshing Token");
-    let oauth = OAuth::get_auth_async(auth_config).await?;
+    let oauth = if cli.anonymous {
+        eprintln!("==> Browsing anonymously");
+        OAuth::anonymous(&auth_config)
+    } else {
+        eprintln!("==> Refreshing Token");
+        OAuth::get_auth_async(auth_config).await?
+    };
+    if let Some(command) = command {
+        return mal::cli::run_command(command, &oauth, &app_config).await;
+    }
     let (sync_io_tx, sync_io_rx) = std::sync::mpsc::channel::<IoEvent>();
 
     // initialize app state
-    let app = Arc::new(Mutex::new(App::new(sync_io_tx, app_config.clone())));
+    let mut app = App::new(sync_io_tx, app_config.clone());
+    app.is_anonymous = oauth.is_anonymous();
+    let app = Arc::new(Mutex::new(app));
 
     let cloned_app = Arc::clone(&app);
     std::thread::spawn(move || {
@@ -124,6 +140,19 @@ async fn start_ui(app_config: AppConfig, app: &Arc<Mutex<App>>) -> Result<()> {
         app.dispatch(IoEvent::GetTopThree(TopThreeBlock::Anime(
             app_config.top_three_anime_types[0].clone(),
         )));
+        app.refresh_home();
+
+        let session = &app_config.session;
+        if session.restore {
+            let anonymous = app.is_anonymous;
+            if let Some(navigator) = mal::session::load(
+                &app_config.paths.session_file_path,
+                session.max_age_hours,
+                anonymous,
+            ) {
+                app.restore_navigator(navigator);
+            }
+        }
     }
 
     loop {
@@ -178,29 +207,49 @@ async fn start_ui(app_config: AppConfig, app: &Arc<Mutex<App>>) -> Result<()> {
         we switch between blocks by pressing Tab and between display by input and navigation
         we will implement a stack for display block to allow going back and forth
                 */
-        if let event::Event::Input(key) = events.next()? {
-            let key = common::get_lowercase_key(key);
-
-            let active_block = app.active_block;
-            // change the default of menu selecting to None when leaving the block
-            if key == Key::Tab {
-                // handle navigation between block
-                handlers::handle_tab(&mut app);
-            } else if key == Key::BackTab {
-                // handle navigation between block
-                handlers::handle_back_tab(&mut app);
-            } else if active_block == ActiveBlock::Input {
-                handlers::input_handler(key, &mut app);
-            } else if common::quit_event(key) {
-                app.exit_confirmation_popup = true;
-            } else if key == app.app_config.keys.back {
-                if app.active_block != ActiveBlock::Input {
-                    app.load_previous_route();
-                    break;
+        match events.next()? {
+            event::Event::Input(key) => {
+                let key = common::get_lowercase_key(key);
+
+                let active_block = app.active_block;
+                app.status_message = None;
+                // change the default of menu selecting to None when leaving the block
+                if app.palette.open {
+                    handlers::palette_handler(key, &mut app);
+                } else if app.fuzzy_filter.editing && active_block == ActiveBlock::DisplayBlock {
+                    // the filter line takes every key while typing
+                    handlers::fuzzy_filter_handler(key, &mut app);
+                } else if app.bulk.is_typing() && active_block == ActiveBlock::DisplayBlock {
+                    // and the tag line of the bulk edit popup
+                    handlers::bulk_tag_handler(key, &mut app);
+                } else if app.help_filter.editing
+                    && app.active_display_block == ActiveDisplayBlock::Help
+                {
+                    // so does the search line of the help screen
+                    handlers::help_handler(key, &mut app);
+                } else if app.is_action(Action::NextBlock, key) {
+                    // handle navigation between block
+                    handlers::handle_tab(&mut app);
+                } else if app.is_action(Action::PreviousBlock, key) {
+                    // handle navigation between block
+                    handlers::handle_back_tab(&mut app);
+                } else if active_block == ActiveBlock::Input {
+                    handlers::input_handler(key, &mut app);
+                } else if app.is_action(Action::Quit, key) {
+                    app.exit_confirmation_popup = true;
+                } else {
+                    handlers::handle_app(key, &mut app);
                 }
-            } else {
-                handlers::handle_app(key, &mut app);
             }
+            event::Event::Mouse(mouse) => handlers::mouse_handler(mouse, &mut app),
+            event::Event::Tick => app.check_new_episodes(),
+        }
+    }
+
+    if app_config.session.restore {
+        let app = app.lock().await;
+        if let Err(e) = mal::session::save(&app) {
+            warn!("Could not save the session: {}", e);
         }
     }
 
@@ -1,21 +1,36 @@
 use crate::{
+    airing::{self, Schedule, ScheduleSource},
     api::{
         self, model::*, GetAnimeDetailQuery, GetAnimeRankingQuery, GetMangaDetailQuery,
         GetMangaRankingQuery, GetSeasonalAnimeQuery, GetSuggestedAnimeQuery,
         GetUserInformationQuery, UpdateUserAnimeListStatusQuery, UpdateUserMangaStatus,
     },
     app::{
-        ActiveBlock, ActiveDisplayBlock, App, Data, Route, SelectedSearchTab, TopThreeBlock,
-        UserAnimeList, UserMangaList,
+        get_season, ActiveBlock, ActiveDisplayBlock, App, Data, Route, SelectedSearchTab,
+        TopThreeBlock, UserAnimeList, UserMangaList,
     },
-    auth::OAuth,
+    auth::{AuthError, OAuth},
+    bookmarks::MediaKind,
+    bulk::{BulkPopup, BulkProgress, BulkRequest},
+    compare, home, notifier, stats,
+    undo::ListChange,
 };
 use bytes::Bytes;
+use chrono::Datelike;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use tracing::warn;
 
+// what the home widgets show, the seasonal list is large so only these are fetched
+const HOME_FIELDS: &str =
+    "id,title,alternative_titles,status,my_list_status,num_episodes,broadcast,main_picture";
+
+// pause between the calls of a bulk edit, MAL rejects bursts of list updates
+const BULK_DELAY_MILLISECONDS: u64 = 400;
+
 #[derive(Debug)]
 pub enum IoEvent {
     GetSearchResults(String),
@@ -29,12 +44,31 @@ pub enum IoEvent {
     GetSuggestedAnime,
     UpdateAnimeListStatus(u64, UpdateUserAnimeListStatusQuery),
     DeleteAnimeListStatus(String),
-    GetAnimeList(Option<UserWatchStatus>),
-    GetMangaList(Option<UserReadStatus>),
+    // user (None is @me), status
+    GetAnimeList(Option<String>, Option<UserWatchStatus>),
+    GetMangaList(Option<String>, Option<UserReadStatus>),
     UpdateMangaListStatus(u64, UpdateUserMangaStatus),
+    // list updates made outside the edit popups, reported in the status message
+    PatchAnimeListStatus(u64, UpdateUserAnimeListStatusQuery),
+    PatchMangaListStatus(u64, UpdateUserMangaStatus),
     DeleteMangaListStatus(String),
+    // title and call of every marked entry, sent one after the other
+    RunBulk(Vec<(String, BulkRequest)>),
+    // inverse of a change of my list, it is taken out of the list changes until it goes through
+    RevertListChange(ListChange),
     GetUserInfo,
+    GetMangaStatistics,
+    GetStatsDashboard,
+    GetHomeDashboard,
+    GetSchedule(ScheduleSource),
+    // new episodes of the watching list, announced in the background
+    CheckNewEpisodes,
     GetTopThree(TopThreeBlock),
+    Login,
+    // the login page redirect, waited for on its own thread
+    LoginRedirect(Result<OAuth, AuthError>),
+    // user, is_anime
+    GetComparison(String, bool),
 }
 
 #[derive(Clone)]
@@ -67,9 +101,9 @@ impl<'a> Network<'a> {
 
             IoEvent::GetSuggestedAnime => self.get_suggested().await,
 
-            IoEvent::GetAnimeList(s) => self.get_user_anime_list(s).await,
+            IoEvent::GetAnimeList(u, s) => self.get_user_anime_list(u, s).await,
 
-            IoEvent::GetMangaList(s) => self.get_user_manga_list(s).await,
+            IoEvent::GetMangaList(u, s) => self.get_user_manga_list(u, s).await,
 
             IoEvent::GetAnime(id) => self.get_anime_details(id).await,
 
@@ -84,7 +118,26 @@ impl<'a> Network<'a> {
             // IoEvent::UpdateMangaListStatus(String) => {}
             // IoEvent::DeleteMangaListStatus(String) => {}
             IoEvent::GetUserInfo => self.get_user_info().await,
+            IoEvent::GetMangaStatistics => self.get_manga_statistics().await,
+            IoEvent::GetStatsDashboard => self.get_stats_dashboard().await,
+
+            IoEvent::GetHomeDashboard => self.get_home_dashboard().await,
+
+            IoEvent::GetSchedule(source) => self.get_schedule(source).await,
+            IoEvent::CheckNewEpisodes => self.check_new_episodes().await,
             IoEvent::GetTopThree(r) => self.get_top_three(r).await,
+            IoEvent::Login => self.login().await,
+            IoEvent::LoginRedirect(redirect) => self.finish_login(redirect).await,
+            IoEvent::GetComparison(user, is_anime) => self.get_comparison(user, is_anime).await,
+
+            IoEvent::PatchAnimeListStatus(anime_id, query) => {
+                self.patch_anime_list_status(anime_id, query).await
+            }
+            IoEvent::PatchMangaListStatus(manga_id, query) => {
+                self.patch_manga_list_status(manga_id, query).await
+            }
+            IoEvent::RunBulk(requests) => self.run_bulk(requests),
+            IoEvent::RevertListChange(change) => self.revert_list_change(change).await,
 
             IoEvent::UpdateAnimeListStatus(anime_id, query) => {
                 self.update_anime_list_status(anime_id, query).await
@@ -110,6 +163,13 @@ impl<'a> Network<'a> {
 
         match api::get_anime_details(id, &query, &self.oauth).await {
             Ok(result) => {
+                let title = result.get_title(&app.app_config.title_language, false)[0].clone();
+                app.record_viewed(
+                    MediaKind::Anime,
+                    result.id,
+                    title,
+                    result.main_picture.as_ref(),
+                );
                 app.anime_details = Some(result.clone());
             }
             Err(e) => {
@@ -160,6 +220,13 @@ impl<'a> Network<'a> {
 
         match api::get_manga_details(id, &query, &self.oauth).await {
             Ok(result) => {
+                let title = result.get_title(&app.app_config.title_language, false)[0].clone();
+                app.record_viewed(
+                    MediaKind::Manga,
+                    result.id,
+                    title,
+                    result.main_picture.as_ref(),
+                );
                 app.manga_details = Some(result.clone());
             }
             Err(e) => {
@@ -478,6 +545,74 @@ impl<'a> Network<'a> {
         );
     }
 
+    async fn login(&mut self) {
+        if let Err(e) = self.oauth.open_login_page().await {
+            self.login_failed(e).await;
+            return;
+        }
+        // the other events go on while the browser redirect is waited for
+        let auth = self.oauth.clone();
+        let io_tx = self.app.lock().await.io_tx.clone();
+        std::thread::spawn(move || {
+            let redirect = auth.wait_login_redirect();
+            if let Some(io_tx) = io_tx {
+                if let Err(e) = io_tx.send(IoEvent::LoginRedirect(redirect)) {
+                    warn!("Error sending the login redirect {}", e);
+                }
+            }
+        });
+    }
+
+    async fn finish_login(&mut self, redirect: Result<OAuth, AuthError>) {
+        let result = match redirect {
+            Ok(auth) => self.oauth.finish_login_async(auth).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            self.login_failed(e).await;
+            return;
+        }
+        {
+            let mut app = self.app.lock().await;
+            app.is_anonymous = false;
+        }
+        self.get_user_info().await;
+        // the home widgets only had the anonymous placeholders
+        self.get_home_dashboard().await;
+    }
+
+    async fn login_failed(&mut self, e: AuthError) {
+        let mut app = self.app.lock().await;
+        app.api_error = format!("Login failed: {}", e);
+        app.active_display_block = ActiveDisplayBlock::Error;
+    }
+
+    async fn get_comparison(&mut self, user: String, is_anime: bool) {
+        self.oauth.refresh().unwrap();
+        let nsfw = self.app.lock().await.app_config.nsfw;
+        // both full lists are fetched, don't block the ui meanwhile
+        let result = compare::compare_with(&user, is_anime, nsfw, &self.oauth).await;
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(comparison) => app.comparison = Some(comparison),
+            Err(e) => {
+                app.write_error(e);
+                app.active_display_block = ActiveDisplayBlock::Error;
+                return;
+            }
+        }
+        let route = Route {
+            data: Some(Data::Comparison(app.comparison.as_ref().unwrap().clone())),
+            block: ActiveDisplayBlock::Comparison,
+            title: format!("Comparison with {}", user),
+            image: None,
+        };
+        app.active_block = ActiveBlock::DisplayBlock;
+        app.active_display_block = ActiveDisplayBlock::Comparison;
+        app.display_block_title = route.title.clone();
+        app.push_navigation_stack(route);
+    }
+
     async fn get_user_info(&mut self) {
         self.oauth.refresh().unwrap();
         let mut app = self.app.lock().await;
@@ -507,6 +642,196 @@ impl<'a> Network<'a> {
         app.display_block_title = "Profile".to_string();
     }
 
+    async fn get_manga_statistics(&mut self) {
+        self.oauth.refresh().unwrap();
+        let nsfw = self.app.lock().await.app_config.nsfw;
+        // the whole manga list is fetched, don't block the ui meanwhile
+        let result = stats::fetch_manga_statistics(nsfw, &self.oauth).await;
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(statistics) => app.manga_statistics = Some(statistics),
+            Err(e) => {
+                app.profile_show_manga = false;
+                app.write_error(e);
+                app.active_display_block = ActiveDisplayBlock::Error;
+            }
+        }
+    }
+
+    async fn get_stats_dashboard(&mut self) {
+        self.oauth.refresh().unwrap();
+        let nsfw = self.app.lock().await.app_config.nsfw;
+        // the whole anime list is fetched, don't block the ui meanwhile
+        let result = stats::fetch_anime_dashboard(nsfw, &self.oauth).await;
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(dashboard) => app.stats_dashboard = Some(dashboard),
+            Err(e) => {
+                app.write_error(e);
+                app.active_display_block = ActiveDisplayBlock::Error;
+                return;
+            }
+        }
+        let route = Route {
+            data: Some(Data::StatsDashboard(
+                app.stats_dashboard.as_ref().unwrap().clone(),
+            )),
+            block: ActiveDisplayBlock::StatsDashboard,
+            title: "Statistics".to_string(),
+            image: None,
+        };
+        app.stats_dashboard_tab = 0;
+        app.active_block = ActiveBlock::DisplayBlock;
+        app.active_display_block = ActiveDisplayBlock::StatsDashboard;
+        app.display_block_title = route.title.clone();
+        app.push_navigation_stack(route);
+    }
+
+    /// entries of the home widgets, fetched without leaving the home screen
+    async fn get_home_dashboard(&mut self) {
+        self.oauth.refresh().unwrap();
+        let (nsfw, limit) = {
+            let app = self.app.lock().await;
+            (app.app_config.nsfw, app.app_config.home.entries_per_widget)
+        };
+        let fields = Some(HOME_FIELDS.to_string());
+        let list_query = |status| api::GetUserAnimeListQuery {
+            fields: fields.clone(),
+            status,
+            sort: Some(SortStyle::ListUpdatedAt),
+            limit,
+            offset: 0,
+            nsfw,
+        };
+        let list = |result: Result<UserList<Anime, UserAnimeListStatus>, _>| match result {
+            Ok(list) => list
+                .data
+                .into_iter()
+                .map(|entry| entry.node)
+                .collect::<Vec<Anime>>(),
+            Err(e) => {
+                warn!("Could not get the home dashboard entries: {:?}", e);
+                vec![]
+            }
+        };
+        let watching = list(
+            api::get_user_anime_list(
+                "@me",
+                &list_query(Some(UserWatchStatus::Watching)),
+                &self.oauth,
+            )
+            .await,
+        );
+        let recent_updates =
+            list(api::get_user_anime_list("@me", &list_query(None), &self.oauth).await);
+
+        // the whole season, my titles are picked from it
+        let now = chrono::Utc::now();
+        let season = AnimeSeason {
+            year: now.year() as u64,
+            season: get_season(),
+        };
+        let seasonal_query = GetSeasonalAnimeQuery {
+            sort: None,
+            limit: 500,
+            offset: 0,
+            nsfw,
+            fields,
+        };
+        let airing = match api::get_seasonal_anime(&season, &seasonal_query, &self.oauth).await {
+            Ok(page) => home::airing_from_my_list(
+                page.data.into_iter().map(|node| node.node).collect(),
+                now,
+            ),
+            Err(e) => {
+                warn!("Could not get the airing titles of the season: {:?}", e);
+                vec![]
+            }
+        };
+
+        let mut app = self.app.lock().await;
+        app.home.watching = Some(watching);
+        app.home.recent_updates = Some(recent_updates);
+        app.home.airing = Some(airing.into_iter().take(limit as usize).collect());
+    }
+
+    async fn check_new_episodes(&mut self) {
+        self.oauth.refresh().unwrap();
+        let app_config = self.app.lock().await.app_config.clone();
+        match notifier::check_watching(&app_config, &self.oauth).await {
+            Ok(new_episodes) => {
+                if let Some(last) = new_episodes.last() {
+                    self.app.lock().await.status_message = Some(last.message());
+                }
+            }
+            // a background check doesn't take over the screen
+            Err(e) => warn!("{}", e),
+        }
+    }
+
+    async fn get_schedule(&mut self, source: ScheduleSource) {
+        self.oauth.refresh().unwrap();
+        let nsfw = self.app.lock().await.app_config.nsfw;
+        let result = match source {
+            ScheduleSource::MyList => {
+                airing::fetch_my_airing(
+                    &[UserWatchStatus::Watching, UserWatchStatus::PlanToWatch],
+                    nsfw,
+                    &self.oauth,
+                )
+                .await
+            }
+            ScheduleSource::CurrentSeason => {
+                let season = AnimeSeason {
+                    year: chrono::Utc::now().year() as u64,
+                    season: get_season(),
+                };
+                let query = GetSeasonalAnimeQuery {
+                    sort: None,
+                    limit: 500,
+                    offset: 0,
+                    nsfw,
+                    fields: Some(airing::AIRING_FIELDS.to_string()),
+                };
+                api::get_seasonal_anime(&season, &query, &self.oauth)
+                    .await
+                    .map(|page| page.data.into_iter().map(|node| node.node).collect())
+            }
+        };
+
+        let mut app = self.app.lock().await;
+        let anime = match result {
+            Ok(anime) => anime,
+            Err(e) => {
+                app.write_error(e);
+                app.active_display_block = ActiveDisplayBlock::Error;
+                return;
+            }
+        };
+        let now = chrono::Utc::now();
+        let schedule = Schedule::new(source, anime, now, &chrono::Local);
+        let title = match source {
+            ScheduleSource::MyList => "Airing Schedule: My List",
+            ScheduleSource::CurrentSeason => "Airing Schedule: This Season",
+        };
+        let route = Route {
+            data: Some(Data::Schedule(schedule.clone())),
+            block: ActiveDisplayBlock::Schedule,
+            title: title.to_string(),
+            image: None,
+        };
+        app.schedule = Some(schedule);
+        app.schedule_day = now
+            .with_timezone(&chrono::Local)
+            .weekday()
+            .num_days_from_monday() as usize;
+        app.reset_result_index();
+        app.active_block = ActiveBlock::DisplayBlock;
+        app.active_display_block = ActiveDisplayBlock::Schedule;
+        app.display_block_title = route.title.clone();
+        app.push_navigation_stack(route);
+    }
+
     async fn get_suggested(&mut self) {
         self.oauth.refresh().unwrap();
         let mut app = self.app.lock().await;
@@ -575,20 +900,33 @@ impl<'a> Network<'a> {
         app.display_block_title = title;
     }
 
-    async fn get_user_anime_list(&mut self, status: Option<UserWatchStatus>) {
+    async fn get_user_anime_list(&mut self, user: Option<String>, status: Option<UserWatchStatus>) {
         self.oauth.refresh().unwrap();
         let mut app = self.app.lock().await;
         let query = api::GetUserAnimeListQuery {
-            fields: Some(ALL_ANIME_AND_MANGA_FIELDS.to_string()),
+            fields: Some(format!("{},list_status", ALL_ANIME_AND_MANGA_FIELDS)),
             status: status.clone(),
             sort: Some(SortStyle::ListScore),
             limit: self.large_search_limit,
             offset: 0,
             nsfw: app.app_config.nsfw,
         };
-        match api::get_user_anime_list("@me".to_string(), &query, &self.oauth).await {
+        let user_name = user.clone().unwrap_or("@me".to_string());
+        let mut my_scores = HashMap::new();
+        match api::get_user_anime_list(user_name, &query, &self.oauth).await {
+            Ok(result) if user.is_some() => {
+                let mut list = result.clone().into_page();
+                // cards show the list owner's status, keep mine aside for comparing
+                for (node, entry) in list.data.iter_mut().zip(result.data.iter()) {
+                    if let Some(mine) = node.node.my_list_status.take() {
+                        my_scores.insert(node.node.id, mine.score);
+                    }
+                    node.node.my_list_status = entry.list_status.clone();
+                }
+                app.search_results.anime = Some(list);
+            }
             Ok(result) => {
-                app.search_results.anime = Some(result.clone());
+                app.search_results.anime = Some(result.into_page());
             }
             Err(e) => {
                 app.write_error(e);
@@ -600,33 +938,55 @@ impl<'a> Network<'a> {
         let data = UserAnimeList {
             anime_list: app.search_results.anime.as_ref().unwrap().clone(),
             status: status.clone(),
+            user: user.clone(),
+            my_scores: my_scores.clone(),
         };
+        // the route keeps the list as loaded, the view is applied on top
+        app.search_results.anime = Some(app.app_config.list_views.anime.apply(&data.anime_list));
         let route = Route {
             block: ActiveDisplayBlock::UserAnimeList,
             data: Some(Data::UserAnimeList(data)),
-            title: format!("My Anime List: {}", get_status_string(status)),
+            title: format!(
+                "{} Anime List: {}",
+                get_list_owner_string(&user),
+                get_status_string(status)
+            ),
             image: None,
         };
+        app.list_user = user;
+        app.list_my_scores = my_scores;
         app.active_block = ActiveBlock::DisplayBlock;
         app.active_display_block = ActiveDisplayBlock::UserAnimeList;
         app.display_block_title = route.title.clone();
         app.push_navigation_stack(route);
     }
 
-    async fn get_user_manga_list(&mut self, status: Option<UserReadStatus>) {
+    async fn get_user_manga_list(&mut self, user: Option<String>, status: Option<UserReadStatus>) {
         self.oauth.refresh().unwrap();
         let mut app = self.app.lock().await;
         let query = api::GetUserMangaListQuery {
-            fields: Some(ALL_ANIME_AND_MANGA_FIELDS.to_string()),
+            fields: Some(format!("{},list_status", ALL_ANIME_AND_MANGA_FIELDS)),
             status: status.clone(),
             sort: Some(SortStyle::ListScore),
             limit: self.large_search_limit,
             offset: 0,
             nsfw: app.app_config.nsfw,
         };
-        match api::get_user_manga_list("@me".to_string(), &query, &self.oauth).await {
+        let user_name = user.clone().unwrap_or("@me".to_string());
+        let mut my_scores = HashMap::new();
+        match api::get_user_manga_list(user_name, &query, &self.oauth).await {
+            Ok(result) if user.is_some() => {
+                let mut list = result.clone().into_page();
+                for (node, entry) in list.data.iter_mut().zip(result.data.iter()) {
+                    if let Some(mine) = node.node.my_list_status.take() {
+                        my_scores.insert(node.node.id, mine.score);
+                    }
+                    node.node.my_list_status = entry.list_status.clone();
+                }
+                app.search_results.manga = Some(list);
+            }
             Ok(result) => {
-                app.search_results.manga = Some(result.clone());
+                app.search_results.manga = Some(result.into_page());
             }
             Err(e) => {
                 app.write_error(e);
@@ -638,14 +998,23 @@ impl<'a> Network<'a> {
         let data = UserMangaList {
             manga_list: app.search_results.manga.as_ref().unwrap().clone(),
             status: status.clone(),
+            user: user.clone(),
+            my_scores: my_scores.clone(),
         };
+        app.search_results.manga = Some(app.app_config.list_views.manga.apply(&data.manga_list));
         let route = Route {
             block: ActiveDisplayBlock::UserMangaList,
             data: Some(Data::UserMangaList(data)),
-            title: format!("My Manga List: {}", get_manga_status_string(status)),
+            title: format!(
+                "{} Manga List: {}",
+                get_list_owner_string(&user),
+                get_manga_status_string(status)
+            ),
             image: None,
         };
 
+        app.list_user = user;
+        app.list_my_scores = my_scores;
         app.active_block = ActiveBlock::DisplayBlock;
         app.active_display_block = ActiveDisplayBlock::UserMangaList;
         app.display_block_title = route.title.clone();
@@ -717,11 +1086,14 @@ impl<'a> Network<'a> {
 
         match api::update_anime_list_status(anime_id, &query, &self.oauth).await {
             Ok(result) => {
-                app.anime_details.as_mut().unwrap().my_list_status = Some(result);
+                app.record_anime_change(anime_id, Some(query));
+                app.apply_anime_list_status(anime_id, result);
                 app.popup_post_req_success_message = Some("updated Successfully".to_string());
                 app.popup_post_req_success = true;
+                app.open_score_prompt(MediaKind::Anime, anime_id);
             }
             Err(e) => {
+                app.score_prompt = None;
                 app.write_error(e);
                 app.popup_post_req_success = false;
             }
@@ -734,18 +1106,179 @@ impl<'a> Network<'a> {
         let mut app = self.app.lock().await;
         match api::update_manga_list_status(manga_id, &query, &self.oauth).await {
             Ok(result) => {
-                //
-                app.manga_details.as_mut().unwrap().my_list_status = Some(result);
+                app.record_manga_change(manga_id, Some(query));
+                app.apply_manga_list_status(manga_id, result);
+                // recomputed the next time they're shown
+                app.manga_statistics = None;
                 app.popup_post_req_success = true;
                 app.popup_post_req_success_message = Some("updated Successfully".to_string());
+                app.open_score_prompt(MediaKind::Manga, manga_id);
             }
             Err(e) => {
+                app.score_prompt = None;
                 app.write_error(e);
                 app.popup_post_req_success = false;
             }
         }
         app.popup_is_loading = false;
     }
+
+    async fn patch_anime_list_status(
+        &mut self,
+        anime_id: u64,
+        query: UpdateUserAnimeListStatusQuery,
+    ) {
+        self.oauth.refresh().unwrap();
+        let result = api::update_anime_list_status(anime_id, &query, &self.oauth).await;
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(status) => {
+                app.status_message = Some(format!(
+                    "{} episodes watched, {}",
+                    status.num_episodes_watched,
+                    status.status.to_string().replace('_', " ")
+                ));
+                app.record_anime_change(anime_id, Some(query));
+                app.apply_anime_list_status(anime_id, status);
+                app.open_score_prompt(MediaKind::Anime, anime_id);
+            }
+            Err(e) => {
+                app.score_prompt = None;
+                warn!("Could not update the anime list: {:?}", e);
+                app.status_message = Some("Could not update the list".to_string());
+            }
+        }
+    }
+
+    async fn patch_manga_list_status(&mut self, manga_id: u64, query: UpdateUserMangaStatus) {
+        self.oauth.refresh().unwrap();
+        let result = api::update_manga_list_status(manga_id, &query, &self.oauth).await;
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(status) => {
+                app.status_message = Some(format!(
+                    "{} chapters read, {}",
+                    status.num_chapters_read,
+                    status.status.to_string().replace('_', " ")
+                ));
+                app.record_manga_change(manga_id, Some(query));
+                app.apply_manga_list_status(manga_id, status);
+                app.manga_statistics = None;
+                app.open_score_prompt(MediaKind::Manga, manga_id);
+            }
+            Err(e) => {
+                app.score_prompt = None;
+                warn!("Could not update the manga list: {:?}", e);
+                app.status_message = Some("Could not update the list".to_string());
+            }
+        }
+    }
+
+    /// the calls run on their own task, the other events go on meanwhile
+    fn run_bulk(&mut self, requests: Vec<(String, BulkRequest)>) {
+        let app = Arc::clone(self.app);
+        let mut oauth = self.oauth.clone();
+        tokio::spawn(async move {
+            let mut progress = BulkProgress {
+                total: requests.len(),
+                ..Default::default()
+            };
+            for (i, (title, request)) in requests.into_iter().enumerate() {
+                if i > 0 {
+                    tokio::time::sleep(Duration::from_millis(BULK_DELAY_MILLISECONDS)).await;
+                }
+                let result = match oauth.refresh_async().await {
+                    Ok(()) => send_list_request(&app, &oauth, request, true)
+                        .await
+                        .map_err(|e| format!("{:?}", e)),
+                    Err(e) => Err(e.to_string()),
+                };
+                if let Err(e) = &result {
+                    warn!("Bulk edit of {} failed: {}", title, e);
+                }
+                progress.record(title, result.is_ok());
+                let mut app = app.lock().await;
+                // the popup may have been closed while the calls go on
+                if let Some(BulkPopup::Running(shown)) = app.bulk.popup.as_mut() {
+                    *shown = progress.clone();
+                }
+            }
+
+            let mut app = app.lock().await;
+            app.bulk.marked.clear();
+            app.bulk_running = false;
+            app.status_message = Some(progress.summary());
+        });
+    }
+
+    async fn revert_list_change(&mut self, change: ListChange) {
+        self.oauth.refresh().unwrap();
+        let result = send_list_request(self.app, &self.oauth, change.revert(), false).await;
+        let mut app = self.app.lock().await;
+        match result {
+            Ok(()) => {
+                app.status_message = Some(format!("Undid the change of {}", change.title));
+            }
+            Err(e) => {
+                warn!("Could not undo the change of {}: {:?}", change.title, e);
+                app.status_message = Some(format!("Could not undo the change of {}", change.title));
+                app.list_changes.put_back(change);
+            }
+        }
+    }
+}
+
+/// sends one list call and shows its outcome, `record` keeps it to undo it later
+async fn send_list_request(
+    app: &Mutex<App>,
+    oauth: &OAuth,
+    request: BulkRequest,
+    record: bool,
+) -> Result<(), api::Error> {
+    match request {
+        BulkRequest::UpdateAnime(id, query) => {
+            let status = api::update_anime_list_status(id, &query, oauth).await?;
+            let mut app = app.lock().await;
+            if record {
+                app.record_anime_change(id, Some(query));
+            }
+            app.apply_anime_list_status(id, status);
+        }
+        BulkRequest::UpdateManga(id, query) => {
+            let status = api::update_manga_list_status(id, &query, oauth).await?;
+            let mut app = app.lock().await;
+            if record {
+                app.record_manga_change(id, Some(query));
+            }
+            app.apply_manga_list_status(id, status);
+            app.manga_statistics = None;
+        }
+        BulkRequest::DeleteAnime(id) => {
+            api::delete_anime_from_list(id, oauth).await?;
+            let mut app = app.lock().await;
+            if record {
+                app.record_anime_change(id, None);
+            }
+            app.remove_anime_from_list(id);
+        }
+        BulkRequest::DeleteManga(id) => {
+            api::delete_manga_from_list(id, oauth).await?;
+            let mut app = app.lock().await;
+            if record {
+                app.record_manga_change(id, None);
+            }
+            app.remove_manga_from_list(id);
+            app.manga_statistics = None;
+        }
+    }
+    Ok(())
+}
+
+fn get_list_owner_string(user: &Option<String>) -> String {
+    match user {
+        Some(user) => format!("{}'s", user),
+        None => "My".to_string(),
+    }
 }
 
 fn get_status_string(status: Option<UserWatchStatus>) -> String {
@@ -0,0 +1,266 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    iter,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    airing,
+    api::{
+        model::{Anime, AnimeStatus, UserWatchStatus},
+        TokenProvider,
+    },
+    config::{
+        app_config::{AppConfig, NotificationsConfig, NotifyMethod},
+        ConfigError,
+    },
+};
+
+/// An episode that should have aired since the last check
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewEpisode {
+    pub id: u64,
+    pub title: String,
+    // unknown without a start date
+    pub episode: Option<u64>,
+    pub aired_at: DateTime<Utc>,
+}
+
+impl NewEpisode {
+    pub fn message(&self) -> String {
+        match self.episode {
+            Some(episode) => format!("{} episode {} is out", self.title, episode),
+            None => format!("A new episode of {} is out", self.title),
+        }
+    }
+}
+
+/// Last notified broadcast of each title, in the cache dir
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NotifiedEpisodes {
+    // anime id to the unix timestamp of its last notified broadcast
+    pub last_aired: HashMap<u64, i64>,
+}
+
+impl NotifiedEpisodes {
+    /// no file yet is nothing notified
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| ConfigError::IOError(e.into()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        let content =
+            serde_json::to_string_pretty(self).map_err(|e| ConfigError::IOError(e.into()))?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// episodes aired since the last notified one, titles seen for the first time are only
+    /// remembered so the first check doesn't announce every airing title
+    pub fn check(&mut self, anime: &[Anime], now: DateTime<Utc>) -> Vec<NewEpisode> {
+        let mut new_episodes = vec![];
+        for anime in anime {
+            let (last, latest) = match anime.status {
+                // MAL marks a title finished once its last episode aired, it's forgotten after it
+                Some(AnimeStatus::FinishedAiring) => (
+                    self.last_aired.remove(&anime.id),
+                    airing::final_broadcast(anime),
+                ),
+                _ => {
+                    let Some(latest) = airing::next_episode_airing(anime, now)
+                        .map(|next| next - Duration::weeks(1))
+                    else {
+                        continue;
+                    };
+                    // not premiered yet
+                    if airing::premiere(anime).is_some_and(|first| latest < first) {
+                        continue;
+                    }
+                    (
+                        self.last_aired.insert(anime.id, latest.timestamp()),
+                        Some(latest),
+                    )
+                }
+            };
+            if let (Some(last), Some(latest)) = (last, latest) {
+                new_episodes.extend(missed_episodes(anime, last, latest));
+            }
+        }
+        new_episodes
+    }
+}
+
+/// weekly broadcasts after the `last` notified one up to `latest`, the oldest first
+fn missed_episodes(anime: &Anime, last: i64, latest: DateTime<Utc>) -> Vec<NewEpisode> {
+    let first = airing::premiere(anime);
+    let limit = anime.num_episodes.filter(|n| *n > 0).unwrap_or(u64::MAX);
+    let mut episodes: Vec<NewEpisode> =
+        iter::successors(Some(latest), |at| Some(*at - Duration::weeks(1)))
+            .take_while(|at| at.timestamp() > last && first.is_none_or(|first| *at >= first))
+            .take(limit as usize)
+            .map(|aired_at| NewEpisode {
+                id: anime.id,
+                title: anime.title.clone(),
+                episode: airing::episode_number(anime, aired_at),
+                aired_at,
+            })
+            .collect();
+    episodes.reverse();
+    episodes
+}
+
+/// sends the notification the config asks for, nothing is written to stdout but the bell
+pub fn notify(config: &NotificationsConfig, episode: &NewEpisode) -> io::Result<()> {
+    match config.method {
+        NotifyMethod::Desktop => {
+            Command::new("notify-send")
+                .arg("--app-name=mal-cli")
+                .arg("New episode")
+                .arg(episode.message())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()?;
+        }
+        NotifyMethod::Bell => {
+            let mut stdout = io::stdout();
+            stdout.write_all(b"\x07")?;
+            stdout.flush()?;
+        }
+        NotifyMethod::Command => {
+            Command::new("sh")
+                .arg("-c")
+                .arg(&config.command)
+                .env("MAL_ID", episode.id.to_string())
+                .env("MAL_TITLE", &episode.title)
+                .env(
+                    "MAL_EPISODE",
+                    episode.episode.map_or(String::new(), |e| e.to_string()),
+                )
+                .env("MAL_MESSAGE", episode.message())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()?;
+        }
+    }
+    Ok(())
+}
+
+/// checks the airing titles of the watching list, notifies their new episodes and remembers them
+pub async fn check_watching(
+    app_config: &AppConfig,
+    auth: &impl TokenProvider,
+) -> Result<Vec<NewEpisode>, String> {
+    // the finished titles too, their last episode may have aired since the last check
+    let anime = airing::fetch_my_anime(&[UserWatchStatus::Watching], app_config.nsfw, auth)
+        .await
+        .map_err(|e| format!("Could not get the watching list: {:?}", e))?;
+    let path = &app_config.paths.notified_file_path;
+    let mut notified = NotifiedEpisodes::load(path)
+        .map_err(|e| format!("Could not read the notified episodes: {}", e))?;
+    let new_episodes = notified.check(&anime, Utc::now());
+    for episode in &new_episodes {
+        if let Err(e) = notify(&app_config.notifications, episode) {
+            warn!("Could not send the notification: {}", e);
+        }
+    }
+    notified
+        .save(path)
+        .map_err(|e| format!("Could not save the notified episodes: {}", e))?;
+    Ok(new_episodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::model::{Broadcast, TimeWrapper};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_check_new_episodes() {
+        let mut anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": "anime",
+            "status": "currently_airing",
+            "start_date": "2025-01-03",
+            "num_episodes": 12,
+        }))
+        .unwrap();
+        anime.broadcast = Some(Broadcast {
+            day_of_the_week: "saturday".to_string(),
+            start_time: Some(TimeWrapper {
+                time: time::Time::from_hms(23, 30, 0).unwrap(),
+            }),
+        });
+        let anime = vec![anime];
+        let mut notified = NotifiedEpisodes::default();
+
+        // episode 2 aired on the 11th, the first check only remembers it
+        let now = Utc.with_ymd_and_hms(2025, 1, 12, 0, 0, 0).unwrap();
+        assert!(notified.check(&anime, now).is_empty());
+        assert!(notified.check(&anime, now).is_empty());
+
+        // episode 3 on the 18th
+        let now = Utc.with_ymd_and_hms(2025, 1, 18, 15, 0, 0).unwrap();
+        let new_episodes = notified.check(&anime, now);
+        assert_eq!(new_episodes.len(), 1);
+        assert_eq!(new_episodes[0].episode, Some(3));
+        assert_eq!(new_episodes[0].message(), "anime episode 3 is out");
+        assert_eq!(
+            notified.last_aired[&1],
+            Utc.with_ymd_and_hms(2025, 1, 18, 14, 30, 0)
+                .unwrap()
+                .timestamp()
+        );
+        assert!(notified.check(&anime, now).is_empty());
+    }
+
+    #[test]
+    fn test_check_missed_episodes() {
+        let mut anime: Anime = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": "anime",
+            "status": "currently_airing",
+            "start_date": "2025-01-03",
+            "num_episodes": 6,
+        }))
+        .unwrap();
+        anime.broadcast = Some(Broadcast {
+            day_of_the_week: "saturday".to_string(),
+            start_time: Some(TimeWrapper {
+                time: time::Time::from_hms(23, 30, 0).unwrap(),
+            }),
+        });
+        let mut notified = NotifiedEpisodes::default();
+        let now = Utc.with_ymd_and_hms(2025, 1, 12, 0, 0, 0).unwrap();
+        assert!(notified.check(&[anime.clone()], now).is_empty());
+
+        // off for three weeks, episodes 3 to 5 are announced in order
+        let now = Utc.with_ymd_and_hms(2025, 2, 2, 0, 0, 0).unwrap();
+        let episodes: Vec<_> = notified
+            .check(&[anime.clone()], now)
+            .iter()
+            .map(|e| e.episode)
+            .collect();
+        assert_eq!(episodes, vec![Some(3), Some(4), Some(5)]);
+
+        // the title is finished by the next check, its last episode is still announced
+        anime.status = Some(AnimeStatus::FinishedAiring);
+        let now = Utc.with_ymd_and_hms(2025, 2, 20, 0, 0, 0).unwrap();
+        let new_episodes = notified.check(&[anime.clone()], now);
+        assert_eq!(new_episodes.len(), 1);
+        assert_eq!(new_episodes[0].episode, Some(6));
+        assert!(notified.last_aired.is_empty());
+        assert!(notified.check(&[anime], now).is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    api::model::{Season, UserReadStatus, UserWatchStatus},
+    app::{ANIME_RANKING_TYPES, MANGA_RANKING_TYPES},
+    fuzzy,
+};
+
+/// State of the command palette, opened over every view
+#[derive(Debug, Default)]
+pub struct Palette {
+    pub open: bool,
+    pub query: String,
+    // index in the matching commands
+    pub selected: usize,
+}
+
+/// A destination or an action the palette can run
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Seasonal(Season, u16),
+    // index in ANIME_RANKING_TYPES
+    AnimeRanking(u8),
+    // index in MANGA_RANKING_TYPES
+    MangaRanking(u8),
+    Suggestions,
+    Profile,
+    StatsDashboard,
+    AnimeList(Option<UserWatchStatus>),
+    MangaList(Option<UserReadStatus>),
+    Bookmarks,
+    History,
+    ListChanges,
+    Schedule,
+    OpenAnime(u64),
+    OpenManga(u64),
+    Help,
+    ToggleLogger,
+    SwitchTitleLanguage,
+    ClearCache,
+    ExportList,
+}
+
+/// commands that take arguments, typed in the palette as is
+pub const COMMAND_USAGE: &str = "anime <id>  manga <id>  seasonal <season> <year>";
+
+const SEASONS: [Season; 4] = [Season::Winter, Season::Spring, Season::Summer, Season::Fall];
+
+impl Command {
+    pub fn label(&self) -> String {
+        match self {
+            Command::Seasonal(season, year) => {
+                format!("Seasonal: {} {}", title_case(&season.to_string()), year)
+            }
+            Command::AnimeRanking(i) => {
+                format!("Anime ranking: {}", ANIME_RANKING_TYPES[*i as usize])
+            }
+            Command::MangaRanking(i) => {
+                format!("Manga ranking: {}", MANGA_RANKING_TYPES[*i as usize])
+            }
+            Command::Suggestions => "Suggested anime".to_string(),
+            Command::Profile => "Profile".to_string(),
+            Command::StatsDashboard => "Statistics dashboard".to_string(),
+            Command::AnimeList(status) => format!(
+                "Anime list: {}",
+                status
+                    .as_ref()
+                    .map_or("All".to_string(), |s| title_case(&s.to_string()))
+            ),
+            Command::MangaList(status) => format!(
+                "Manga list: {}",
+                status
+                    .as_ref()
+                    .map_or("All".to_string(), |s| title_case(&s.to_string()))
+            ),
+            Command::Bookmarks => "Bookmarks".to_string(),
+            Command::History => "Viewed history".to_string(),
+            Command::ListChanges => "List changes (undo)".to_string(),
+            Command::Schedule => "Airing schedule".to_string(),
+            Command::OpenAnime(id) => format!("Open anime #{}", id),
+            Command::OpenManga(id) => format!("Open manga #{}", id),
+            Command::Help => "Help".to_string(),
+            Command::ToggleLogger => "Toggle logger".to_string(),
+            Command::SwitchTitleLanguage => "Switch title language".to_string(),
+            Command::ClearCache => "Clear image cache".to_string(),
+            Command::ExportList => "Export the current list".to_string(),
+        }
+    }
+
+    /// commands reading or changing the user's lists
+    fn needs_login(&self) -> bool {
+        matches!(
+            self,
+            Command::Suggestions
+                | Command::Profile
+                | Command::StatsDashboard
+                | Command::AnimeList(_)
+                | Command::MangaList(_)
+                | Command::ListChanges
+        )
+    }
+}
+
+/// every command without arguments, the seasons are the ones of `year`
+fn all_commands(year: u16) -> Vec<Command> {
+    let mut commands: Vec<Command> = SEASONS
+        .iter()
+        .map(|season| Command::Seasonal(season.clone(), year))
+        .collect();
+    commands.extend((0..ANIME_RANKING_TYPES.len() as u8).map(Command::AnimeRanking));
+    commands.extend((0..MANGA_RANKING_TYPES.len() as u8).map(Command::MangaRanking));
+    commands.extend([
+        Command::Suggestions,
+        Command::Profile,
+        Command::StatsDashboard,
+    ]);
+    commands.push(Command::AnimeList(None));
+    commands.extend(
+        UserWatchStatus::iter()
+            .filter(|s| !matches!(s, UserWatchStatus::Other(_)))
+            .map(|s| Command::AnimeList(Some(s))),
+    );
+    commands.push(Command::MangaList(None));
+    commands.extend(
+        UserReadStatus::iter()
+            .filter(|s| !matches!(s, UserReadStatus::Other(_)))
+            .map(|s| Command::MangaList(Some(s))),
+    );
+    commands.extend([
+        Command::Bookmarks,
+        Command::History,
+        Command::ListChanges,
+        Command::Schedule,
+        Command::Help,
+        Command::ToggleLogger,
+        Command::SwitchTitleLanguage,
+        Command::ClearCache,
+        Command::ExportList,
+    ]);
+    commands
+}
+
+/// the command spelled out with its arguments, like "seasonal fall 2006"
+fn parse_command(query: &str) -> Option<Command> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    match words.as_slice() {
+        [name, id] if name == "anime" => id.parse().ok().map(Command::OpenAnime),
+        [name, id] if name == "manga" => id.parse().ok().map(Command::OpenManga),
+        [name, season, year] if name == "seasonal" => {
+            let season = SEASONS.iter().find(|s| s.to_string() == *season)?;
+            Some(Command::Seasonal(season.clone(), year.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// commands matching `query`, best matches first
+pub fn matching_commands(query: &str, year: u16, is_anonymous: bool) -> Vec<Command> {
+    let mut matches: Vec<(i64, Command)> = all_commands(year)
+        .into_iter()
+        .filter(|command| !(is_anonymous && command.needs_login()))
+        .filter_map(|command| fuzzy::fuzzy_score(query, &command.label()).map(|s| (s, command)))
+        .collect();
+    // stable, ties keep the listing order
+    matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    parse_command(query)
+        .into_iter()
+        .chain(matches.into_iter().map(|(_, command)| command))
+        .collect()
+}
+
+fn title_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or(String::new(), |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_with_arguments() {
+        assert_eq!(parse_command("anime 5114"), Some(Command::OpenAnime(5114)));
+        assert_eq!(
+            parse_command("Seasonal Fall 2006"),
+            Some(Command::Seasonal(Season::Fall, 2006))
+        );
+        assert_eq!(parse_command("seasonal autumn 2006"), None);
+        assert_eq!(parse_command("manga one"), None);
+
+        let commands = matching_commands("anime 1", 2025, true);
+        assert_eq!(commands[0], Command::OpenAnime(1));
+    }
+
+    #[test]
+    fn test_matching_commands() {
+        let commands = matching_commands("ptw", 2025, false);
+        assert_eq!(
+            commands[0],
+            Command::AnimeList(Some(UserWatchStatus::PlanToWatch))
+        );
+        assert!(!matching_commands("", 2025, true).contains(&Command::Profile));
+        assert_eq!(
+            Command::Seasonal(Season::Winter, 2025).label(),
+            "Seasonal: Winter 2025"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use chrono::Datelike;
+use time::{Date, Month};
+
+use crate::{
+    api::{
+        model::{Anime, DateWrapper, Manga, UserReadStatus, UserWatchStatus},
+        UpdateUserAnimeListStatusQuery, UpdateUserMangaStatus,
+    },
+    config::app_config::ListUpdatePolicy,
+};
+
+/// local date of today, the list dates have no time
+pub fn today() -> Date {
+    let now = chrono::Local::now().date_naive();
+    Month::try_from(now.month() as u8)
+        .ok()
+        .and_then(|month| Date::from_calendar_date(now.year(), month, now.day() as u8).ok())
+        .unwrap_or(Date::MIN)
+}
+
+/// one more episode watched
+pub fn next_episode(
+    policy: &ListUpdatePolicy,
+    anime: &Anime,
+    today: Date,
+) -> Option<(UpdateUserAnimeListStatusQuery, bool)> {
+    let watched = anime
+        .my_list_status
+        .as_ref()
+        .map_or(0, |s| s.num_episodes_watched);
+    if anime
+        .num_episodes
+        .is_some_and(|total| total > 0 && watched >= total)
+    {
+        return None;
+    }
+    let mut query = UpdateUserAnimeListStatusQuery {
+        num_watched_episodes: Some(watched + 1),
+        ..Default::default()
+    };
+    let ask_score = apply_anime_policy(policy, anime, &mut query, today);
+    Some((query, ask_score))
+}
+
+/// one more chapter read
+pub fn next_chapter(
+    policy: &ListUpdatePolicy,
+    manga: &Manga,
+    today: Date,
+) -> Option<(UpdateUserMangaStatus, bool)> {
+    let read = manga
+        .my_list_status
+        .as_ref()
+        .map_or(0, |s| s.num_chapters_read);
+    if manga
+        .num_chapters
+        .is_some_and(|total| total > 0 && read >= total)
+    {
+        return None;
+    }
+    let mut query = UpdateUserMangaStatus {
+        num_chapters_read: Some(read + 1),
+        ..Default::default()
+    };
+    let ask_score = apply_manga_policy(policy, manga, &mut query, today);
+    Some((query, ask_score))
+}
+
+/// fills the status and dates the update implies, true when the score should be asked for
+pub fn apply_anime_policy(
+    policy: &ListUpdatePolicy,
+    anime: &Anime,
+    query: &mut UpdateUserAnimeListStatusQuery,
+    today: Date,
+) -> bool {
+    let entry = anime.my_list_status.as_ref().map(|s| Entry {
+        status: s.status.clone(),
+        progress: s.num_episodes_watched,
+        has_start_date: s.start_date.is_some(),
+        has_finish_date: s.finish_date.is_some(),
+        score: s.score,
+    });
+    let statuses = [
+        UserWatchStatus::PlanToWatch,
+        UserWatchStatus::Watching,
+        UserWatchStatus::Completed,
+    ];
+    let change = transition(
+        policy,
+        entry,
+        Update {
+            status: query.status.clone(),
+            progress: query.num_watched_episodes,
+            score: query.score,
+        },
+        anime.num_episodes,
+        statuses,
+    );
+    query.status = change.status.or(query.status.take());
+    query.num_watched_episodes = change.progress.or(query.num_watched_episodes);
+    if change.start_date {
+        query.start_date = Some(DateWrapper { date: today });
+    }
+    if change.finish_date {
+        query.finish_date = Some(DateWrapper { date: today });
+    }
+    change.ask_score
+}
+
+pub fn apply_manga_policy(
+    policy: &ListUpdatePolicy,
+    manga: &Manga,
+    query: &mut UpdateUserMangaStatus,
+    today: Date,
+) -> bool {
+    let entry = manga.my_list_status.as_ref().map(|s| Entry {
+        status: s.status.clone(),
+        progress: s.num_chapters_read,
+        has_start_date: s.start_date.is_some(),
+        has_finish_date: s.finish_date.is_some(),
+        score: s.score,
+    });
+    let statuses = [
+        UserReadStatus::PlanToRead,
+        UserReadStatus::Reading,
+        UserReadStatus::Completed,
+    ];
+    let change = transition(
+        policy,
+        entry,
+        Update {
+            status: query.status.clone(),
+            progress: query.num_chapters_read,
+            score: query.score,
+        },
+        manga.num_chapters,
+        statuses,
+    );
+    query.status = change.status.or(query.status.take());
+    query.num_chapters_read = change.progress.or(query.num_chapters_read);
+    if change.start_date {
+        query.start_date = Some(DateWrapper { date: today });
+    }
+    if change.finish_date {
+        query.finish_date = Some(DateWrapper { date: today });
+    }
+    change.ask_score
+}
+
+/// my list entry before the update
+struct Entry<S> {
+    status: S,
+    progress: u64,
+    has_start_date: bool,
+    has_finish_date: bool,
+    score: u8,
+}
+
+/// what the update sets, None is unchanged
+struct Update<S> {
+    status: Option<S>,
+    progress: Option<u64>,
+    score: Option<u8>,
+}
+
+/// what the policy adds to the update
+struct Change<S> {
+    status: Option<S>,
+    progress: Option<u64>,
+    start_date: bool,
+    finish_date: bool,
+    ask_score: bool,
+}
+
+/// same rules for anime and manga, `statuses` are the planned, in progress and completed ones
+fn transition<S: Clone + PartialEq>(
+    policy: &ListUpdatePolicy,
+    entry: Option<Entry<S>>,
+    update: Update<S>,
+    total: Option<u64>,
+    statuses: [S; 3],
+) -> Change<S> {
+    let [planned, in_progress, completed] = statuses;
+    let old_status = entry.as_ref().map(|e| e.status.clone());
+    let old_progress = entry.as_ref().map_or(0, |e| e.progress);
+    let total = total.filter(|n| *n > 0);
+    let mut status = update.status.clone().or(old_status.clone());
+    let mut progress = None;
+
+    if policy.auto_status {
+        let new_progress = update.progress.unwrap_or(old_progress);
+        if new_progress > old_progress {
+            if total == Some(new_progress) {
+                status = Some(completed.clone());
+            } else if status.is_none() || status.as_ref() == Some(&planned) {
+                status = Some(in_progress.clone());
+            }
+        }
+        // completed from the status popup, the progress follows
+        if status.as_ref() == Some(&completed) && old_status.as_ref() != Some(&completed) {
+            progress = total.filter(|total| *total != new_progress);
+        }
+    }
+
+    let started =
+        status.as_ref() == Some(&in_progress) && old_status.as_ref() != Some(&in_progress);
+    let is_completed =
+        status.as_ref() == Some(&completed) && old_status.as_ref() != Some(&completed);
+    let (has_start_date, has_finish_date, score) = entry.as_ref().map_or((false, false, 0), |e| {
+        (e.has_start_date, e.has_finish_date, e.score)
+    });
+    Change {
+        // only what the update doesn't already say
+        status: status
+            .filter(|s| Some(s) != update.status.as_ref() && Some(s) != old_status.as_ref()),
+        progress,
+        start_date: policy.auto_dates && (started || is_completed) && !has_start_date,
+        finish_date: policy.auto_dates && is_completed && !has_finish_date,
+        ask_score: policy.ask_score_on_completion
+            && is_completed
+            && update.score.unwrap_or(score) == 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anime(num_episodes: u64, status: Option<(&str, u64)>) -> Anime {
+        let mut value = serde_json::json!({
+            "id": 1,
+            "title": "anime",
+            "num_episodes": num_episodes,
+        });
+        if let Some((status, watched)) = status {
+            value["my_list_status"] = serde_json::json!({
+                "status": status,
+                "score": 0,
+                "num_episodes_watched": watched,
+                "is_rewatching": false,
+                "start_date": "2025-01-01",
+                "updated_at": "2025-01-01T00:00:00+00:00",
+            });
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_next_episode() {
+        let policy = ListUpdatePolicy::default();
+        let today = Date::from_calendar_date(2025, Month::March, 2).unwrap();
+
+        // first episode of a title not in the list yet
+        let (query, _) = next_episode(&policy, &anime(12, None), today).unwrap();
+        assert_eq!(query.num_watched_episodes, Some(1));
+        assert_eq!(query.status, Some(UserWatchStatus::Watching));
+        assert_eq!(query.start_date.map(|d| d.date), Some(today));
+        assert!(query.finish_date.is_none());
+
+        // only the count changes in between
+        let (query, ask_score) =
+            next_episode(&policy, &anime(12, Some(("watching", 5))), today).unwrap();
+        assert_eq!(query.num_watched_episodes, Some(6));
+        assert!(query.status.is_none() && query.start_date.is_none());
+        assert!(!ask_score);
+
+        // the last one completes it, keeping the start date
+        let (query, ask_score) =
+            next_episode(&policy, &anime(12, Some(("watching", 11))), today).unwrap();
+        assert_eq!(query.status, Some(UserWatchStatus::Completed));
+        assert_eq!(query.finish_date.map(|d| d.date), Some(today));
+        assert!(query.start_date.is_none());
+        assert!(ask_score);
+
+        assert!(next_episode(&policy, &anime(12, Some(("completed", 12))), today).is_none());
+        // unknown episode count, never completed
+        let (query, _) = next_episode(&policy, &anime(0, Some(("watching", 30))), today).unwrap();
+        assert_eq!(query.num_watched_episodes, Some(31));
+        assert!(query.status.is_none());
+    }
+
+    #[test]
+    fn test_policy_on_popup_updates() {
+        let today = Date::from_calendar_date(2025, Month::March, 2).unwrap();
+        let anime = anime(12, Some(("plan_to_watch", 0)));
+
+        // completed from the status popup, the episodes follow
+        let mut query = UpdateUserAnimeListStatusQuery {
+            status: Some(UserWatchStatus::Completed),
+            num_watched_episodes: Some(0),
+            ..Default::default()
+        };
+        let policy = ListUpdatePolicy::default();
+        assert!(apply_anime_policy(&policy, &anime, &mut query, today));
+        assert_eq!(query.num_watched_episodes, Some(12));
+        assert_eq!(query.finish_date.map(|d| d.date), Some(today));
+
+        // nothing added when turned off
+        let policy = ListUpdatePolicy {
+            auto_status: false,
+            auto_dates: false,
+            ask_score_on_completion: false,
+        };
+        let mut query = UpdateUserAnimeListStatusQuery {
+            num_watched_episodes: Some(3),
+            ..Default::default()
+        };
+        assert!(!apply_anime_policy(&policy, &anime, &mut query, today));
+        assert!(query.status.is_none() && query.start_date.is_none());
+    }
+}
@@ -0,0 +1,97 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    app::{App, Navigator},
+    config::ConfigError,
+};
+
+/// Navigation of the last run, saved in the cache dir on exit
+#[derive(Deserialize, Serialize)]
+struct Session {
+    // unix timestamp, in seconds
+    saved_at: i64,
+    // the routes of a logged in user aren't restored anonymously and the other way around
+    anonymous: bool,
+    navigator: Navigator,
+}
+
+pub fn save(app: &App) -> Result<(), ConfigError> {
+    let session = Session {
+        saved_at: chrono::Utc::now().timestamp(),
+        anonymous: app.is_anonymous,
+        navigator: app.navigator.clone(),
+    };
+    let content = serde_json::to_string(&session).map_err(|e| ConfigError::IOError(e.into()))?;
+    fs::write(&app.app_config.paths.session_file_path, content)?;
+    Ok(())
+}
+
+/// the saved navigator, None when there is none or it is stale
+pub fn load(path: &Path, max_age_hours: u32, anonymous: bool) -> Option<Navigator> {
+    let content = fs::read_to_string(path).ok()?;
+    let session: Session = match serde_json::from_str(&content) {
+        Ok(session) => session,
+        Err(e) => {
+            // an older version may have saved it
+            warn!("Could not read the saved session: {}", e);
+            return None;
+        }
+    };
+    restorable(
+        session,
+        chrono::Utc::now().timestamp(),
+        max_age_hours,
+        anonymous,
+    )
+}
+
+fn restorable(
+    session: Session,
+    now: i64,
+    max_age_hours: u32,
+    anonymous: bool,
+) -> Option<Navigator> {
+    let age = now - session.saved_at;
+    if age > max_age_hours as i64 * 3600 || session.anonymous != anonymous {
+        return None;
+    }
+    Some(session.navigator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{ActiveDisplayBlock, Route};
+
+    #[test]
+    fn test_session_round_trip_and_staleness() {
+        let mut navigator = Navigator::new();
+        navigator.add_route(Route {
+            data: None,
+            block: ActiveDisplayBlock::Seasonal,
+            title: "Seasonal Anime: fall 2006".to_string(),
+            image: None,
+        });
+        let session = Session {
+            saved_at: 0,
+            anonymous: false,
+            navigator,
+        };
+        let session: Session =
+            serde_json::from_str(&serde_json::to_string(&session).unwrap()).unwrap();
+
+        let navigator = restorable(session, 3600, 24, false).unwrap();
+        assert_eq!(navigator.history, vec![0, 1]);
+        assert_eq!(navigator.data[&1].block, ActiveDisplayBlock::Seasonal);
+
+        let stale = Session {
+            saved_at: 0,
+            anonymous: false,
+            navigator: Navigator::new(),
+        };
+        assert!(restorable(stale, 25 * 3600, 24, false).is_none());
+    }
+}
@@ -0,0 +1,425 @@
+use crate::api::{
+    self,
+    model::{
+        Anime, ListNode, Manga, Season, UserAnimeListStatus, UserMangaListStatus, UserReadStatus,
+    },
+    GetUserAnimeListQuery, GetUserMangaListQuery, TokenProvider,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// MAL caps user list pages at 1000 entries
+const LIST_PAGE_LIMIT: u64 = 1000;
+const MANGA_STATS_FIELDS: &str = "list_status,num_chapters,num_volumes";
+const DASHBOARD_FIELDS: &str =
+    "list_status,mean,genres,studios,start_season,media_type,num_episodes,average_episode_duration";
+// MAL doesn't track reading time, this is only an estimate
+pub const MINUTES_PER_CHAPTER: f64 = 5.0;
+pub const MAX_DEVIATIONS: usize = 20;
+
+/// Fetches the whole anime list of `user`, page by page
+pub async fn fetch_full_anime_list(
+    user: &str,
+    fields: &str,
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<Vec<ListNode<Anime, UserAnimeListStatus>>, api::Error> {
+    let mut entries = vec![];
+    let mut offset = 0;
+    loop {
+        let query = GetUserAnimeListQuery {
+            fields: Some(fields.to_string()),
+            status: None,
+            sort: None,
+            limit: LIST_PAGE_LIMIT,
+            offset,
+            nsfw,
+        };
+        let page = api::get_user_anime_list(user, &query, auth).await?;
+        let has_next = page.paging.next.is_some();
+        entries.extend(page.data);
+        if !has_next {
+            return Ok(entries);
+        }
+        offset += LIST_PAGE_LIMIT;
+    }
+}
+
+/// Fetches the whole manga list of `user`, page by page
+pub async fn fetch_full_manga_list(
+    user: &str,
+    fields: &str,
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<Vec<ListNode<Manga, UserMangaListStatus>>, api::Error> {
+    let mut entries = vec![];
+    let mut offset = 0;
+    loop {
+        let query = GetUserMangaListQuery {
+            fields: Some(fields.to_string()),
+            status: None,
+            sort: None,
+            limit: LIST_PAGE_LIMIT,
+            offset,
+            nsfw,
+        };
+        let page = api::get_user_manga_list(user, &query, auth).await?;
+        let has_next = page.paging.next.is_some();
+        entries.extend(page.data);
+        if !has_next {
+            return Ok(entries);
+        }
+        offset += LIST_PAGE_LIMIT;
+    }
+}
+
+/// Manga counterpart of `AnimeStatistics`, which the API doesn't provide
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct MangaStatistics {
+    pub num_items_reading: u64,
+    pub num_items_completed: u64,
+    pub num_items_on_hold: u64,
+    pub num_items_dropped: u64,
+    pub num_items_plan_to_read: u64,
+    pub num_items: u64,
+
+    pub num_chapters: u64,
+    pub num_volumes: u64,
+    pub num_times_reread: u64,
+    // 0.0 when nothing is scored
+    pub mean_score: f64,
+    // estimated from MINUTES_PER_CHAPTER
+    pub num_days: f64,
+}
+
+impl MangaStatistics {
+    pub fn from_list(list: &[ListNode<Manga, UserMangaListStatus>]) -> Self {
+        let mut stats = Self::default();
+        let mut score_sum = 0u64;
+        let mut scored = 0u64;
+
+        for status in list.iter().filter_map(|e| e.list_status.as_ref()) {
+            stats.num_items += 1;
+            match status.status {
+                UserReadStatus::Reading => stats.num_items_reading += 1,
+                UserReadStatus::Completed => stats.num_items_completed += 1,
+                UserReadStatus::OnHold => stats.num_items_on_hold += 1,
+                UserReadStatus::Dropped => stats.num_items_dropped += 1,
+                UserReadStatus::PlanToRead => stats.num_items_plan_to_read += 1,
+                UserReadStatus::Other(_) => {}
+            }
+            stats.num_chapters += status.num_chapters_read;
+            stats.num_volumes += status.num_volumes_read;
+            stats.num_times_reread += status.num_times_reread.unwrap_or(0) as u64;
+            if status.score != 0 {
+                score_sum += status.score as u64;
+                scored += 1;
+            }
+        }
+
+        if scored != 0 {
+            stats.mean_score = score_sum as f64 / scored as f64;
+        }
+        stats.num_days = stats.num_chapters as f64 * MINUTES_PER_CHAPTER / (60.0 * 24.0);
+        stats
+    }
+}
+
+/// Computes the logged in user's manga statistics from their whole list
+pub async fn fetch_manga_statistics(
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<MangaStatistics, api::Error> {
+    let list = fetch_full_manga_list("@me", MANGA_STATS_FIELDS, nsfw, auth).await?;
+    Ok(MangaStatistics::from_list(&list))
+}
+
+/// Number of titles and mean score of a genre, studio or media type
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CategoryStatistics {
+    pub name: String,
+    pub count: u64,
+    // None when nothing is scored
+    pub mean_score: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SeasonStatistics {
+    pub year: u16,
+    pub season: String,
+    pub count: u64,
+    // episodes watched (rewatches included) times their average duration
+    pub minutes_watched: u64,
+}
+
+/// My score of a title against its MAL mean
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ScoreDeviation {
+    pub id: u64,
+    pub title: String,
+    pub score: u8,
+    pub mean: f64,
+}
+
+impl ScoreDeviation {
+    pub fn deviation(&self) -> f64 {
+        self.score as f64 - self.mean
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AnimeDashboard {
+    pub num_items: u64,
+    // titles per score, index 0 is a score of 1
+    pub score_histogram: [u64; 10],
+    // most common first
+    pub genres: Vec<CategoryStatistics>,
+    pub studios: Vec<CategoryStatistics>,
+    pub media_types: Vec<CategoryStatistics>,
+    // newest first
+    pub seasons: Vec<SeasonStatistics>,
+    // average of my score - MAL mean over scored titles
+    pub mean_deviation: Option<f64>,
+    // biggest deviations first
+    pub deviations: Vec<ScoreDeviation>,
+}
+
+#[derive(Default)]
+struct CategoryAccumulator {
+    count: u64,
+    score_sum: u64,
+    scored: u64,
+}
+
+impl CategoryAccumulator {
+    fn add(&mut self, score: u8) {
+        self.count += 1;
+        if score != 0 {
+            self.score_sum += score as u64;
+            self.scored += 1;
+        }
+    }
+}
+
+fn into_categories(map: HashMap<String, CategoryAccumulator>) -> Vec<CategoryStatistics> {
+    let mut categories: Vec<CategoryStatistics> = map
+        .into_iter()
+        .map(|(name, acc)| CategoryStatistics {
+            name,
+            count: acc.count,
+            mean_score: (acc.scored != 0).then(|| acc.score_sum as f64 / acc.scored as f64),
+        })
+        .collect();
+    // ties by name to keep the order stable
+    categories.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    categories
+}
+
+fn season_order(season: &Season) -> u8 {
+    match season {
+        Season::Winter => 0,
+        Season::Spring => 1,
+        Season::Summer => 2,
+        Season::Fall => 3,
+        Season::Other(_) => 4,
+    }
+}
+
+impl AnimeDashboard {
+    pub fn from_list(list: &[ListNode<Anime, UserAnimeListStatus>]) -> Self {
+        let mut score_histogram = [0; 10];
+        let mut genres: HashMap<String, CategoryAccumulator> = HashMap::new();
+        let mut studios: HashMap<String, CategoryAccumulator> = HashMap::new();
+        let mut media_types: HashMap<String, CategoryAccumulator> = HashMap::new();
+        let mut seasons: HashMap<(u16, u8), SeasonStatistics> = HashMap::new();
+        let mut deviations = vec![];
+        let mut num_items = 0;
+
+        for entry in list {
+            let Some(status) = entry.list_status.as_ref() else {
+                continue;
+            };
+            let anime = &entry.node;
+            num_items += 1;
+
+            if (1..=10).contains(&status.score) {
+                score_histogram[status.score as usize - 1] += 1;
+            }
+            for genre in anime.genres.iter().flatten() {
+                genres
+                    .entry(genre.name.clone())
+                    .or_default()
+                    .add(status.score);
+            }
+            for studio in anime.studios.iter().flatten() {
+                studios
+                    .entry(studio.name.clone())
+                    .or_default()
+                    .add(status.score);
+            }
+            let media_type: &str = anime
+                .media_type
+                .as_ref()
+                .map_or("unknown", |media_type| media_type.clone().into());
+            media_types
+                .entry(media_type.to_string())
+                .or_default()
+                .add(status.score);
+
+            if let Some(start_season) = anime.start_season.as_ref() {
+                let episodes = status.num_episodes_watched
+                    + status.num_times_rewatched.unwrap_or(0) * anime.num_episodes.unwrap_or(0);
+                let minutes = episodes * anime.average_episode_duration.unwrap_or(0) / 60;
+                let season = seasons
+                    .entry((start_season.year, season_order(&start_season.season)))
+                    .or_insert_with(|| SeasonStatistics {
+                        year: start_season.year,
+                        season: start_season.season.to_string(),
+                        count: 0,
+                        minutes_watched: 0,
+                    });
+                season.count += 1;
+                season.minutes_watched += minutes;
+            }
+
+            if let (Some(mean), true) = (anime.mean, status.score != 0) {
+                deviations.push(ScoreDeviation {
+                    id: anime.id,
+                    title: anime.title.clone(),
+                    score: status.score,
+                    mean,
+                });
+            }
+        }
+
+        let mean_deviation = (!deviations.is_empty()).then(|| {
+            deviations.iter().map(|d| d.deviation()).sum::<f64>() / deviations.len() as f64
+        });
+        deviations.sort_by(|a, b| b.deviation().abs().total_cmp(&a.deviation().abs()));
+        deviations.truncate(MAX_DEVIATIONS);
+
+        let mut seasons: Vec<((u16, u8), SeasonStatistics)> = seasons.into_iter().collect();
+        seasons.sort_by_key(|(key, _)| std::cmp::Reverse(*key));
+
+        Self {
+            num_items,
+            score_histogram,
+            genres: into_categories(genres),
+            studios: into_categories(studios),
+            media_types: into_categories(media_types),
+            seasons: seasons.into_iter().map(|(_, s)| s).collect(),
+            mean_deviation,
+            deviations,
+        }
+    }
+}
+
+/// Builds the statistics dashboard from the logged in user's whole anime list
+pub async fn fetch_anime_dashboard(
+    nsfw: bool,
+    auth: &impl TokenProvider,
+) -> Result<AnimeDashboard, api::Error> {
+    let list = fetch_full_anime_list("@me", DASHBOARD_FIELDS, nsfw, auth).await?;
+    Ok(AnimeDashboard::from_list(&list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(status: &str, score: u8, chapters: u64, volumes: u64) -> serde_json::Value {
+        serde_json::json!({
+            "node": { "id": 1, "title": "a" },
+            "list_status": {
+                "status": status,
+                "score": score,
+                "num_volumes_read": volumes,
+                "num_chapters_read": chapters,
+                "is_rereading": false,
+                "updated_at": "2024-01-01T00:00:00+00:00"
+            }
+        })
+    }
+
+    #[test]
+    fn test_manga_statistics() {
+        let list: Vec<ListNode<Manga, UserMangaListStatus>> =
+            serde_json::from_value(serde_json::json!([
+                entry("reading", 8, 100, 10),
+                entry("completed", 6, 188, 20),
+                entry("completed", 0, 0, 0),
+                entry("plan_to_read", 0, 0, 0),
+            ]))
+            .unwrap();
+        let stats = MangaStatistics::from_list(&list);
+
+        assert_eq!(stats.num_items, 4);
+        assert_eq!(stats.num_items_reading, 1);
+        assert_eq!(stats.num_items_completed, 2);
+        assert_eq!(stats.num_items_plan_to_read, 1);
+        assert_eq!(stats.num_chapters, 288);
+        assert_eq!(stats.num_volumes, 30);
+        // unscored entries don't count
+        assert_eq!(stats.mean_score, 7.0);
+        assert_eq!(stats.num_days, 1.0);
+    }
+
+    fn anime(
+        id: u64,
+        score: u8,
+        mean: f64,
+        genres: &[&str],
+        season: (&str, u16),
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "node": {
+                "id": id,
+                "title": format!("anime {}", id),
+                "mean": mean,
+                "genres": genres
+                    .iter()
+                    .enumerate()
+                    .map(|(i, g)| serde_json::json!({ "id": i, "name": g }))
+                    .collect::<Vec<_>>(),
+                "media_type": "tv",
+                "num_episodes": 12,
+                "average_episode_duration": 1440,
+                "start_season": { "season": season.0, "year": season.1 }
+            },
+            "list_status": {
+                "status": "completed",
+                "score": score,
+                "num_episodes_watched": 12,
+                "is_rewatching": false,
+                "updated_at": "2024-01-01T00:00:00+00:00"
+            }
+        })
+    }
+
+    #[test]
+    fn test_anime_dashboard() {
+        let list: Vec<ListNode<Anime, UserAnimeListStatus>> =
+            serde_json::from_value(serde_json::json!([
+                anime(1, 9, 8.0, &["Action", "Drama"], ("winter", 2023)),
+                anime(2, 5, 7.0, &["Action"], ("fall", 2023)),
+                anime(3, 0, 6.0, &["Comedy"], ("fall", 2023)),
+            ]))
+            .unwrap();
+        let dashboard = AnimeDashboard::from_list(&list);
+
+        assert_eq!(dashboard.num_items, 3);
+        assert_eq!(dashboard.score_histogram[8], 1);
+        assert_eq!(dashboard.score_histogram[4], 1);
+        assert_eq!(dashboard.genres[0].name, "Action");
+        assert_eq!(dashboard.genres[0].count, 2);
+        assert_eq!(dashboard.genres[0].mean_score, Some(7.0));
+        assert_eq!(dashboard.media_types[0].name, "tv");
+        // newest season first
+        assert_eq!(dashboard.seasons[0].season, "fall");
+        assert_eq!(dashboard.seasons[0].count, 2);
+        assert_eq!(dashboard.seasons[0].minutes_watched, 2 * 12 * 24);
+        // (+1 - 2) / 2, unscored titles are left out
+        assert_eq!(dashboard.mean_deviation, Some(-0.5));
+        assert_eq!(dashboard.deviations[0].id, 2);
+    }
+}
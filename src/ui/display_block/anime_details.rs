@@ -91,7 +91,7 @@ fn draw_synopsis(f: &mut Frame, app: &App, chunk: Rect) {
                 format!(
                     "{}: {}",
                     a.relation_type_formatted,
-                    a.node.get_title(&app.app_config, false)[0].clone()
+                    a.node.get_title(&app.app_config.title_language, false)[0].clone()
                 )
             })
             .collect::<Vec<String>>();
@@ -111,7 +111,7 @@ fn draw_synopsis(f: &mut Frame, app: &App, chunk: Rect) {
                 format!(
                     "{}: {}",
                     a.relation_type_formatted,
-                    a.node.get_title(&app.app_config, false)[0].clone()
+                    a.node.get_title(&app.app_config.title_language, false)[0].clone()
                 )
             })
             .collect::<Vec<String>>();
@@ -131,7 +131,7 @@ fn draw_synopsis(f: &mut Frame, app: &App, chunk: Rect) {
                 format!(
                     "{:02}. {}",
                     i + 1,
-                    a.node.get_title(&app.app_config, false)[0].clone()
+                    a.node.get_title(&app.app_config.title_language, false)[0].clone()
                 )
             })
             .collect::<Vec<String>>();
@@ -406,9 +406,14 @@ fn draw_info(f: &mut Frame, app: &App, chunk: Rect) {
     // user stats:
     let user_status_list = app.anime_details.as_ref().unwrap().my_list_status.clone();
     // user_status:
+    let add_text = if app.is_anonymous {
+        "login to add"
+    } else {
+        "add to list"
+    };
     let user_status = user_status_list
         .as_ref()
-        .map_or("add to list".to_string(), |s| s.status.clone().to_string());
+        .map_or(add_text.to_string(), |s| s.status.clone().to_string());
     let mut user_status_paragraph = Paragraph::new(user_status)
         .alignment(Alignment::Center)
         .block(unhovered_block.clone());
@@ -0,0 +1,90 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Cell, Padding, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    app::{ActiveBlock, App, ClickTarget},
+    config::keymap::{Action, KeyContext},
+    ui::util::register_list_items,
+};
+
+use super::{draw_keys_bar, loading::draw_centered_line};
+
+pub fn draw_bookmarks(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = draw_keys_bar(f, app, chunk);
+    let bookmarks = app.bookmarks.sorted();
+    if bookmarks.is_empty() {
+        let key = app
+            .app_config
+            .keymap
+            .first_key(KeyContext::Details, Action::ToggleBookmark)
+            .map_or(String::new(), |k| format!(" with {}", k));
+        draw_centered_line(
+            f,
+            app,
+            chunk,
+            &format!("No bookmarks yet, add them from a detail page{}", key),
+        );
+        return;
+    }
+
+    let theme = app.app_config.theme;
+    let header = Row::new(["Group", "Type", "Title", "Note"])
+        .style(
+            Style::default()
+                .fg(theme.mal_color)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+    let rows: Vec<Row> = bookmarks
+        .iter()
+        .map(|b| {
+            Row::new([
+                Cell::from(b.group.clone().unwrap_or_else(|| "-".to_string())),
+                Cell::from(Into::<&str>::into(b.kind)),
+                Cell::from(b.title.clone()),
+                Cell::from(b.note.clone()),
+            ])
+            .style(Style::default().fg(theme.text))
+        })
+        .collect();
+
+    let is_active = app.active_block == ActiveBlock::DisplayBlock;
+    let mut state = TableState::default();
+    state.select(is_active.then(|| app.search_results.selected_display_card_index.unwrap_or(0)));
+
+    let block = Block::default().padding(Padding::new(2, 2, 1, 1));
+    let rows_area = block.inner(chunk);
+    let widths = [
+        Constraint::Length(20),
+        Constraint::Length(6),
+        Constraint::Fill(2),
+        Constraint::Fill(1),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(
+            Style::default()
+                .fg(theme.selected)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    f.render_stateful_widget(table, chunk, &mut state);
+    // rows start under the header and its margin
+    register_list_items(
+        app,
+        Rect {
+            y: rows_area.y + 2,
+            height: rows_area.height.saturating_sub(2),
+            ..rows_area
+        },
+        state.offset(),
+        bookmarks.len(),
+        ClickTarget::Card,
+    );
+}
@@ -0,0 +1,132 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::{ActiveDisplayBlock, App, ClickTarget},
+    bulk::BulkPopup,
+    config::keymap::{Action, KeyContext},
+    ui::util::register_list_items,
+};
+
+use super::center_area;
+
+const MARK: &str = "● ";
+
+/// title of a card or a row, marked ones for a bulk edit are prefixed
+pub fn marked_title(app: &App, id: u64, title: &str) -> String {
+    if app.bulk.is_marked(id) {
+        format!("{}{}", MARK, title)
+    } else {
+        title.to_string()
+    }
+}
+
+pub fn draw_bulk_popup(f: &mut Frame, app: &App, chunk: Rect) {
+    let Some(popup) = app.bulk.popup.as_ref() else {
+        return;
+    };
+    let area = center_area(chunk, 40, 50);
+    let title = match popup {
+        BulkPopup::Actions => format!("Edit {} entries", app.bulk.marked.len()),
+        BulkPopup::Status => "Change status".to_string(),
+        BulkPopup::Score => "Set score".to_string(),
+        BulkPopup::Tag(_) => "Add tag".to_string(),
+        BulkPopup::ConfirmDelete => "Delete from list".to_string(),
+        BulkPopup::Running(_) => "Bulk edit".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double);
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    let inner_area = center_area(area, 90, 90);
+
+    let is_anime = app.active_display_block == ActiveDisplayBlock::UserAnimeList;
+    if let Some(options) = popup.options(is_anime) {
+        let mut state = ListState::default();
+        state.select(Some(app.bulk.selected));
+        let block = Block::default().padding(Padding::symmetric(2, 1));
+        let items_area = block.inner(inner_area);
+        let len = options.len();
+        let list = List::new(options)
+            .block(block)
+            .style(Style::default().fg(app.app_config.theme.text))
+            .highlight_style(
+                Style::default()
+                    .fg(app.app_config.theme.selected)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("> ");
+        f.render_stateful_widget(list, inner_area, &mut state);
+        register_list_items(
+            app,
+            items_area,
+            state.offset(),
+            len,
+            ClickTarget::PopupOption,
+        );
+        return;
+    }
+
+    let text_style = Style::default().fg(app.app_config.theme.text);
+    let active_style = Style::default().fg(app.app_config.theme.active);
+    let lines = match popup {
+        BulkPopup::Tag(tag) => vec![
+            Line::styled("Tag added to every marked entry:", text_style),
+            Line::from(""),
+            Line::styled(format!("{}_", tag), active_style),
+            Line::from(""),
+            Line::styled("Enter to apply, Esc to cancel", text_style),
+        ],
+        BulkPopup::ConfirmDelete => vec![
+            Line::styled(
+                format!("Delete {} entries from your list?", app.bulk.marked.len()),
+                text_style,
+            ),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(get_key(app, Action::Confirm), active_style),
+                Span::styled(" to delete, ", text_style),
+                Span::styled(get_key(app, Action::Cancel), active_style),
+                Span::styled(" to cancel", text_style),
+            ]),
+        ],
+        BulkPopup::Running(progress) => {
+            let mut lines = vec![Line::styled(
+                format!("{} / {} done", progress.done, progress.total),
+                active_style,
+            )];
+            if progress.is_finished() {
+                lines.push(Line::from(""));
+                lines.push(Line::styled(progress.summary(), text_style));
+                lines.extend(progress.failed.iter().map(|title| {
+                    Line::styled(
+                        format!("failed: {}", title),
+                        Style::default().fg(app.app_config.theme.error_text),
+                    )
+                }));
+            }
+            lines
+        }
+        _ => vec![],
+    };
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().padding(Padding::symmetric(2, 1)))
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, inner_area);
+}
+
+fn get_key(app: &App, action: Action) -> String {
+    app.app_config
+        .keymap
+        .first_key(KeyContext::Popup, action)
+        .map_or_else(String::new, |key| key.to_string())
+}
@@ -0,0 +1,139 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use crate::{app::App, compare::Comparison};
+
+pub fn draw_comparison(f: &mut Frame, app: &App, chunk: Rect) {
+    let comparison = app.comparison.as_ref().unwrap();
+
+    let [summary_chunk, lists_chunk] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(6), Constraint::Fill(1)])
+        .areas(chunk.inner(Margin::new(2, 1)));
+
+    draw_summary(f, app, comparison, summary_chunk);
+
+    let [disagreements_chunk, recommendations_chunk] = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .areas(lists_chunk);
+
+    draw_disagreements(f, app, comparison, disagreements_chunk);
+    draw_recommendations(f, app, comparison, recommendations_chunk);
+}
+
+fn format_mean(mean: Option<f64>) -> String {
+    mean.map_or("N/A".to_string(), |m| format!("{:.2}", m))
+}
+
+fn draw_summary(f: &mut Frame, app: &App, comparison: &Comparison, chunk: Rect) {
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let kind = if comparison.is_anime {
+        "anime"
+    } else {
+        "manga"
+    };
+
+    let lines = vec![
+        Line::from(vec![
+            Span::raw("Shared titles: "),
+            Span::styled(comparison.shared.len().to_string(), bold),
+            Span::raw(format!(
+                "   (you: {} {}, {}: {} {})",
+                comparison.my_count, kind, comparison.user, comparison.their_count, kind
+            )),
+        ]),
+        Line::from(vec![
+            Span::raw("Mean score: you "),
+            Span::styled(format_mean(comparison.my_mean), bold),
+            Span::raw(format!("   {} ", comparison.user)),
+            Span::styled(format_mean(comparison.their_mean), bold),
+            Span::raw("   difference "),
+            Span::styled(
+                comparison
+                    .mean_difference
+                    .map_or("N/A".to_string(), |d| format!("{:+.2}", d)),
+                bold,
+            ),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .style(Style::default().fg(app.app_config.theme.text))
+        .alignment(Alignment::Left)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(app.app_config.theme.inactive))
+                .title("Summary"),
+        );
+    f.render_widget(paragraph, chunk);
+}
+
+fn draw_disagreements(f: &mut Frame, app: &App, comparison: &Comparison, chunk: Rect) {
+    let items: Vec<ListItem> = comparison
+        .disagreements
+        .iter()
+        .map(|e| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:+3} ", e.difference()),
+                    Style::default().fg(app.app_config.theme.status_dropped),
+                ),
+                Span::raw(format!("{} ", e.title)),
+                Span::styled(
+                    format!("(you {} / {})", e.my_score, e.their_score),
+                    Style::default().fg(app.app_config.theme.inactive),
+                ),
+            ]))
+        })
+        .collect();
+
+    draw_list(f, app, items, "Biggest Disagreements", chunk);
+}
+
+fn draw_recommendations(f: &mut Frame, app: &App, comparison: &Comparison, chunk: Rect) {
+    let items: Vec<ListItem> = comparison
+        .recommendations
+        .iter()
+        .map(|e| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:>2} ⭐ ", e.score),
+                    Style::default().fg(app.app_config.theme.status_completed),
+                ),
+                Span::raw(e.title.clone()),
+            ]))
+        })
+        .collect();
+
+    let title = format!("Rated Highly By {}, Not On Your List", comparison.user);
+    draw_list(f, app, items, &title, chunk);
+}
+
+fn draw_list(f: &mut Frame, app: &App, items: Vec<ListItem>, title: &str, chunk: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(app.app_config.theme.inactive))
+        .title(title.to_string());
+
+    if items.is_empty() {
+        let paragraph = Paragraph::new("Nothing here")
+            .alignment(Alignment::Center)
+            .block(block);
+        f.render_widget(paragraph, chunk);
+        return;
+    }
+
+    let list = List::new(items)
+        .style(Style::default().fg(app.app_config.theme.text))
+        .block(block);
+    f.render_widget(list, chunk);
+}
@@ -9,13 +9,15 @@ use ratatui_image::StatefulImage;
 use tui_scrollview::{ScrollView, ScrollbarVisibility};
 
 use crate::{
+    airing,
     api::model::{
         AlternativeTitles, AnimeMediaType, AnimeStatus, MangaMediaType, MangaStatus, Source,
     },
     app::{
-        ActiveDisplayBlock, ActiveMangaDetailBlock, App, RATING_OPTIONS, USER_READ_STATUS,
-        USER_WATCH_STATUS,
+        ActiveDisplayBlock, ActiveMangaDetailBlock, App, ClickTarget, RATING_OPTIONS,
+        USER_READ_STATUS, USER_WATCH_STATUS,
     },
+    ui::util::register_list_items,
 };
 
 use super::{center_area, loading};
@@ -399,7 +401,7 @@ pub fn get_anime_key_val_info(
         .as_ref()
         .map_or("Unknown".to_string(), |b| {
             format!(
-                "{} {}",
+                "{} {} (JST)",
                 b.clone()
                     .start_time
                     .map_or("?".to_string(), |t| t.time.to_string()),
@@ -412,6 +414,24 @@ pub fn get_anime_key_val_info(
     let broadcast_line = Line::from(vec![broadcast_title, Span::raw(broadcast)]);
     key_vals_paragraph.push(broadcast_line);
 
+    //* next episode, in local time:
+    let now = chrono::Utc::now();
+    if let Some(next) = airing::next_episode_airing(app.anime_details.as_ref().unwrap(), now) {
+        let next_episode = format!(
+            "airs in {} ({})",
+            airing::format_countdown(next, now),
+            next.with_timezone(&chrono::Local).format("%a %H:%M")
+        );
+        let next_episode_title = Span::styled(
+            "Next episode: ",
+            Style::default().add_modifier(Modifier::BOLD),
+        );
+        key_vals_paragraph.push(Line::from(vec![
+            next_episode_title,
+            Span::raw(next_episode),
+        ]));
+    }
+
     //* studios:
     let studios =
         app.anime_details
@@ -705,12 +725,22 @@ pub fn draw_user_status_popup(f: &mut Frame, app: &App, chunk: Rect) {
     let mut state = ListState::default();
     state.select(selected_status);
 
+    let list_area = center_area(chunk, 100, 70);
+    let items_area = block.inner(list_area);
+    let len = status_list.len();
     let list = List::new(status_list).block(block).highlight_style(
         Style::default()
             .fg(app.app_config.theme.selected)
             .add_modifier(Modifier::BOLD),
     );
-    f.render_stateful_widget(list, center_area(chunk, 100, 70), &mut state);
+    f.render_stateful_widget(list, list_area, &mut state);
+    register_list_items(
+        app,
+        items_area,
+        state.offset(),
+        len,
+        ClickTarget::PopupOption,
+    );
 }
 
 pub fn draw_rate_popup(f: &mut Frame, app: &App, chunk: Rect) {
@@ -755,12 +785,22 @@ pub fn draw_rate_popup(f: &mut Frame, app: &App, chunk: Rect) {
     let mut state = ListState::default();
     state.select(selected_rate);
 
+    let list_area = center_area(chunk, 100, 80);
+    let items_area = block.inner(list_area);
+    let len = rate_list.len();
     let list = List::new(rate_list).block(block).highlight_style(
         Style::default()
             .fg(app.app_config.theme.selected)
             .add_modifier(Modifier::BOLD),
     );
-    f.render_stateful_widget(list, center_area(chunk, 100, 80), &mut state);
+    f.render_stateful_widget(list, list_area, &mut state);
+    register_list_items(
+        app,
+        items_area,
+        state.offset(),
+        len,
+        ClickTarget::PopupOption,
+    );
 }
 
 pub fn draw_count_popup(f: &mut Frame, app: &App, chunk: Rect) {
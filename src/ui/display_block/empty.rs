@@ -1,3 +1,4 @@
+use super::home;
 use crate::app::App;
 use figlet_rs::FIGfont;
 use ratatui::layout::Flex;
@@ -11,6 +12,10 @@ use ratatui::{
 };
 
 pub fn draw_empty(f: &mut Frame, app: &App, chunk: Rect) {
+    if !app.app_config.home.widgets.is_empty() {
+        home::draw_home(f, app, chunk);
+        return;
+    }
     let [banner_layout] = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(6)])
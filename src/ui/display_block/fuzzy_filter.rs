@@ -0,0 +1,42 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::App;
+
+/// filter line under the cards while a query is typed or kept,
+/// returns the area left for the cards
+pub fn draw_filter_line(f: &mut Frame, app: &App, chunk: Rect, matches: usize) -> Rect {
+    if !app.fuzzy_filter.is_active() {
+        return chunk;
+    }
+    let [cards_chunk, filter_chunk] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .areas(chunk);
+
+    let theme = app.app_config.theme;
+    let query_style = if app.fuzzy_filter.editing {
+        Style::default()
+            .fg(theme.active)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.text)
+    };
+    let cursor = if app.fuzzy_filter.editing { "_" } else { "" };
+
+    let line = Line::from(vec![
+        Span::styled(" > ", Style::default().fg(theme.mal_color)),
+        Span::styled(format!("{}{}", app.fuzzy_filter.query, cursor), query_style),
+        Span::styled(
+            format!("  {} matches", matches),
+            Style::default().fg(theme.inactive),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(line), filter_chunk);
+    cards_chunk
+}
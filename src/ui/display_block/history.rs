@@ -0,0 +1,80 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Cell, Padding, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    app::{ActiveBlock, App, ClickTarget},
+    history::viewed_ago,
+    ui::util::register_list_items,
+};
+
+use super::{draw_keys_bar, fuzzy_filter, loading::draw_centered_line};
+
+pub fn draw_history(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = draw_keys_bar(f, app, chunk);
+    if app.history.entries.is_empty() {
+        draw_centered_line(f, app, chunk, "Nothing viewed yet");
+        return;
+    }
+    let entries = app.history.matching(&app.fuzzy_filter.query);
+    let chunk = fuzzy_filter::draw_filter_line(f, app, chunk, entries.len());
+
+    let theme = app.app_config.theme;
+    let header = Row::new(["Viewed", "Type", "Title"])
+        .style(
+            Style::default()
+                .fg(theme.mal_color)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+    let now = chrono::Utc::now().timestamp();
+    let rows: Vec<Row> = entries
+        .iter()
+        .map(|v| {
+            Row::new([
+                Cell::from(viewed_ago(v.viewed_at, now)),
+                Cell::from(Into::<&str>::into(v.kind)),
+                Cell::from(v.title.clone()),
+            ])
+            .style(Style::default().fg(theme.text))
+        })
+        .collect();
+
+    let is_active = app.active_block == ActiveBlock::DisplayBlock;
+    let mut state = TableState::default();
+    state.select(is_active.then(|| app.search_results.selected_display_card_index.unwrap_or(0)));
+
+    let block = Block::default().padding(Padding::new(2, 2, 1, 1));
+    let rows_area = block.inner(chunk);
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(6),
+        Constraint::Fill(1),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(
+            Style::default()
+                .fg(theme.selected)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    f.render_stateful_widget(table, chunk, &mut state);
+    // rows start under the header and its margin
+    register_list_items(
+        app,
+        Rect {
+            y: rows_area.y + 2,
+            height: rows_area.height.saturating_sub(2),
+            ..rows_area
+        },
+        state.offset(),
+        entries.len(),
+        ClickTarget::Card,
+    );
+}
@@ -0,0 +1,205 @@
+use chrono::{DateTime, Utc};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{
+    airing,
+    api::model::{Anime, UserWatchStatus},
+    app::{ActiveBlock, App, ClickTarget},
+    config::app_config::HomeWidget,
+    history::viewed_ago,
+    ui::util::get_color,
+};
+
+use super::{draw_keys_bar, get_anime_status_color};
+
+/// widgets of the home dashboard in two columns, in the configured order
+pub fn draw_home(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = draw_keys_bar(f, app, chunk);
+    let widgets = &app.app_config.home.widgets;
+    let rows = widgets.len().div_ceil(2);
+    let row_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+        .split(chunk);
+
+    let now = chrono::Utc::now();
+    for (index, widget) in widgets.iter().enumerate() {
+        let [left, right] = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(row_chunks[index / 2]);
+        // the last widget of an odd count takes the whole row
+        let widget_chunk = if index == widgets.len() - 1 && index % 2 == 0 {
+            row_chunks[index / 2]
+        } else if index % 2 == 0 {
+            left
+        } else {
+            right
+        };
+        draw_widget(f, app, widget_chunk, index, *widget, now);
+    }
+}
+
+fn draw_widget(
+    f: &mut Frame,
+    app: &App,
+    chunk: Rect,
+    index: usize,
+    widget: HomeWidget,
+    now: DateTime<Utc>,
+) {
+    let theme = app.app_config.theme;
+    let is_focused = index == app.home.focused && app.active_block == ActiveBlock::DisplayBlock;
+    let title: &str = widget.into();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(get_color(is_focused, theme))
+        .title(Span::styled(
+            format!(" {} ", title),
+            Style::default()
+                .fg(theme.mal_color)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let items = match widget_items(app, widget, now) {
+        Ok(items) => items,
+        Err(message) => {
+            let line = Line::styled(message, Style::default().fg(theme.inactive));
+            f.render_widget(Paragraph::new(line).block(block), chunk);
+            return;
+        }
+    };
+    let len = items.len();
+    let area = block.inner(chunk);
+
+    let mut state = ListState::default();
+    state.select(is_focused.then_some(app.home.selected));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(theme.selected)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    f.render_stateful_widget(list, chunk, &mut state);
+
+    for (line, entry) in (state.offset()..len).enumerate().take(area.height as usize) {
+        let entry_area = Rect::new(area.x, area.y + line as u16, area.width, 1);
+        app.register_click_area(entry_area, ClickTarget::Entry(index, entry));
+    }
+}
+
+/// entries of the widget, or the line shown instead of them
+fn widget_items(
+    app: &App,
+    widget: HomeWidget,
+    now: DateTime<Utc>,
+) -> Result<Vec<ListItem<'static>>, String> {
+    let entries = match widget {
+        HomeWidget::Watching => &app.home.watching,
+        HomeWidget::AiringThisSeason => &app.home.airing,
+        HomeWidget::RecentUpdates => &app.home.recent_updates,
+        HomeWidget::RecentlyViewed => {
+            if app.history.entries.is_empty() {
+                return Err("Nothing viewed yet".to_string());
+            }
+            let limit = app.app_config.home.entries_per_widget as usize;
+            return Ok(app
+                .history
+                .entries
+                .iter()
+                .take(limit)
+                .map(|viewed| {
+                    let kind: &str = viewed.kind.into();
+                    entry_line(
+                        app,
+                        viewed.title.clone(),
+                        format!(
+                            "{} · {}",
+                            kind,
+                            viewed_ago(viewed.viewed_at, now.timestamp())
+                        ),
+                        None,
+                    )
+                })
+                .collect());
+        }
+        HomeWidget::QuickActions => {
+            return Ok(app
+                .home_quick_actions()
+                .iter()
+                .map(|command| ListItem::new(command.label()))
+                .collect());
+        }
+    };
+    if app.is_anonymous {
+        return Err("Login to see your list".to_string());
+    }
+    let Some(entries) = entries else {
+        return Err("Loading...".to_string());
+    };
+    if entries.is_empty() {
+        return Err("Nothing here".to_string());
+    }
+    Ok(entries
+        .iter()
+        .map(|anime| {
+            let status = anime.my_list_status.as_ref().map(|s| s.status.clone());
+            entry_line(
+                app,
+                anime.get_title(&app.app_config.title_language, false)[0].clone(),
+                anime_details(anime, widget, now),
+                status,
+            )
+        })
+        .collect())
+}
+
+/// progress and the next episode countdown, or the status for recent updates
+fn anime_details(anime: &Anime, widget: HomeWidget, now: DateTime<Utc>) -> String {
+    let watched = anime
+        .my_list_status
+        .as_ref()
+        .map_or(0, |s| s.num_episodes_watched);
+    let episodes = anime
+        .num_episodes
+        .filter(|n| *n > 0)
+        .map_or("?".to_string(), |n| n.to_string());
+    let progress = format!("ep {}/{}", watched, episodes);
+    if widget == HomeWidget::RecentUpdates {
+        let status: &str = anime
+            .my_list_status
+            .as_ref()
+            .map_or("", |s| s.status.clone().into());
+        return format!("{} · {}", status.replace('_', " "), progress);
+    }
+    match airing::next_episode_airing(anime, now) {
+        Some(next) => format!(
+            "{} · next ep in {}",
+            progress,
+            airing::format_countdown(next, now)
+        ),
+        None => progress,
+    }
+}
+
+fn entry_line(
+    app: &App,
+    title: String,
+    details: String,
+    status: Option<UserWatchStatus>,
+) -> ListItem<'static> {
+    let theme = app.app_config.theme;
+    let details_color = status.map_or(theme.inactive, |s| get_anime_status_color(&s, app));
+    ListItem::new(Line::from(vec![
+        Span::styled(title, Style::default().fg(theme.text)),
+        Span::styled(format!("  {}", details), Style::default().fg(details_color)),
+    ]))
+}
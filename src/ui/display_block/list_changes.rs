@@ -0,0 +1,68 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Cell, Padding, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    app::{ActiveBlock, App},
+    history::viewed_ago,
+};
+
+use super::{draw_keys_bar, loading::draw_centered_line};
+
+pub fn draw_list_changes(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = draw_keys_bar(f, app, chunk);
+    let entries = &app.list_changes.entries;
+    if entries.is_empty() {
+        draw_centered_line(f, app, chunk, "No change of your list yet");
+        return;
+    }
+
+    let theme = app.app_config.theme;
+    let header = Row::new(["Changed", "Type", "Title", "Change"])
+        .style(
+            Style::default()
+                .fg(theme.mal_color)
+                .add_modifier(Modifier::BOLD),
+        )
+        .bottom_margin(1);
+
+    let now = chrono::Utc::now().timestamp();
+    let rows: Vec<Row> = entries
+        .iter()
+        .map(|c| {
+            Row::new([
+                Cell::from(viewed_ago(c.changed_at, now)),
+                Cell::from(Into::<&str>::into(c.kind())),
+                Cell::from(c.title.clone()),
+                Cell::from(c.describe()),
+            ])
+            .style(Style::default().fg(theme.text))
+        })
+        .collect();
+
+    let is_active = app.active_block == ActiveBlock::DisplayBlock;
+    let mut state = TableState::default();
+    state.select(is_active.then(|| app.search_results.selected_display_card_index.unwrap_or(0)));
+
+    // rows aren't clickable, a click would revert the change right away
+    let block = Block::default().padding(Padding::new(2, 2, 1, 1));
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(6),
+        Constraint::Fill(1),
+        Constraint::Fill(2),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(
+            Style::default()
+                .fg(theme.selected)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    f.render_stateful_widget(table, chunk, &mut state);
+}
@@ -0,0 +1,101 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Padding},
+    Frame,
+};
+
+use crate::{
+    app::{App, ClickTarget, LIST_VIEW_OPTIONS},
+    list_view::ListView,
+    ui::util::{capitalize_each_word, register_list_items},
+};
+
+use super::{center_area, loading::draw_centered_line};
+
+pub fn draw_list_view_popup(f: &mut Frame, app: &App, chunk: Rect, is_anime: bool) {
+    let area = center_area(chunk, 40, 50);
+    let popup = Block::default()
+        .title("Sort & Filter")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double);
+
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+
+    let view = if is_anime {
+        &app.app_config.list_views.anime
+    } else {
+        &app.app_config.list_views.manga
+    };
+
+    let lines: Vec<Line> = LIST_VIEW_OPTIONS
+        .iter()
+        .zip(get_option_values(view))
+        .map(|(option, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<12}", option),
+                    Style::default().fg(app.app_config.theme.text),
+                ),
+                Span::styled(value, Style::default().fg(app.app_config.theme.active)),
+            ])
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(app.list_view_popup_index));
+
+    let list_area = center_area(area, 90, 90);
+    let block = Block::default().padding(Padding::symmetric(2, 1));
+    let items_area = block.inner(list_area);
+    let len = lines.len();
+    let list = List::new(lines)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(app.app_config.theme.selected)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    f.render_stateful_widget(list, list_area, &mut state);
+    register_list_items(
+        app,
+        items_area,
+        state.offset(),
+        len,
+        ClickTarget::PopupOption,
+    );
+}
+
+fn get_option_values(view: &ListView) -> Vec<String> {
+    let filter = &view.filter;
+    let or_all = |value: &Option<String>| {
+        value.as_ref().map_or("All".to_string(), |v| {
+            capitalize_each_word(v.replace('_', " "))
+        })
+    };
+    vec![
+        format!("< {} >", Into::<&str>::into(view.sort)),
+        if view.reverse { "Reversed" } else { "Default" }.to_string(),
+        or_all(&filter.genre),
+        or_all(&filter.media_type),
+        or_all(&filter.status),
+        filter.min_score.to_string(),
+        filter.max_score.to_string(),
+        or_all(&filter.tag),
+        "enter".to_string(),
+    ]
+}
+
+/// shown instead of the cards when the filters leave nothing
+pub fn draw_no_matches(f: &mut Frame, app: &App, chunk: Rect) {
+    draw_centered_line(
+        f,
+        app,
+        chunk,
+        "No entries match the filters (r to change them)",
+    );
+}
@@ -128,7 +128,7 @@ fn draw_synopsis(f: &mut Frame, app: &App, chunk: Rect) {
                 format!(
                     "{}: {}",
                     a.relation_type_formatted,
-                    a.node.get_title(&app.app_config, false)[0].clone()
+                    a.node.get_title(&app.app_config.title_language, false)[0].clone()
                 )
             })
             .collect::<Vec<String>>();
@@ -148,7 +148,7 @@ fn draw_synopsis(f: &mut Frame, app: &App, chunk: Rect) {
                 format!(
                     "{}: {}",
                     a.relation_type_formatted,
-                    a.node.get_title(&app.app_config, false)[0].clone()
+                    a.node.get_title(&app.app_config.title_language, false)[0].clone()
                 )
             })
             .collect::<Vec<String>>();
@@ -168,7 +168,7 @@ fn draw_synopsis(f: &mut Frame, app: &App, chunk: Rect) {
                 format!(
                     "{:02}. {}",
                     i + 1,
-                    a.node.get_title(&app.app_config, false)[0].clone()
+                    a.node.get_title(&app.app_config.title_language, false)[0].clone()
                 )
             })
             .collect::<Vec<String>>();
@@ -415,11 +415,14 @@ fn draw_info(f: &mut Frame, app: &App, chunk: Rect) {
     // user stats:
     let user_status_list = app.manga_details.as_ref().unwrap().my_list_status.clone();
     // user_status:
-    let user_status = user_status_list
-        .as_ref()
-        .map_or("add to list".to_string(), |s| {
-            Into::<&str>::into(s.status.clone()).to_string()
-        });
+    let add_text = if app.is_anonymous {
+        "login to add"
+    } else {
+        "add to list"
+    };
+    let user_status = user_status_list.as_ref().map_or(add_text.to_string(), |s| {
+        Into::<&str>::into(s.status.clone()).to_string()
+    });
     let mut user_status_paragraph = Paragraph::new(user_status)
         .alignment(Alignment::Center)
         .block(unhovered_block.clone());
@@ -1,21 +1,34 @@
 use crate::api::model::{UserReadStatus, UserWatchStatus};
 use crate::app::{ActiveBlock, ActiveDisplayBlock, App};
+use crate::config::keymap::Action;
+use crate::event::Key;
 use ratatui::layout::{Alignment, Constraint, Direction, Flex, Layout};
 use ratatui::style::{Color, Style};
 use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
 use ratatui::{layout::Rect, Frame};
 mod error;
+mod fuzzy_filter;
 mod seasonal;
-use super::util::get_color;
+use super::{help, util::get_color};
 mod anime_details;
+mod bookmarks;
+mod bulk_popup;
+mod comparison;
 mod details_utils;
 mod empty;
+mod history;
+mod home;
+mod list_changes;
+mod list_view_popup;
 mod loading;
 mod manga_details;
 mod ranking;
 mod results;
+mod schedule;
 mod search;
+mod stats_dashboard;
 mod suggestion;
+mod table;
 mod user;
 mod user_anime_list;
 mod user_manga_list;
@@ -28,7 +41,7 @@ pub fn draw_display_layout(f: &mut Frame, app: &mut App, chunk: Rect) {
     match current_display_block {
         ActiveDisplayBlock::Empty => empty::draw_empty(f, app, chunk),
 
-        ActiveDisplayBlock::Help => {} // draw_help_menu(f, app);
+        ActiveDisplayBlock::Help => help::draw_help_menu(f, app, chunk),
 
         ActiveDisplayBlock::AnimeDetails => anime_details::draw_anime_detail(f, app, chunk),
 
@@ -52,6 +65,15 @@ pub fn draw_display_layout(f: &mut Frame, app: &mut App, chunk: Rect) {
 
         ActiveDisplayBlock::Error => error::draw_error(f, app, chunk),
 
+        ActiveDisplayBlock::Comparison => comparison::draw_comparison(f, app, chunk),
+
+        ActiveDisplayBlock::StatsDashboard => stats_dashboard::draw_stats_dashboard(f, app, chunk),
+
+        ActiveDisplayBlock::Bookmarks => bookmarks::draw_bookmarks(f, app, chunk),
+        ActiveDisplayBlock::History => history::draw_history(f, app, chunk),
+        ActiveDisplayBlock::ListChanges => list_changes::draw_list_changes(f, app, chunk),
+        ActiveDisplayBlock::Schedule => schedule::draw_schedule(f, app, chunk),
+
         ActiveDisplayBlock::Loading => {
             if app.is_loading {
                 loading::draw_centered_line(f, app, chunk, "Loading...");
@@ -74,15 +96,176 @@ pub fn draw_main_display_layout(f: &mut Frame, app: &App, chunk: Rect) {
     f.render_widget(block, chunk);
 }
 
-pub const NAVIGATION_KEYS: [(&str, &str); 5] = [
-    ("s", "Switch Type"),
-    ("q", "Quit"),
-    ("arrows", "Navigate"),
-    ("n", "Next page"),
-    ("p", "Previous page"),
+use Action::*;
+
+const NAVIGATE: &[Action] = &[Up, Down, Left, Right];
+
+/// hints of the key bar, each one shows the first key of its actions
+const GRID_HINTS: &[(&[Action], &str)] = &[
+    (&[Toggle], "Switch Type"),
+    (&[Filter], "Filter"),
+    (&[ToggleLayout], "Cards/Table"),
+    (NAVIGATE, "Navigate"),
+    (&[Select], "Details"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const SEASONAL_HINTS: &[(&[Action], &str)] = &[
+    (&[Toggle], "Season"),
+    (&[Filter], "Filter"),
+    (&[ToggleLayout], "Cards/Table"),
+    (NAVIGATE, "Navigate"),
+    (&[Select], "Details"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const RANKING_HINTS: &[(&[Action], &str)] = &[
+    (&[Toggle], "Anime/Manga"),
+    (&[OpenPopup], "Ranking Type"),
+    (&[Filter], "Filter"),
+    (&[ToggleLayout], "Cards/Table"),
+    (NAVIGATE, "Navigate"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const USER_LIST_HINTS: &[(&[Action], &str)] = &[
+    (&[Toggle], "Switch Status"),
+    (&[IncrementProgress], "+1"),
+    (&[Mark], "Mark"),
+    (&[BulkEdit], "Bulk Edit"),
+    (&[SwitchMedia], "Anime/Manga"),
+    (&[OpenPopup], "Sort/Filter"),
+    (&[ToggleLayout], "Cards/Table"),
+    (NAVIGATE, "Navigate"),
+    (&[Select], "Details"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const OTHER_USER_LIST_HINTS: &[(&[Action], &str)] = &[
+    (&[Toggle], "Switch Status"),
+    (&[SwitchMedia], "Anime/Manga"),
+    (&[OpenPopup], "Sort/Filter"),
+    (&[CompareMode], "Compare"),
+    (&[ViewComparison], "Comparison"),
+    (NAVIGATE, "Navigate"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const PROFILE_HINTS: &[(&[Action], &str)] = &[
+    (&[SwitchMedia], "Anime/Manga"),
+    (&[StatsDashboard], "Dashboard"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const DASHBOARD_HINTS: &[(&[Action], &str)] = &[
+    (&[Toggle, Left, Right], "Switch Tab"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const DETAILS_HINTS: &[(&[Action], &str)] = &[
+    (&[Toggle], "Switch Tab"),
+    (NAVIGATE, "Navigate"),
+    (&[Select], "Select"),
+    (&[IncrementProgress], "+1"),
+    (&[ToggleBookmark], "Bookmark"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const BOOKMARKS_HINTS: &[(&[Action], &str)] = &[
+    (&[Up, Down], "Navigate"),
+    (&[Select], "Details"),
+    (&[ToggleBookmark], "Remove"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const HISTORY_HINTS: &[(&[Action], &str)] = &[
+    (&[Up, Down], "Navigate"),
+    (&[Select], "Details"),
+    (&[Filter], "Search"),
+    (&[ClearHistory], "Clear"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const LIST_CHANGES_HINTS: &[(&[Action], &str)] = &[
+    (&[Up, Down], "Navigate"),
+    (&[Select], "Revert"),
+    (&[Undo], "Undo Last"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const SCHEDULE_HINTS: &[(&[Action], &str)] = &[
+    (&[Left, Right], "Switch Day"),
+    (&[Up, Down], "Navigate"),
+    (&[Select], "Details"),
+    (&[Toggle], "My List/Season"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const HOME_HINTS: &[(&[Action], &str)] = &[
+    (&[Left, Right], "Switch Widget"),
+    (&[Up, Down], "Navigate"),
+    (&[Select], "Open"),
+    (&[Help], "Help"),
+    (&[Quit], "Quit"),
+];
+const HELP_HINTS: &[(&[Action], &str)] = &[
+    (&[Search], "Search"),
+    (&[Toggle], "All Contexts"),
+    (&[Up, Down], "Scroll"),
+    (&[Back], "Back"),
 ];
-pub const DETAILS_NAVIGATION_KEYS: [(&str, &str); 3] =
-    [("s/arrows", "Navigate"), ("q", "Quit"), ("enter", "Select")];
+
+fn get_key_hints(app: &App) -> &'static [(&'static [Action], &'static str)] {
+    match app.active_display_block {
+        ActiveDisplayBlock::AnimeDetails | ActiveDisplayBlock::MangaDetails => DETAILS_HINTS,
+        ActiveDisplayBlock::UserAnimeList | ActiveDisplayBlock::UserMangaList => {
+            if app.list_user.is_some() && !app.is_anonymous {
+                OTHER_USER_LIST_HINTS
+            } else {
+                USER_LIST_HINTS
+            }
+        }
+        ActiveDisplayBlock::AnimeRanking | ActiveDisplayBlock::MangaRanking => RANKING_HINTS,
+        ActiveDisplayBlock::Seasonal => SEASONAL_HINTS,
+        ActiveDisplayBlock::UserInfo => PROFILE_HINTS,
+        ActiveDisplayBlock::StatsDashboard => DASHBOARD_HINTS,
+        ActiveDisplayBlock::Help => HELP_HINTS,
+        ActiveDisplayBlock::Bookmarks => BOOKMARKS_HINTS,
+        ActiveDisplayBlock::History => HISTORY_HINTS,
+        ActiveDisplayBlock::ListChanges => LIST_CHANGES_HINTS,
+        ActiveDisplayBlock::Schedule => SCHEDULE_HINTS,
+        ActiveDisplayBlock::Empty => HOME_HINTS,
+        _ => GRID_HINTS,
+    }
+}
+
+/// first key of every action of a hint, arrows are shown side by side
+fn get_hint_keys(app: &App, actions: &[Action]) -> Option<String> {
+    let context = app.display_key_context();
+    let keys: Vec<Key> = actions
+        .iter()
+        .filter_map(|action| app.app_config.keymap.first_key(context, *action))
+        .collect();
+    if keys.is_empty() {
+        return None;
+    }
+    let arrows = keys
+        .iter()
+        .map(|key| match key {
+            Key::Up => Some("↑"),
+            Key::Down => Some("↓"),
+            Key::Left => Some("←"),
+            Key::Right => Some("→"),
+            _ => None,
+        })
+        .collect::<Option<String>>();
+    Some(arrows.unwrap_or_else(|| {
+        keys.iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<String>>()
+            .join("/")
+    }))
+}
 
 pub fn draw_keys_bar(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
     let [display_chunk, keys_chunk] = Layout::default()
@@ -91,12 +274,13 @@ pub fn draw_keys_bar(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
         .constraints([Constraint::Percentage(95), Constraint::Length(2)])
         .areas(chunk);
 
-    let keys = match app.active_display_block {
-        ActiveDisplayBlock::AnimeDetails | ActiveDisplayBlock::MangaDetails => {
-            DETAILS_NAVIGATION_KEYS.to_vec()
-        }
-        _ => NAVIGATION_KEYS.to_vec(),
-    };
+    // hints with no bound key are left out
+    let keys: Vec<(String, &str)> = get_key_hints(app)
+        .iter()
+        .filter_map(|(actions, description)| {
+            get_hint_keys(app, actions).map(|keys| (keys, *description))
+        })
+        .collect();
     let key_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(
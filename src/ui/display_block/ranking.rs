@@ -1,4 +1,4 @@
-use std::{fmt::Debug, slice::Iter};
+use std::slice::Iter;
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -9,18 +9,24 @@ use ratatui::{
 };
 
 use crate::{
-    api::model::{AnimeMediaType, MangaMediaType, PageableData, UserWatchStatus},
+    api::model::{AnimeMediaType, MangaMediaType, UserWatchStatus},
     app::{
-        ActiveBlock, ActiveDisplayBlock, App, ANIME_RANKING_TYPES, DISPLAY_COLUMN_NUMBER,
-        DISPLAY_RAWS_NUMBER, MANGA_RANKING_TYPES,
+        ActiveBlock, ActiveDisplayBlock, App, ClickTarget, ANIME_RANKING_TYPES, MANGA_RANKING_TYPES,
+    },
+    ui::{
+        format_number_with_commas, get_card_page,
+        util::{get_color, register_list_items},
     },
-    ui::{format_number_with_commas, get_end_card_index, util::get_color},
 };
 
-use super::{center_area, get_anime_status_color};
+use super::{
+    center_area, draw_keys_bar, fuzzy_filter, get_anime_status_color, results::construct_card_grid,
+    table,
+};
 
 pub fn draw_anime_ranking(f: &mut Frame, app: &App, chunk: Rect) {
     let chunk = draw_nav_bar(f, app, chunk);
+    let chunk = draw_keys_bar(f, app, chunk);
     draw_anime_ranking_results(f, app, chunk);
     if app.popup {
         draw_ranking_popup(f, app, chunk)
@@ -29,6 +35,7 @@ pub fn draw_anime_ranking(f: &mut Frame, app: &App, chunk: Rect) {
 
 pub fn draw_manga_ranking(f: &mut Frame, app: &App, chunk: Rect) {
     let chunk = draw_nav_bar(f, app, chunk);
+    let chunk = draw_keys_bar(f, app, chunk);
     draw_manga_ranking_results(f, app, chunk);
     if app.popup {
         draw_ranking_popup(f, app, chunk)
@@ -66,6 +73,7 @@ pub fn draw_nav_bar(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
     );
 
     f.render_widget(anime_tab_paragraph, anime_tab);
+    app.register_click_area(anime_tab, ClickTarget::Tab(0));
 
     // manga tab:
     let manga_tab = tab[1];
@@ -81,22 +89,28 @@ pub fn draw_nav_bar(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
     // .block(block);
 
     f.render_widget(manga_tab_block, manga_tab);
+    app.register_click_area(manga_tab, ClickTarget::Tab(1));
     splitted_layout[1]
 }
 
 pub fn draw_anime_ranking_results(f: &mut Frame, app: &App, chunk: Rect) {
     let results = app.anime_ranking_data.as_ref().unwrap();
+    let chunk = fuzzy_filter::draw_filter_line(f, app, chunk, results.data.len());
     if results.data.is_empty() {
         // draw_no_results(f, app, chunk);
         return;
     }
-    let (cards, components) = construct_cards_with_data(chunk, results);
-    let start_index = app.start_card_list_index as usize;
-    let end_index = get_end_card_index(app);
+    if app.is_table_layout() {
+        table::draw_table_layout(f, app, chunk, &results.data);
+        return;
+    }
+    let (grid, position) = get_card_page(app, chunk, results.data.len());
+    let end_index = grid.end_index(position.start, results.data.len());
+    let cards = construct_card_grid(chunk, grid);
 
-    let component_page = components[start_index..=end_index].to_vec();
+    let component_page = &results.data[position.start..=end_index];
 
-    let selected_card_index = app.search_results.selected_display_card_index.unwrap_or(0);
+    let selected_card_index = position.selected;
 
     for (index, component_pair) in component_page.iter().enumerate() {
         let component = component_pair.node.clone();
@@ -116,7 +130,7 @@ pub fn draw_anime_ranking_results(f: &mut Frame, app: &App, chunk: Rect) {
 
         let title_style = get_color(is_active, app.app_config.theme);
 
-        let anime_title = &component.get_title(&app.app_config, false)[0];
+        let anime_title = &component.get_title(&app.app_config.title_language, false)[0];
 
         let title: Line<'_> = Line::from(vec![
             Span::styled(anime_title, title_style.add_modifier(Modifier::BOLD)),
@@ -180,23 +194,28 @@ pub fn draw_anime_ranking_results(f: &mut Frame, app: &App, chunk: Rect) {
             );
 
         f.render_widget(card, cards[index]);
+        app.register_click_area(cards[index], ClickTarget::Card(index));
     }
 }
 
 pub fn draw_manga_ranking_results(f: &mut Frame, app: &App, chunk: Rect) {
     let results = app.manga_ranking_data.as_ref().unwrap();
+    let chunk = fuzzy_filter::draw_filter_line(f, app, chunk, results.data.len());
     if results.data.is_empty() {
         // draw_no_results(f,app,chunk);
         return;
     }
-    let (cards, components) = construct_cards_with_data(chunk, results);
-
-    let start_index = app.start_card_list_index as usize;
-    let end_index = get_end_card_index(app);
+    if app.is_table_layout() {
+        table::draw_table_layout(f, app, chunk, &results.data);
+        return;
+    }
+    let (grid, position) = get_card_page(app, chunk, results.data.len());
+    let end_index = grid.end_index(position.start, results.data.len());
+    let cards = construct_card_grid(chunk, grid);
 
-    let component_page = components[start_index..=end_index].to_vec();
+    let component_page = &results.data[position.start..=end_index];
 
-    let selected_card_index = app.search_results.selected_display_card_index.unwrap_or(0);
+    let selected_card_index = position.selected;
 
     for (index, component_pair) in component_page.iter().enumerate() {
         let component = component_pair.node.clone();
@@ -205,7 +224,7 @@ pub fn draw_manga_ranking_results(f: &mut Frame, app: &App, chunk: Rect) {
 
         let title_style = get_color(is_active, app.app_config.theme);
 
-        let manga_title = &component.get_title(&app.app_config, false)[0];
+        let manga_title = &component.get_title(&app.app_config.title_language, false)[0];
 
         let title: Line<'_> = Line::from(vec![
             Span::styled(manga_title, title_style.add_modifier(Modifier::BOLD)),
@@ -269,43 +288,10 @@ pub fn draw_manga_ranking_results(f: &mut Frame, app: &App, chunk: Rect) {
                     .title_style(get_color(is_active, app.app_config.theme)),
             );
         f.render_widget(card, cards[index]);
+        app.register_click_area(cards[index], ClickTarget::Card(index));
     }
 }
 
-fn construct_cards_with_data<T: Clone + Debug>(
-    chunk: Rect,
-    results: &PageableData<Vec<T>>,
-) -> (Vec<Rect>, Vec<&T>) {
-    let current_page = &results;
-
-    let raw_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(1)
-        .constraints(vec![Constraint::Percentage(20); DISPLAY_RAWS_NUMBER])
-        .split(chunk);
-
-    let components: Vec<&T> = current_page.data.iter().collect();
-
-    (
-        raw_layout
-            .iter()
-            .flat_map(|raw| {
-                Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints(vec![
-                        Constraint::Ratio(1, DISPLAY_COLUMN_NUMBER as u32);
-                        DISPLAY_COLUMN_NUMBER
-                    ])
-                    .split(*raw)
-                    .iter()
-                    .copied()
-                    .collect::<Vec<Rect>>()
-            })
-            .collect(),
-        components,
-    )
-}
-
 fn draw_ranking_popup(f: &mut Frame, app: &App, chunk: Rect) {
     let area = center_area(chunk, 20, 40);
     let popup = Block::default()
@@ -348,13 +334,22 @@ fn draw_ranking_popup(f: &mut Frame, app: &App, chunk: Rect) {
 
     let block = Block::default().padding(Padding::symmetric(1, 2));
 
+    let centered_list_area = center_area(area, 60, 90);
+    let items_area = block.inner(centered_list_area);
     let rank_list = List::new(list).block(block).highlight_style(
         Style::default()
             .fg(app.app_config.theme.selected)
             .add_modifier(Modifier::BOLD), // .add_modifier(Modifier::UNDERLINED),
     );
-    let centered_list_area = center_area(area, 60, 90);
+    let len = rank_list.len();
     f.render_stateful_widget(rank_list, centered_list_area, &mut state);
+    register_list_items(
+        app,
+        items_area,
+        state.offset(),
+        len,
+        ClickTarget::PopupOption,
+    );
 }
 
 // fn draw_manga_ranking_popup(f: &mut Frame, app: &App, chunk: Rect) {
@@ -4,13 +4,12 @@ use crate::api::model::MangaMediaType;
 use crate::api::model::Node;
 use crate::api::model::PageableData;
 use crate::api::model::UserReadStatus;
-use crate::app::DISPLAY_COLUMN_NUMBER;
-use crate::app::DISPLAY_RAWS_NUMBER;
+use crate::app::GridSize;
 use crate::ui::format_number_with_commas;
-use crate::ui::get_end_card_index;
+use crate::ui::get_card_page;
 use crate::{
     api::model::{AnimeMediaType, UserWatchStatus},
-    app::{ActiveBlock, App, SelectedSearchTab},
+    app::{ActiveBlock, App, ClickTarget, SelectedSearchTab},
     ui::util::get_color,
 };
 use ratatui::{
@@ -21,7 +20,7 @@ use ratatui::{
     Frame,
 };
 
-use super::get_anime_status_color;
+use super::{fuzzy_filter, get_anime_status_color, table};
 
 pub fn draw_results(f: &mut Frame, app: &App, chunk: Rect) {
     match app.search_results.selected_tab {
@@ -44,19 +43,22 @@ pub fn draw_results(f: &mut Frame, app: &App, chunk: Rect) {
 
 pub fn draw_anime_search_results(f: &mut Frame, app: &App, chunk: Rect) {
     let results = app.search_results.anime.as_ref().unwrap();
+    let chunk = fuzzy_filter::draw_filter_line(f, app, chunk, results.data.len());
     if results.data.is_empty() {
         // draw_no_results(f, app, chunk);
         return;
     }
-    let start_index = app.start_card_list_index as usize;
-    let end_index = get_end_card_index(app);
-    // let end_index = app.end_card_list_index as usize;
+    if app.is_table_layout() {
+        table::draw_table_layout(f, app, chunk, &results.data);
+        return;
+    }
+    let (grid, position) = get_card_page(app, chunk, results.data.len());
+    let end_index = grid.end_index(position.start, results.data.len());
 
-    let (cards, components_result) = construct_cards_with_data(chunk, results);
-    // we need to calculate the end index carefully
-    let component_page = components_result[start_index..=end_index].to_vec();
+    let (cards, components_result) = construct_cards_with_data(chunk, results, grid);
+    let component_page = components_result[position.start..=end_index].to_vec();
 
-    let selected_card_index = app.search_results.selected_display_card_index.unwrap_or(0);
+    let selected_card_index = position.selected;
 
     // let selected_card_index = 5;
 
@@ -77,7 +79,7 @@ pub fn draw_anime_search_results(f: &mut Frame, app: &App, chunk: Rect) {
 
         let title_style = get_color(is_active, app.app_config.theme);
 
-        let anime_title = &component.get_title(&app.app_config, false)[0];
+        let anime_title = &component.get_title(&app.app_config.title_language, false)[0];
 
         let title: Line<'_> = Line::from(vec![
             Span::styled(anime_title, title_style.add_modifier(Modifier::BOLD)),
@@ -145,6 +147,7 @@ pub fn draw_anime_search_results(f: &mut Frame, app: &App, chunk: Rect) {
             );
 
         f.render_widget(card, cards[index]);
+        app.register_click_area(cards[index], ClickTarget::Card(index));
     }
     /*
     we are gonna display these fields:
@@ -160,17 +163,22 @@ pub fn draw_anime_search_results(f: &mut Frame, app: &App, chunk: Rect) {
 
 pub fn draw_manga_search_results(f: &mut Frame, app: &App, chunk: Rect) {
     let results = app.search_results.manga.as_ref().unwrap();
+    let chunk = fuzzy_filter::draw_filter_line(f, app, chunk, results.data.len());
     if results.data.is_empty() {
         //TODO: handle no results
         // draw_no_results(f, app, chunk);
         return;
     }
-    let start_index = app.start_card_list_index as usize;
-    let end_index = get_end_card_index(app);
-    let (cards, components_result) = construct_cards_with_data(chunk, results);
-    let component_page = components_result[start_index..=end_index].to_vec();
+    if app.is_table_layout() {
+        table::draw_table_layout(f, app, chunk, &results.data);
+        return;
+    }
+    let (grid, position) = get_card_page(app, chunk, results.data.len());
+    let end_index = grid.end_index(position.start, results.data.len());
+    let (cards, components_result) = construct_cards_with_data(chunk, results, grid);
+    let component_page = components_result[position.start..=end_index].to_vec();
 
-    let selected_card_index = app.search_results.selected_display_card_index.unwrap_or(0);
+    let selected_card_index = position.selected;
 
     for (index, component) in component_page.iter().enumerate() {
         if index >= cards.len() {
@@ -189,7 +197,7 @@ pub fn draw_manga_search_results(f: &mut Frame, app: &App, chunk: Rect) {
         let manga_status_color = get_manga_status_color(&manga_status, app);
 
         let title_style = get_color(is_active, app.app_config.theme);
-        let title = &component.get_title(&app.app_config, false)[0];
+        let title = &component.get_title(&app.app_config.title_language, false)[0];
 
         let title: Line<'_> = Line::from(vec![
             Span::styled(title, title_style.add_modifier(Modifier::BOLD)),
@@ -209,7 +217,7 @@ pub fn draw_manga_search_results(f: &mut Frame, app: &App, chunk: Rect) {
                 }),
         );
 
-        let vol_num: String = component.get_num(&app.app_config);
+        let vol_num: String = component.get_num(&app.app_config.manga_display_type);
         let start_date: String = component
             .start_date
             .as_ref()
@@ -249,41 +257,42 @@ pub fn draw_manga_search_results(f: &mut Frame, app: &App, chunk: Rect) {
             );
 
         f.render_widget(card, cards[index]);
+        app.register_click_area(cards[index], ClickTarget::Card(index));
     }
 }
 
 pub fn construct_cards_with_data<T: Clone + Debug>(
     chunk: Rect,
     results: &PageableData<Vec<Node<T>>>,
+    grid: GridSize,
 ) -> (Vec<Rect>, Vec<&T>) {
-    let current_page = &results.data;
+    let components: Vec<&T> = results.data.iter().map(|node| &node.node).collect();
+    (construct_card_grid(chunk, grid), components)
+}
 
+/// card areas of a page, row by row
+pub fn construct_card_grid(chunk: Rect, grid: GridSize) -> Vec<Rect> {
     let raw_layout = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
-        .constraints(vec![Constraint::Percentage(20); DISPLAY_RAWS_NUMBER])
+        .constraints(vec![Constraint::Ratio(1, grid.rows as u32); grid.rows])
         .split(chunk);
 
-    let components: Vec<&T> = current_page.iter().map(|node| &node.node).collect();
-
-    (
-        raw_layout
-            .iter()
-            .flat_map(|raw| {
-                Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints(vec![
-                        Constraint::Ratio(1, DISPLAY_COLUMN_NUMBER as u32);
-                        DISPLAY_COLUMN_NUMBER
-                    ])
-                    .split(*raw)
-                    .iter()
-                    .copied()
-                    .collect::<Vec<Rect>>()
-            })
-            .collect(),
-        components,
-    )
+    raw_layout
+        .iter()
+        .flat_map(|raw| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(vec![
+                    Constraint::Ratio(1, grid.columns as u32);
+                    grid.columns
+                ])
+                .split(*raw)
+                .iter()
+                .copied()
+                .collect::<Vec<Rect>>()
+        })
+        .collect()
 }
 
 fn get_manga_status_color(status: &UserReadStatus, app: &App) -> Color {
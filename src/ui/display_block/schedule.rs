@@ -0,0 +1,104 @@
+use chrono::{Datelike, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::{
+    airing,
+    app::{ActiveBlock, App, ClickTarget},
+    ui::util::get_color,
+};
+
+use super::{draw_keys_bar, get_anime_status_color, loading::draw_centered_line};
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// one column per weekday in local time, each title with its time and countdown
+pub fn draw_schedule(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = draw_keys_bar(f, app, chunk);
+    let Some(schedule) = app.schedule.as_ref() else {
+        return;
+    };
+    if schedule.days.iter().all(Vec::is_empty) {
+        draw_centered_line(f, app, chunk, "Nothing airing");
+        return;
+    }
+
+    let theme = app.app_config.theme;
+    let now = chrono::Utc::now();
+    let today = now.with_timezone(&Local).weekday().num_days_from_monday() as usize;
+    let day_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, 7); 7])
+        .split(chunk);
+
+    for (day, (anime, day_chunk)) in schedule.days.iter().zip(day_chunks.iter()).enumerate() {
+        let is_focused = day == app.schedule_day && app.active_block == ActiveBlock::DisplayBlock;
+        let mut title_style = Style::default().fg(theme.mal_color);
+        if day == today {
+            title_style = title_style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        }
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(get_color(is_focused, theme))
+            .title(Span::styled(format!(" {} ", WEEKDAYS[day]), title_style));
+        let area = block.inner(*day_chunk);
+
+        let items: Vec<ListItem> = anime
+            .iter()
+            .map(|anime| {
+                let time = airing::next_episode_airing(anime, now).map_or(
+                    ("?".to_string(), String::new()),
+                    |next| {
+                        (
+                            next.with_timezone(&Local).format("%H:%M").to_string(),
+                            airing::format_countdown(next, now),
+                        )
+                    },
+                );
+                let status_color = anime
+                    .my_list_status
+                    .as_ref()
+                    .map_or(theme.inactive, |s| get_anime_status_color(&s.status, app));
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(time.0, Style::default().fg(status_color)),
+                        Span::styled(
+                            format!(" in {}", time.1),
+                            Style::default().fg(theme.inactive),
+                        ),
+                    ]),
+                    Line::styled(
+                        anime.get_title(&app.app_config.title_language, false)[0].clone(),
+                        Style::default().fg(theme.text),
+                    ),
+                ])
+            })
+            .collect();
+
+        let mut state = ListState::default();
+        state.select(
+            is_focused.then(|| app.search_results.selected_display_card_index.unwrap_or(0)),
+        );
+        let list = List::new(items).block(block).highlight_style(
+            Style::default()
+                .fg(theme.selected)
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_stateful_widget(list, *day_chunk, &mut state);
+        app.register_click_area(*day_chunk, ClickTarget::Tab(day));
+
+        // two lines per title
+        for (line, index) in (state.offset()..anime.len())
+            .enumerate()
+            .take(area.height as usize / 2)
+        {
+            let entry = Rect::new(area.x, area.y + 2 * line as u16, area.width, 2);
+            app.register_click_area(entry, ClickTarget::Entry(day, index));
+        }
+    }
+}
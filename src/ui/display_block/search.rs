@@ -6,7 +6,7 @@ use ratatui::{
 };
 
 use crate::{
-    app::{App, SelectedSearchTab},
+    app::{App, ClickTarget, SelectedSearchTab},
     ui::util::get_color,
 };
 
@@ -48,6 +48,7 @@ pub fn draw_nav_bar(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
     );
 
     f.render_widget(anime_tab_paragraph, anime_tab);
+    app.register_click_area(anime_tab, ClickTarget::Tab(0));
 
     let manga_tab = tab[1];
 
@@ -62,5 +63,6 @@ pub fn draw_nav_bar(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
     // .block(block);
 
     f.render_widget(manga_tab_block, manga_tab);
+    app.register_click_area(manga_tab, ClickTarget::Tab(1));
     splitted_layout[1]
 }
@@ -1,6 +1,7 @@
 use super::center_area;
 use super::{draw_keys_bar, results};
-use crate::app::{App, SEASONS};
+use crate::app::{App, ClickTarget, SEASONS};
+use crate::ui::util::register_list_items;
 use ratatui::layout::{Alignment, Constraint, Direction, Flex, Layout};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::Line;
@@ -70,7 +71,8 @@ fn draw_seasonal_popup(f: &mut Frame, app: &App, chunk: Rect) {
     let mut state = ListState::default();
     state.select(season_selected);
 
-    let season_list = List::new(list).block(season_block).highlight_style(
+    let season_len = list.len();
+    let season_list = List::new(list).block(season_block.clone()).highlight_style(
         Style::default()
             .fg(app.app_config.theme.selected)
             .add_modifier(Modifier::BOLD),
@@ -82,6 +84,13 @@ fn draw_seasonal_popup(f: &mut Frame, app: &App, chunk: Rect) {
         .flex(Flex::Center)
         .areas(season_chunk);
     f.render_stateful_widget(season_list, centered_season_chunk, &mut state);
+    register_list_items(
+        app,
+        season_block.inner(centered_season_chunk),
+        state.offset(),
+        season_len,
+        ClickTarget::PopupOption,
+    );
 
     // ===> year
 
@@ -120,7 +129,8 @@ fn draw_seasonal_popup(f: &mut Frame, app: &App, chunk: Rect) {
     })
     .collect();
 
-    let year_list = List::new(list).block(year_block).highlight_style(
+    let year_len = list.len();
+    let year_list = List::new(list).block(year_block.clone()).highlight_style(
         Style::default()
             .fg(app.app_config.theme.selected)
             .add_modifier(Modifier::BOLD),
@@ -132,4 +142,11 @@ fn draw_seasonal_popup(f: &mut Frame, app: &App, chunk: Rect) {
         .flex(Flex::Center)
         .areas(year_chunk);
     f.render_widget(year_list, centered_year_chunk);
+    register_list_items(
+        app,
+        year_block.inner(centered_year_chunk),
+        0,
+        year_len,
+        ClickTarget::PopupSideOption,
+    );
 }
@@ -0,0 +1,288 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, BorderType, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+
+use crate::{
+    app::{App, ClickTarget, DASHBOARD_TABS},
+    stats::{AnimeDashboard, CategoryStatistics},
+    ui::util::{capitalize_each_word, get_color},
+};
+
+use super::draw_keys_bar;
+
+// width of the proportional bars in the tables
+const BAR_WIDTH: u64 = 30;
+
+pub fn draw_stats_dashboard(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = draw_keys_bar(f, app, chunk);
+    let dashboard = app.stats_dashboard.as_ref().unwrap();
+
+    let [tabs_chunk, content_chunk] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Fill(1)])
+        .areas(chunk.inner(Margin::new(2, 1)));
+
+    draw_tabs(f, app, tabs_chunk);
+
+    match app.stats_dashboard_tab {
+        0 => draw_score_histogram(f, app, dashboard, content_chunk),
+        1 => draw_categories(f, app, &dashboard.genres, "Genre", content_chunk),
+        2 => draw_categories(f, app, &dashboard.studios, "Studio", content_chunk),
+        3 => draw_seasons(f, app, dashboard, content_chunk),
+        4 => draw_categories(f, app, &dashboard.media_types, "Type", content_chunk),
+        _ => draw_deviation(f, app, dashboard, content_chunk),
+    }
+}
+
+fn draw_tabs(f: &mut Frame, app: &App, chunk: Rect) {
+    let tab_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            DASHBOARD_TABS
+                .iter()
+                .map(|_| Constraint::Ratio(1, DASHBOARD_TABS.len() as u32))
+                .collect::<Vec<Constraint>>(),
+        )
+        .split(chunk);
+
+    for (i, tab) in DASHBOARD_TABS.iter().enumerate() {
+        let is_active = i == app.stats_dashboard_tab;
+        let paragraph = Paragraph::new(Span::styled(
+            *tab,
+            get_color(is_active, app.app_config.theme),
+        ))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(get_color(is_active, app.app_config.theme)),
+        );
+        f.render_widget(paragraph, tab_chunks[i]);
+        app.register_click_area(tab_chunks[i], ClickTarget::Tab(i));
+    }
+}
+
+fn content_block(app: &App, title: String) -> Block<'static> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(app.app_config.theme.inactive))
+        .title(title)
+}
+
+fn format_score(score: Option<f64>) -> String {
+    score.map_or("N/A".to_string(), |s| format!("{:.2}", s))
+}
+
+fn get_bar(value: u64, max: u64) -> String {
+    if max == 0 {
+        return String::new();
+    }
+    "█".repeat((value * BAR_WIDTH).div_ceil(max) as usize)
+}
+
+fn draw_score_histogram(f: &mut Frame, app: &App, dashboard: &AnimeDashboard, chunk: Rect) {
+    let scored: u64 = dashboard.score_histogram.iter().sum();
+    let title = format!(
+        "Score Distribution ({} scored of {} titles)",
+        scored, dashboard.num_items
+    );
+
+    let bars: Vec<Bar> = dashboard
+        .score_histogram
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            Bar::default()
+                .value(count)
+                .label(Line::from((i + 1).to_string()))
+                .style(Style::default().fg(app.app_config.theme.active))
+                .value_style(
+                    Style::default()
+                        .fg(app.app_config.theme.text)
+                        .bg(app.app_config.theme.active)
+                        .add_modifier(Modifier::BOLD),
+                )
+        })
+        .collect();
+
+    let inner_width = chunk.width.saturating_sub(2);
+    // ten bars with a gap of one between them
+    let bar_width = (inner_width.saturating_sub(9) / 10).max(1);
+
+    let chart = BarChart::default()
+        .block(content_block(app, title))
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .label_style(Style::default().fg(app.app_config.theme.text));
+
+    f.render_widget(chart, chunk);
+}
+
+fn draw_categories(
+    f: &mut Frame,
+    app: &App,
+    categories: &[CategoryStatistics],
+    name: &str,
+    chunk: Rect,
+) {
+    let max = categories.first().map_or(0, |c| c.count);
+    let rows: Vec<Row> = categories
+        .iter()
+        .map(|c| {
+            Row::new(vec![
+                Cell::from(capitalize_each_word(c.name.replace('_', " "))),
+                Cell::from(c.count.to_string()),
+                Cell::from(format_score(c.mean_score)),
+                Cell::from(get_bar(c.count, max))
+                    .style(Style::default().fg(app.app_config.theme.active)),
+            ])
+        })
+        .collect();
+
+    let title = format!("{}s by number of titles", name);
+    draw_table(
+        f,
+        app,
+        rows,
+        [name, "Titles", "Mean Score", ""],
+        [
+            Constraint::Length(25),
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Fill(1),
+        ],
+        title,
+        chunk,
+    );
+}
+
+fn draw_seasons(f: &mut Frame, app: &App, dashboard: &AnimeDashboard, chunk: Rect) {
+    let max = dashboard
+        .seasons
+        .iter()
+        .map(|s| s.minutes_watched)
+        .max()
+        .unwrap_or(0);
+    let rows: Vec<Row> = dashboard
+        .seasons
+        .iter()
+        .map(|s| {
+            Row::new(vec![
+                Cell::from(format!(
+                    "{} {}",
+                    capitalize_each_word(s.season.clone()),
+                    s.year
+                )),
+                Cell::from(s.count.to_string()),
+                Cell::from(format!("{:.1}h", s.minutes_watched as f64 / 60.0)),
+                Cell::from(get_bar(s.minutes_watched, max))
+                    .style(Style::default().fg(app.app_config.theme.active)),
+            ])
+        })
+        .collect();
+
+    draw_table(
+        f,
+        app,
+        rows,
+        ["Season", "Titles", "Watched", ""],
+        [
+            Constraint::Length(15),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Fill(1),
+        ],
+        "Watch Time by Airing Season".to_string(),
+        chunk,
+    );
+}
+
+fn draw_deviation(f: &mut Frame, app: &App, dashboard: &AnimeDashboard, chunk: Rect) {
+    let [summary_chunk, table_chunk] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Fill(1)])
+        .areas(chunk);
+
+    let summary = match dashboard.mean_deviation {
+        Some(d) if d >= 0.0 => format!("On average you score {:.2} above the MAL mean", d),
+        Some(d) => format!("On average you score {:.2} below the MAL mean", -d),
+        None => "Score some titles to compare them with the MAL mean".to_string(),
+    };
+    let paragraph = Paragraph::new(summary)
+        .style(Style::default().fg(app.app_config.theme.text))
+        .alignment(Alignment::Center)
+        .block(content_block(app, "Deviation".to_string()));
+    f.render_widget(paragraph, summary_chunk);
+
+    let rows: Vec<Row> = dashboard
+        .deviations
+        .iter()
+        .map(|d| {
+            let color = if d.deviation() >= 0.0 {
+                app.app_config.theme.status_completed
+            } else {
+                app.app_config.theme.status_dropped
+            };
+            Row::new(vec![
+                Cell::from(d.title.clone()),
+                Cell::from(d.score.to_string()),
+                Cell::from(format!("{:.2}", d.mean)),
+                Cell::from(format!("{:+.2}", d.deviation())).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+
+    draw_table(
+        f,
+        app,
+        rows,
+        ["Title", "You", "MAL", "Difference"],
+        [
+            Constraint::Fill(1),
+            Constraint::Length(5),
+            Constraint::Length(6),
+            Constraint::Length(10),
+        ],
+        "Biggest Deviations".to_string(),
+        table_chunk,
+    );
+}
+
+fn draw_table(
+    f: &mut Frame,
+    app: &App,
+    rows: Vec<Row>,
+    header: [&str; 4],
+    widths: [Constraint; 4],
+    title: String,
+    chunk: Rect,
+) {
+    if rows.is_empty() {
+        let paragraph = Paragraph::new("Nothing here")
+            .alignment(Alignment::Center)
+            .block(content_block(app, title));
+        f.render_widget(paragraph, chunk);
+        return;
+    }
+
+    let header = Row::new(
+        header
+            .iter()
+            .map(|&h| Cell::from(h).style(Style::default().add_modifier(Modifier::BOLD)))
+            .collect::<Vec<Cell>>(),
+    );
+    let table = Table::default()
+        .rows(rows)
+        .header(header)
+        .widths(widths)
+        .style(Style::default().fg(app.app_config.theme.text))
+        .block(content_block(app, title));
+    f.render_widget(table, chunk);
+}
@@ -0,0 +1,202 @@
+use std::fmt::Debug;
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::Span,
+    widgets::{Block, Cell, Padding, Row, Table, TableState},
+    Frame,
+};
+
+use crate::{
+    api::model::{Anime, Manga, Node, RankingAnimePair, RankingMangaPair},
+    app::{ActiveBlock, App, ClickTarget},
+    config::app_config::TableColumn,
+};
+
+use super::{bulk_popup::marked_title, get_anime_status_color, get_manga_status_color};
+use crate::ui::util::register_list_items;
+
+/// What a row of the table layout shows for each column
+pub trait TableEntry {
+    fn cell(&self, column: TableColumn, app: &App) -> Cell<'static>;
+}
+
+impl TableEntry for Anime {
+    fn cell(&self, column: TableColumn, app: &App) -> Cell<'static> {
+        let list_status = self.my_list_status.as_ref();
+        match column {
+            TableColumn::Title => Cell::from(marked_title(
+                app,
+                self.id,
+                &self.get_title(&app.app_config.title_language, false)[0],
+            )),
+            TableColumn::MediaType => Cell::from(
+                self.media_type
+                    .clone()
+                    .map_or("-", Into::<&str>::into)
+                    .to_string(),
+            ),
+            TableColumn::Episodes => Cell::from(count_or_unknown(self.num_episodes)),
+            TableColumn::Status => match list_status {
+                Some(s) => Cell::from(Span::styled(
+                    Into::<&str>::into(s.status.clone()).replace('_', " "),
+                    Style::default().fg(get_anime_status_color(&s.status, app)),
+                )),
+                None => Cell::from("-"),
+            },
+            TableColumn::MyScore => Cell::from(score_or_dash(list_status.map(|s| s.score))),
+            TableColumn::Mean => Cell::from(mean_or_dash(self.mean)),
+            TableColumn::Progress => Cell::from(list_status.map_or("-".to_string(), |s| {
+                format!(
+                    "{}/{}",
+                    s.num_episodes_watched,
+                    count_or_unknown(self.num_episodes)
+                )
+            })),
+        }
+    }
+}
+
+impl TableEntry for Manga {
+    fn cell(&self, column: TableColumn, app: &App) -> Cell<'static> {
+        let list_status = self.my_list_status.as_ref();
+        match column {
+            TableColumn::Title => Cell::from(marked_title(
+                app,
+                self.id,
+                &self.get_title(&app.app_config.title_language, false)[0],
+            )),
+            TableColumn::MediaType => Cell::from(
+                self.media_type
+                    .clone()
+                    .map_or("-", Into::<&str>::into)
+                    .to_string(),
+            ),
+            TableColumn::Episodes => Cell::from(count_or_unknown(self.num_chapters)),
+            TableColumn::Status => match list_status {
+                Some(s) => Cell::from(Span::styled(
+                    Into::<&str>::into(s.status.clone()).replace('_', " "),
+                    Style::default().fg(get_manga_status_color(&s.status, app)),
+                )),
+                None => Cell::from("-"),
+            },
+            TableColumn::MyScore => Cell::from(score_or_dash(list_status.map(|s| s.score))),
+            TableColumn::Mean => Cell::from(mean_or_dash(self.mean)),
+            TableColumn::Progress => Cell::from(list_status.map_or("-".to_string(), |s| {
+                format!(
+                    "{}/{}",
+                    s.num_chapters_read,
+                    count_or_unknown(self.num_chapters)
+                )
+            })),
+        }
+    }
+}
+
+impl<T: TableEntry + Clone + Debug> TableEntry for Node<T> {
+    fn cell(&self, column: TableColumn, app: &App) -> Cell<'static> {
+        self.node.cell(column, app)
+    }
+}
+
+impl TableEntry for RankingAnimePair {
+    fn cell(&self, column: TableColumn, app: &App) -> Cell<'static> {
+        self.node.cell(column, app)
+    }
+}
+
+impl TableEntry for RankingMangaPair {
+    fn cell(&self, column: TableColumn, app: &App) -> Cell<'static> {
+        self.node.cell(column, app)
+    }
+}
+
+fn count_or_unknown(count: Option<u64>) -> String {
+    match count {
+        Some(c) if c != 0 => c.to_string(),
+        _ => "?".to_string(),
+    }
+}
+
+fn score_or_dash(score: Option<u8>) -> String {
+    match score {
+        Some(s) if s != 0 => s.to_string(),
+        _ => "-".to_string(),
+    }
+}
+
+fn mean_or_dash(mean: Option<f64>) -> String {
+    mean.map_or("-".to_string(), |m| format!("{:.2}", m))
+}
+
+fn get_column_width(column: TableColumn) -> Constraint {
+    match column {
+        TableColumn::Title => Constraint::Fill(1),
+        TableColumn::MediaType => Constraint::Length(8),
+        TableColumn::Episodes => Constraint::Length(8),
+        TableColumn::Status => Constraint::Length(14),
+        TableColumn::MyScore => Constraint::Length(8),
+        TableColumn::Mean => Constraint::Length(6),
+        TableColumn::Progress => Constraint::Length(10),
+    }
+}
+
+/// dense alternative to the card grid, one row per entry with the configured columns
+pub fn draw_table_layout<T: TableEntry>(f: &mut Frame, app: &App, chunk: Rect, entries: &[T]) {
+    let columns = &app.app_config.table_layout.columns;
+    let header = Row::new(
+        columns
+            .iter()
+            .map(|&c| Cell::from(Into::<&str>::into(c)))
+            .collect::<Vec<Cell>>(),
+    )
+    .style(
+        Style::default()
+            .fg(app.app_config.theme.mal_color)
+            .add_modifier(Modifier::BOLD),
+    )
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = entries
+        .iter()
+        .map(|entry| {
+            Row::new(
+                columns
+                    .iter()
+                    .map(|&c| entry.cell(c, app))
+                    .collect::<Vec<Cell>>(),
+            )
+            .style(Style::default().fg(app.app_config.theme.text))
+        })
+        .collect();
+
+    let is_active = app.active_block == ActiveBlock::DisplayBlock;
+    let mut state = TableState::default();
+    state.select(is_active.then(|| app.search_results.selected_display_card_index.unwrap_or(0)));
+
+    let block = Block::default().padding(Padding::new(2, 2, 1, 1));
+    let rows_area = block.inner(chunk);
+    let table = Table::new(rows, columns.iter().map(|&c| get_column_width(c)))
+        .header(header)
+        .block(block)
+        .row_highlight_style(
+            Style::default()
+                .fg(app.app_config.theme.selected)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("> ");
+    f.render_stateful_widget(table, chunk, &mut state);
+    // rows start under the header and its margin
+    register_list_items(
+        app,
+        Rect {
+            y: rows_area.y + 2,
+            height: rows_area.height.saturating_sub(2),
+            ..rows_area
+        },
+        state.offset(),
+        entries.len(),
+        ClickTarget::Card,
+    );
+}
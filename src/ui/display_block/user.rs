@@ -1,6 +1,6 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Gauge, Paragraph, Wrap},
     Frame,
@@ -8,9 +8,10 @@ use ratatui::{
 
 use crate::app::App;
 
-use super::{center_area, empty::draw_figlet};
+use super::{center_area, draw_keys_bar, empty::draw_figlet};
 
 pub fn draw_user_info(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = draw_keys_bar(f, app, chunk);
     let [username_chunk, info_chunk] = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(10), Constraint::Fill(1)])
@@ -30,6 +31,39 @@ pub fn draw_user_info(f: &mut Frame, app: &App, chunk: Rect) {
 }
 
 fn draw_gauges(f: &mut Frame, app: &App, layout: Rect) {
+    let theme = app.app_config.theme;
+    if app.profile_show_manga {
+        let Some(stats) = app.manga_statistics.as_ref() else {
+            if app.is_loading {
+                draw_centered_line(f, app, layout, "Computing manga statistics...");
+            } else {
+                draw_no_stats(f, app, layout);
+            }
+            return;
+        };
+        if stats.num_items == 0 {
+            draw_no_stats(f, app, layout);
+            return;
+        }
+        let gauges = [
+            ("Reading", stats.num_items_reading, theme.status_watching),
+            (
+                "Completed",
+                stats.num_items_completed,
+                theme.status_completed,
+            ),
+            ("On Hold", stats.num_items_on_hold, theme.status_on_hold),
+            ("Dropped", stats.num_items_dropped, theme.status_dropped),
+            (
+                "Plan to Read",
+                stats.num_items_plan_to_read,
+                theme.status_plan_to_watch,
+            ),
+        ];
+        draw_status_gauges(f, app, layout, &gauges, stats.num_items);
+        return;
+    }
+
     let stats = app.user_profile.as_ref().unwrap().anime_statistics.as_ref();
     if stats.is_none() {
         draw_no_stats(f, app, layout);
@@ -37,12 +71,32 @@ fn draw_gauges(f: &mut Frame, app: &App, layout: Rect) {
     }
     let stats = stats.unwrap().clone();
 
-    let watching = stats.num_items_watching;
-    let completed = stats.num_items_completed;
-    let on_hold = stats.num_items_on_hold;
-    let dropped = stats.num_items_dropped;
-    let plan_to_watch = stats.num_items_plan_to_watch;
-    let total_entries = stats.num_items;
+    let gauges = [
+        ("Watching", stats.num_items_watching, theme.status_watching),
+        (
+            "Completed",
+            stats.num_items_completed,
+            theme.status_completed,
+        ),
+        ("On Hold", stats.num_items_on_hold, theme.status_on_hold),
+        ("Dropped", stats.num_items_dropped, theme.status_dropped),
+        (
+            "Plan to Watch",
+            stats.num_items_plan_to_watch,
+            theme.status_plan_to_watch,
+        ),
+    ];
+    draw_status_gauges(f, app, layout, &gauges, stats.num_items);
+}
+
+/// one labeled gauge per (status, count, color)
+fn draw_status_gauges(
+    f: &mut Frame,
+    app: &App,
+    layout: Rect,
+    gauges: &[(&str, u64, Color); 5],
+    total_entries: u64,
+) {
     let layout: [Rect; 5] = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(3); 5])
@@ -53,100 +107,24 @@ fn draw_gauges(f: &mut Frame, app: &App, layout: Rect) {
         .border_type(BorderType::Plain)
         .borders(Borders::ALL);
 
-    let watching_title = Paragraph::new(format!("Watching: {}", watching))
-        .style(Style::default().fg(app.app_config.theme.text))
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
-    let watching_gauge = Gauge::default()
-        .block(block.clone())
-        .gauge_style(app.app_config.theme.status_watching)
-        .ratio(watching as f64 / total_entries as f64)
-        .label(format!(
-            "{:.0}%",
-            (watching as f64 / total_entries as f64) * 100.0
-        ));
-
-    let completed_title = Paragraph::new(format!("Completed: {}", completed))
-        .style(Style::default().fg(app.app_config.theme.text))
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
-    let completed_gauge = Gauge::default()
-        .block(block.clone())
-        .gauge_style(app.app_config.theme.status_watching)
-        .gauge_style(app.app_config.theme.status_completed)
-        .ratio(completed as f64 / total_entries as f64)
-        .label(format!(
-            "{:.0}%",
-            (completed as f64 / total_entries as f64) * 100.0
-        ));
-
-    let on_hold_title = Paragraph::new(format!("On Hold: {}", on_hold))
-        .style(Style::default().fg(app.app_config.theme.text))
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
-
-    let on_hold_gauge = Gauge::default()
-        .block(block.clone())
-        .gauge_style(app.app_config.theme.status_watching)
-        .gauge_style(app.app_config.theme.status_on_hold)
-        .ratio(on_hold as f64 / total_entries as f64)
-        .label(format!(
-            "{:.0}%",
-            (on_hold as f64 / total_entries as f64) * 100.0
-        ));
-
-    let dropped_title = Paragraph::new(format!("Dropped: {}", dropped))
-        .style(Style::default().fg(app.app_config.theme.text))
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
-
-    let dropped_gauge = Gauge::default()
-        .block(block.clone())
-        .gauge_style(app.app_config.theme.status_watching)
-        .gauge_style(app.app_config.theme.status_dropped)
-        .ratio(dropped as f64 / total_entries as f64)
-        .label(format!(
-            "{:.0}%",
-            (dropped as f64 / total_entries as f64) * 100.0
-        ));
-    let plan_to_watch_title = Paragraph::new(format!("Plan to Watch: {}", plan_to_watch))
-        .style(Style::default().fg(app.app_config.theme.text))
-        .alignment(Alignment::Left)
-        .wrap(Wrap { trim: true });
-
-    let plan_to_watch_gauge = Gauge::default()
-        .block(block.clone())
-        .gauge_style(app.app_config.theme.status_plan_to_watch)
-        .ratio(plan_to_watch as f64 / total_entries as f64)
-        .label(format!(
-            "{:.0}%",
-            (plan_to_watch as f64 / total_entries as f64) * 100.0
-        ));
-
     let percent_y = 40;
     let percent_x = 100;
-    let chunk = get_stat_chunk(layout[0]);
-    f.render_widget(watching_title, center_area(chunk[0], percent_x, percent_y));
-    f.render_widget(watching_gauge, chunk[1]);
-
-    let chunk = get_stat_chunk(layout[1]);
-    f.render_widget(completed_title, center_area(chunk[0], percent_x, percent_y));
-    f.render_widget(completed_gauge, chunk[1]);
-
-    let chunk = get_stat_chunk(layout[2]);
-    f.render_widget(on_hold_title, center_area(chunk[0], percent_x, percent_y));
-    f.render_widget(on_hold_gauge, chunk[1]);
-
-    let chunk = get_stat_chunk(layout[3]);
-    f.render_widget(dropped_title, center_area(chunk[0], percent_x, percent_y));
-    f.render_widget(dropped_gauge, chunk[1]);
-
-    let chunk = get_stat_chunk(layout[4]);
-    f.render_widget(
-        plan_to_watch_title,
-        center_area(chunk[0], percent_x, percent_y),
-    );
-    f.render_widget(plan_to_watch_gauge, chunk[1]);
+    for (i, (status, count, color)) in gauges.iter().enumerate() {
+        let ratio = *count as f64 / total_entries as f64;
+        let title = Paragraph::new(format!("{}: {}", status, count))
+            .style(Style::default().fg(app.app_config.theme.text))
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true });
+        let gauge = Gauge::default()
+            .block(block.clone())
+            .gauge_style(*color)
+            .ratio(ratio)
+            .label(format!("{:.0}%", ratio * 100.0));
+
+        let chunk = get_stat_chunk(layout[i]);
+        f.render_widget(title, center_area(chunk[0], percent_x, percent_y));
+        f.render_widget(gauge, chunk[1]);
+    }
 }
 
 fn get_stat_chunk(layout: Rect) -> [Rect; 2] {
@@ -157,8 +135,15 @@ fn get_stat_chunk(layout: Rect) -> [Rect; 2] {
 }
 
 fn draw_no_stats(f: &mut Frame, app: &App, chunk: Rect) {
-    let text = "No statistics available !! Add some anime to your list!";
+    let text = if app.profile_show_manga {
+        "No statistics available !! Add some manga to your list!"
+    } else {
+        "No statistics available !! Add some anime to your list!"
+    };
+    draw_centered_line(f, app, chunk, text);
+}
 
+fn draw_centered_line(f: &mut Frame, app: &App, chunk: Rect, text: &str) {
     let line = Line::from(Span::styled(
         text,
         Style::default().fg(app.app_config.theme.text),
@@ -177,16 +162,56 @@ fn draw_info(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
         .areas(chunk);
 
     let joined_at = app.user_profile.as_ref().unwrap().joined_at.clone();
-    let mut total_items = 0;
-    let mut mean_score = 0.0;
-    let mut total_eps = 0;
-    let mut total_days = 0.0;
-    let stats = app.user_profile.as_ref().unwrap().anime_statistics.as_ref();
-    if stats.is_some() {
-        total_items = stats.unwrap().num_items;
-        mean_score = stats.unwrap().mean_score;
-        total_days = stats.unwrap().num_days;
-        total_eps = stats.unwrap().num_episodes;
+    let mut list = vec![(
+        "Joined at: ".to_string(),
+        joined_at.datetime.date().to_string(),
+    )];
+    if app.profile_show_manga {
+        let stats = app.manga_statistics.clone().unwrap_or_default();
+        list.push(("Total Entries: ".to_string(), stats.num_items.to_string()));
+        if stats.mean_score != 0.0 {
+            let mean_score = format!("{:.2}", stats.mean_score);
+            list.push(("Mean Score: ".to_string(), mean_score));
+        }
+        if stats.num_chapters != 0 {
+            let chapters = format!("{} Chs", stats.num_chapters);
+            list.push(("Total Chapters: ".to_string(), chapters));
+        }
+        if stats.num_volumes != 0 {
+            let volumes = format!("{} Vols", stats.num_volumes);
+            list.push(("Total Volumes: ".to_string(), volumes));
+        }
+        if stats.num_days != 0.0 {
+            let hours = format!(" ~{:.0} hours", (stats.num_days * 24.0).floor());
+            list.push(("Total Time: ".to_string(), hours));
+        }
+    } else {
+        let mut total_items = 0;
+        let mut mean_score = 0.0;
+        let mut total_eps = 0;
+        let mut total_days = 0.0;
+        let stats = app.user_profile.as_ref().unwrap().anime_statistics.as_ref();
+        if stats.is_some() {
+            total_items = stats.unwrap().num_items;
+            mean_score = stats.unwrap().mean_score;
+            total_days = stats.unwrap().num_days;
+            total_eps = stats.unwrap().num_episodes;
+        }
+        list.push(("Total Entries: ".to_string(), total_items.to_string()));
+        if mean_score != 0.0 {
+            let mean_score = format!("{:.2}", mean_score);
+            list.push(("Mean Score: ".to_string(), mean_score));
+        }
+        if total_eps != 0 {
+            list.push(("Total Episodes: ".to_string(), format!("{} Eps", total_eps)));
+        }
+        if total_days != 0.0 {
+            let total_days = {
+                let hours = ((total_days) * 24.0).floor();
+                format!(" {:.0} hours", hours)
+            };
+            list.push(("Total Time: ".to_string(), total_days));
+        }
     }
 
     let location = app
@@ -197,28 +222,6 @@ fn draw_info(f: &mut Frame, app: &App, chunk: Rect) -> Rect {
         .clone()
         .unwrap_or("".to_string());
 
-    let mut list = vec![
-        (
-            "Joined at: ".to_string(),
-            joined_at.datetime.date().to_string(),
-        ),
-        ("Total Entries: ".to_string(), total_items.to_string()),
-    ];
-    if mean_score != 0.0 {
-        let mean_score = format!("{:.2}", mean_score);
-        list.push(("Mean Score: ".to_string(), mean_score));
-    }
-    if total_eps != 0 {
-        list.push(("Total Episodes: ".to_string(), format!("{} Eps", total_eps)));
-    }
-    if total_days != 0.0 {
-        let total_days = {
-            let hours = ((total_days) * 24.0).floor();
-            format!(" {:.0} hours", hours)
-        };
-        list.push(("Total Time: ".to_string(), total_days));
-    }
-
     if !location.is_empty() {
         list.push(("Location: ".to_string(), location));
     }
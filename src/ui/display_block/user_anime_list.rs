@@ -1,5 +1,6 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Flex, Layout, Rect},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph, Wrap},
     Frame,
@@ -7,12 +8,12 @@ use ratatui::{
 
 use crate::{
     api::model::{AnimeMediaType, UserReadStatus, UserWatchStatus},
-    app::{ActiveBlock, App},
+    app::{ActiveBlock, App, ClickTarget},
     config::app_config::Theme,
-    ui::util::get_color,
+    ui::{get_card_page, util::get_color},
 };
 
-use super::results::construct_cards_with_data;
+use super::{bulk_popup, fuzzy_filter, list_view_popup, results::construct_cards_with_data, table};
 
 pub fn draw_user_anime_list(f: &mut Frame, app: &App, chunk: Rect) {
     // order matters, it should be the same as the Status enum
@@ -29,6 +30,10 @@ pub fn draw_user_anime_list(f: &mut Frame, app: &App, chunk: Rect) {
 
     let chunk = super::draw_keys_bar(f, app, chunk);
     draw_anime_list_results(f, app, chunk);
+    if app.popup {
+        list_view_popup::draw_list_view_popup(f, app, chunk, true);
+    }
+    bulk_popup::draw_bulk_popup(f, app, chunk);
 }
 
 pub fn draw_user_list_nav_bar(
@@ -66,6 +71,7 @@ pub fn draw_user_list_nav_bar(
         };
         let status = get_status_title(status, is_anime);
         draw_tab(f, tabs[i], status, is_active, app.app_config.theme);
+        app.register_click_area(tabs[i], ClickTarget::Tab(i));
     }
 
     layout[1]
@@ -106,28 +112,33 @@ pub fn draw_tab(f: &mut Frame, tab_chunk: Rect, content: String, is_active: bool
 
 fn draw_anime_list_results(f: &mut Frame, app: &App, chunk: Rect) {
     let results = app.search_results.anime.as_ref().unwrap();
+    let chunk = fuzzy_filter::draw_filter_line(f, app, chunk, results.data.len());
     if results.data.is_empty() {
-        // draw_no_results(f, app, chunk);
+        if app.app_config.list_views.anime.is_filtered() {
+            list_view_popup::draw_no_matches(f, app, chunk);
+        }
         return;
     }
-    let cards_results = construct_cards_with_data(chunk, results);
-
-    let cards = cards_results.0;
-    let components = cards_results.1;
-
-    let selected_card_index = app.search_results.selected_display_card_index.unwrap_or(0);
-
-    for (index, component) in components.iter().enumerate() {
-        if index >= cards.len() {
-            break;
-        }
+    if app.is_table_layout() {
+        table::draw_table_layout(f, app, chunk, &results.data);
+        return;
+    }
+    let (grid, position) = get_card_page(app, chunk, results.data.len());
+    let end_index = grid.end_index(position.start, results.data.len());
+    let (cards, components) = construct_cards_with_data(chunk, results, grid);
+    let selected_card_index = position.selected;
 
+    for (index, component) in components[position.start..=end_index].iter().enumerate() {
         let is_active =
             index == selected_card_index && app.active_block == ActiveBlock::DisplayBlock;
 
         let title_style = get_color(is_active, app.app_config.theme);
 
-        let anime_title = &component.get_title(&app.app_config, false)[0];
+        let anime_title = bulk_popup::marked_title(
+            app,
+            component.id,
+            &component.get_title(&app.app_config.title_language, false)[0],
+        );
 
         let anime_title = Line::styled(anime_title, title_style);
 
@@ -164,18 +175,59 @@ fn draw_anime_list_results(f: &mut Frame, app: &App, chunk: Rect) {
                         .map_or("".to_string(), |tags| tags.join(", "))
                 }),
         );
-        let anime_info = vec![anime_title, anime_type_and_score, tags];
+        let mut anime_info = vec![anime_title, anime_type_and_score, tags];
+        let their_score = component.my_list_status.as_ref().map_or(0, |s| s.score);
+        if let Some(line) = get_compare_line(app, component.id, their_score) {
+            anime_info.push(line);
+        }
         let paragraph = Paragraph::new(anime_info)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(get_color(is_active, app.app_config.theme)),
+                    .border_style(get_card_border_style(app, component.id, is_active)),
             )
             .alignment(Alignment::Left)
             .wrap(Wrap { trim: true });
 
         f.render_widget(paragraph, cards[index]);
+        app.register_click_area(cards[index], ClickTarget::Card(index));
+    }
+}
+
+/// "you: 7 (+1)" line for comparing another user's list with mine
+pub fn get_compare_line(app: &App, id: u64, their_score: u8) -> Option<Line<'static>> {
+    if !app.compare_mode || app.list_user.is_none() {
+        return None;
+    }
+    let line = match app.list_my_scores.get(&id) {
+        Some(0) => Line::styled("you: -", app.app_config.theme.status_completed),
+        Some(&mine) => {
+            let diff = if their_score == 0 {
+                String::new()
+            } else {
+                format!(" ({:+})", their_score as i16 - mine as i16)
+            };
+            Line::styled(
+                format!("you: {}{}", mine, diff),
+                app.app_config.theme.status_completed,
+            )
+        }
+        None => Line::styled("not in your list", app.app_config.theme.inactive),
+    };
+    Some(line)
+}
+
+/// shared titles are highlighted in compare mode
+pub fn get_card_border_style(app: &App, id: u64, is_active: bool) -> Style {
+    if !is_active
+        && app.compare_mode
+        && app.list_user.is_some()
+        && app.list_my_scores.contains_key(&id)
+    {
+        Style::default().fg(app.app_config.theme.status_completed)
+    } else {
+        get_color(is_active, app.app_config.theme)
     }
 }
 
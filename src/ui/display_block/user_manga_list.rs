@@ -7,11 +7,16 @@ use ratatui::{
 
 use crate::{
     api::model::MangaMediaType,
-    app::{ActiveBlock, App},
-    ui::util::get_color,
+    app::{ActiveBlock, App, ClickTarget},
+    ui::{get_card_page, util::get_color},
 };
 
-use super::{results::construct_cards_with_data, user_anime_list::draw_user_list_nav_bar};
+use super::{
+    bulk_popup, fuzzy_filter, list_view_popup,
+    results::construct_cards_with_data,
+    table,
+    user_anime_list::{draw_user_list_nav_bar, get_card_border_style, get_compare_line},
+};
 
 pub fn draw_user_manga_list(f: &mut Frame, app: &App, chunk: Rect) {
     let statuses = vec![
@@ -25,29 +30,40 @@ pub fn draw_user_manga_list(f: &mut Frame, app: &App, chunk: Rect) {
     let chunk = draw_user_list_nav_bar(f, app, chunk, false, statuses);
     let chunk = super::draw_keys_bar(f, app, chunk);
     draw_manga_list_results(f, app, chunk);
+    if app.popup {
+        list_view_popup::draw_list_view_popup(f, app, chunk, false);
+    }
+    bulk_popup::draw_bulk_popup(f, app, chunk);
 }
 
 fn draw_manga_list_results(f: &mut Frame, app: &App, chunk: Rect) {
     let results = app.search_results.manga.as_ref().unwrap();
+    let chunk = fuzzy_filter::draw_filter_line(f, app, chunk, results.data.len());
     if results.data.is_empty() {
+        if app.app_config.list_views.manga.is_filtered() {
+            list_view_popup::draw_no_matches(f, app, chunk);
+        }
         return;
     }
-    let cards_results = construct_cards_with_data(chunk, results);
-
-    let cards = cards_results.0;
-    let components = cards_results.1;
-    let selected_card_index = app.search_results.selected_display_card_index.unwrap_or(0);
-
-    for (index, component) in components.iter().enumerate() {
-        if index >= cards.len() {
-            break;
-        }
+    if app.is_table_layout() {
+        table::draw_table_layout(f, app, chunk, &results.data);
+        return;
+    }
+    let (grid, position) = get_card_page(app, chunk, results.data.len());
+    let end_index = grid.end_index(position.start, results.data.len());
+    let (cards, components) = construct_cards_with_data(chunk, results, grid);
+    let selected_card_index = position.selected;
 
+    for (index, component) in components[position.start..=end_index].iter().enumerate() {
         let is_active =
             index == selected_card_index && app.active_block == ActiveBlock::DisplayBlock;
         let title_style = get_color(is_active, app.app_config.theme);
 
-        let manga_title = &component.get_title(&app.app_config, false)[0];
+        let manga_title = bulk_popup::marked_title(
+            app,
+            component.id,
+            &component.get_title(&app.app_config.title_language, false)[0],
+        );
         let manga_title = Line::styled(manga_title, title_style);
 
         let media_type: &str = Into::<&str>::into(
@@ -84,17 +100,22 @@ fn draw_manga_list_results(f: &mut Frame, app: &App, chunk: Rect) {
                 }),
         );
 
-        let manga_info = vec![manga_title, manga_type_and_score, tags];
+        let mut manga_info = vec![manga_title, manga_type_and_score, tags];
+        let their_score = component.my_list_status.as_ref().map_or(0, |s| s.score);
+        if let Some(line) = get_compare_line(app, component.id, their_score) {
+            manga_info.push(line);
+        }
         let paragraph = Paragraph::new(manga_info)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(get_color(is_active, app.app_config.theme)),
+                    .border_style(get_card_border_style(app, component.id, is_active)),
             )
             .alignment(Alignment::Left)
             .wrap(Wrap { trim: true });
 
         f.render_widget(paragraph, cards[index]);
+        app.register_click_area(cards[index], ClickTarget::Card(index));
     }
 }
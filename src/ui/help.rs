@@ -1,32 +1,35 @@
 use crate::app::App;
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
-    style::Style,
-    text::Span,
-    widgets::{Block, Borders, Cell, Row, Table},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
     Frame,
 };
 
-pub fn draw_help_menu(f: &mut Frame, app: &App) {
-    let chunks = Layout::default()
+pub fn draw_help_menu(f: &mut Frame, app: &App, chunk: Rect) {
+    let chunk = crate::ui::display_block::draw_keys_bar(f, app, chunk);
+    let [table_chunk, search_chunk] = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(100)].as_ref())
-        .margin(2)
-        .split(f.area());
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .margin(1)
+        .areas(chunk);
 
-    let white = Style::default().fg(app.app_config.theme.text);
-    let gray = Style::default().fg(app.app_config.theme.inactive); //
+    let theme = app.app_config.theme;
+    let white = Style::default().fg(theme.text);
+    let gray = Style::default().fg(theme.inactive);
 
-    let header = ["Description", "Event", "Context"];
-    let help_docs = get_help();
-    let help_docs: &[Vec<&str>] = &help_docs[app.help_menu_offset as usize..];
+    let header = ["Description", "Keys", "Context"];
+    let help_docs = app.help_rows();
+    let offset = (app.help_menu_offset as usize).min(help_docs.len().saturating_sub(1));
 
     let rows: Vec<Row> = help_docs
         .iter()
+        .skip(offset)
         .map(|i| -> Row {
             Row::new(
                 i.iter()
-                    .map(|&cell| -> Cell { Cell::from(cell).style(gray) })
+                    .map(|cell| -> Cell { Cell::from(cell.as_str()).style(gray) })
                     .collect::<Vec<Cell>>(),
             )
         })
@@ -39,6 +42,13 @@ pub fn draw_help_menu(f: &mut Frame, app: &App) {
             .collect::<Vec<Cell>>(),
     );
 
+    let context: &str = app.help_context.into();
+    let title = if app.help_show_all {
+        "Help: all contexts".to_string()
+    } else {
+        format!("Help: {} context", context)
+    };
+
     let help_menu = Table::default()
         .rows(rows)
         .header(header)
@@ -46,20 +56,32 @@ pub fn draw_help_menu(f: &mut Frame, app: &App) {
             Block::default()
                 .borders(Borders::ALL)
                 .style(white)
-                .title(Span::styled("Help (press <Esc> to go back)", gray))
+                .title(Span::styled(title, gray))
                 .border_style(gray),
         )
-        .style(Style::default().fg(app.app_config.theme.text))
+        .style(Style::default().fg(theme.text))
         .widths([
-            Constraint::Length(50),
-            Constraint::Length(40),
-            Constraint::Length(20),
+            Constraint::Percentage(55),
+            Constraint::Percentage(30),
+            Constraint::Percentage(15),
         ]);
 
-    f.render_widget(help_menu, chunks[0]);
-}
+    f.render_widget(help_menu, table_chunk);
 
-pub fn get_help() -> Vec<Vec<&'static str>> {
-    // TODO: Help docs
-    vec![vec!["Down", "j", "Pagination"]]
+    if app.help_filter.is_active() {
+        let query_style = if app.help_filter.editing {
+            Style::default()
+                .fg(theme.active)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            white
+        };
+        let cursor = if app.help_filter.editing { "_" } else { "" };
+        let line = Line::from(vec![
+            Span::styled(" > ", Style::default().fg(theme.mal_color)),
+            Span::styled(format!("{}{}", app.help_filter.query, cursor), query_style),
+            Span::styled(format!("  {} keys", help_docs.len()), gray),
+        ]);
+        f.render_widget(Paragraph::new(line), search_chunk);
+    }
 }
@@ -1,4 +1,5 @@
 pub mod help;
+mod palette;
 mod side_menu;
 mod top_three;
 pub mod util;
@@ -14,6 +15,8 @@ use util::get_color;
 mod display_block;
 
 pub fn draw_main_layout(f: &mut Frame, app: &mut App) {
+    app.size = f.area();
+    app.click_areas.get_mut().clear();
     let margin = util::get_main_layout_margin(app);
     let app_area;
     if app.app_config.behavior.show_logger {
@@ -41,6 +44,10 @@ pub fn draw_main_layout(f: &mut Frame, app: &mut App) {
     // draw side and dipsplay sections
     let chunk = side_menu::draw_routes(f, app, parent_layout[1]);
     display_block::draw_display_layout(f, app, chunk);
+
+    if app.palette.open {
+        palette::draw_palette(f, app, app_area);
+    }
 }
 
 pub fn draw_input_and_help_box(f: &mut Frame, app: &App, layout_chunk: Rect) {
@@ -67,8 +74,12 @@ pub fn draw_input_and_help_box(f: &mut Frame, app: &App, layout_chunk: Rect) {
             .border_style(get_color(highlight_state, app.app_config.theme)),
     );
     f.render_widget(input, search_chunk);
+    app.register_click_area(search_chunk, ClickTarget::SearchInput);
 
-    let mut title = app.display_block_title.clone();
+    let mut title = app
+        .status_message
+        .clone()
+        .unwrap_or_else(|| app.display_block_title.clone());
     if title.is_empty() {
         title = "Home".to_string(); // Default title , since i couldn't initialize it in app.rs:15
     }
@@ -105,72 +116,40 @@ pub fn format_number_with_commas(number: u64) -> String {
     result.chars().rev().collect()
 }
 
-fn get_end_index(app: &App, typ: &str) -> usize {
-    match typ {
-        "anime" => {
-            let data_len = app.search_results.anime.as_ref().unwrap().data.len();
-            if app.start_card_list_index as usize + DISPLAY_RAWS_NUMBER * DISPLAY_COLUMN_NUMBER
-                > data_len - 1
-            // end is bigger than last index
-            {
-                data_len - 1
-            } else {
-                // start index + 14 to get the last index
-                app.start_card_list_index as usize + DISPLAY_COLUMN_NUMBER * DISPLAY_RAWS_NUMBER - 1
-            }
-        }
-        "manga" => {
-            let data_len = app.search_results.manga.as_ref().unwrap().data.len();
-            if app.start_card_list_index as usize + DISPLAY_RAWS_NUMBER * DISPLAY_COLUMN_NUMBER
-                > data_len - 1
-            // end is bigger than last index in the data
-            {
-                data_len - 1
-            } else {
-                // start index + 14 to get the last index
-                app.start_card_list_index as usize + DISPLAY_COLUMN_NUMBER * DISPLAY_RAWS_NUMBER - 1
-            }
-        }
-        //TODO: handle these cases:
-        "anime_ranking" => {
-            let data_len = app.anime_ranking_data.as_ref().unwrap().data.len();
-            if app.start_card_list_index as usize + DISPLAY_RAWS_NUMBER * DISPLAY_COLUMN_NUMBER
-                > data_len - 1
-            // end is bigger than last index in the data
-            {
-                data_len - 1
-            } else {
-                // start index + 14 to get the last index
-                app.start_card_list_index as usize + DISPLAY_COLUMN_NUMBER * DISPLAY_RAWS_NUMBER - 1
-            }
-        }
-        "manga_ranking" => {
-            let data_len = app.manga_ranking_data.as_ref().unwrap().data.len();
-            if app.start_card_list_index as usize + DISPLAY_RAWS_NUMBER * DISPLAY_COLUMN_NUMBER
-                > data_len - 1
-            // end is bigger than last index in the data
-            {
-                data_len - 1
-            } else {
-                // start index + 14 to get the last index
-                app.start_card_list_index as usize + DISPLAY_COLUMN_NUMBER * DISPLAY_RAWS_NUMBER - 1
-            }
-        }
-        _ => panic!("Unknown type: {}", typ),
-    }
-}
-
-pub fn get_end_card_index(app: &App) -> usize {
+/// number of cards in the current result view
+pub fn get_card_count(app: &App) -> usize {
+    let anime_len = || {
+        app.search_results
+            .anime
+            .as_ref()
+            .map_or(0, |d| d.data.len())
+    };
+    let manga_len = || {
+        app.search_results
+            .manga
+            .as_ref()
+            .map_or(0, |d| d.data.len())
+    };
     match app.active_display_block {
         ActiveDisplayBlock::SearchResultBlock => match app.search_results.selected_tab {
-            SelectedSearchTab::Manga => get_end_index(app, "manga"),
-            SelectedSearchTab::Anime => get_end_index(app, "anime"),
+            SelectedSearchTab::Manga => manga_len(),
+            SelectedSearchTab::Anime => anime_len(),
         },
-        ActiveDisplayBlock::AnimeRanking => get_end_index(app, "anime_ranking"),
-        ActiveDisplayBlock::MangaRanking => get_end_index(app, "manga_ranking"),
-        _ => {
-            // Default case, if no specific block is active
-            get_end_index(app, "anime")
+        ActiveDisplayBlock::AnimeRanking => {
+            app.anime_ranking_data.as_ref().map_or(0, |d| d.data.len())
         }
+        ActiveDisplayBlock::MangaRanking => {
+            app.manga_ranking_data.as_ref().map_or(0, |d| d.data.len())
+        }
+        ActiveDisplayBlock::UserMangaList => manga_len(),
+        _ => anime_len(),
     }
 }
+
+/// grid of the current result view drawn in `chunk` and the selected card, kept on the visible page
+pub fn get_card_page(app: &App, chunk: Rect, len: usize) -> (GridSize, GridPosition) {
+    // the cards are drawn inside a margin of one cell, see `construct_card_grid`
+    app.grid_area.set(Some(chunk.inner(Margin::new(1, 1))));
+    let grid = app.grid_size();
+    (grid, app.grid_position().clamp(grid, len))
+}
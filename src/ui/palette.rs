@@ -0,0 +1,64 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListState, Paragraph},
+    Frame,
+};
+
+use crate::{app::App, palette::COMMAND_USAGE};
+
+use super::display_block::center_area;
+
+/// command palette over the whole app, the query on top and the matching commands under it
+pub fn draw_palette(f: &mut Frame, app: &App, area: Rect) {
+    let area = center_area(area, 50, 60);
+    let theme = app.app_config.theme;
+    let popup = Block::default()
+        .title("Commands")
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .border_style(Style::default().fg(theme.active));
+
+    f.render_widget(Clear, area);
+    let inner = popup.inner(area);
+    f.render_widget(popup, area);
+
+    let [query_chunk, list_chunk, usage_chunk] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+
+    let query = Line::from(vec![
+        Span::styled(" > ", Style::default().fg(theme.mal_color)),
+        Span::styled(
+            format!("{}_", app.palette.query),
+            Style::default()
+                .fg(theme.active)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(query), query_chunk);
+
+    let commands = app.palette_commands();
+    let items: Vec<Line> = commands
+        .iter()
+        .map(|command| Line::styled(format!("   {}", command.label()), theme.text))
+        .collect();
+    let mut state = ListState::default();
+    state.select((!commands.is_empty()).then_some(app.palette.selected));
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .fg(theme.selected)
+            .add_modifier(Modifier::BOLD),
+    );
+    f.render_stateful_widget(list, list_chunk, &mut state);
+
+    let usage = Line::styled(format!(" {}", COMMAND_USAGE), theme.inactive);
+    f.render_widget(Paragraph::new(usage), usage_chunk);
+}
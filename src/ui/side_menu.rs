@@ -1,7 +1,10 @@
-use super::{top_three::draw_top_three, util::get_color};
+use super::{
+    top_three::draw_top_three,
+    util::{get_color, register_list_items},
+};
 use crate::app::{
-    ActiveBlock, App, ANIME_OPTIONS, ANIME_OPTIONS_RANGE, GENERAL_OPTIONS, GENERAL_OPTIONS_RANGE,
-    USER_OPTIONS, USER_OPTIONS_RANGE,
+    ActiveBlock, App, ClickTarget, ANIME_OPTIONS, ANIME_OPTIONS_RANGE, ANONYMOUS_USER_OPTIONS,
+    GENERAL_OPTIONS, GENERAL_OPTIONS_RANGE, USER_OPTIONS, USER_OPTIONS_RANGE,
 };
 
 use ratatui::{
@@ -55,14 +58,19 @@ pub fn draw_anime_routes(f: &mut Frame, app: &App, layout_chunk: Rect) {
         .constraints([Constraint::Length(3)])
         .flex(Flex::Center)
         .areas(layout_chunk);
-    draw_selectable_list(f, app, list_layout, items, index);
+    draw_selectable_list(f, app, list_layout, items, index, ANIME_OPTIONS_RANGE.start);
 }
 
 pub fn draw_user_routes(f: &mut Frame, app: &App, layout_chunk: Rect) {
     let current_block = app.active_block;
     let highlight_state = current_block == ActiveBlock::User;
 
-    let items: Vec<Line> = USER_OPTIONS
+    let options: &[&str] = if app.is_anonymous {
+        &ANONYMOUS_USER_OPTIONS
+    } else {
+        &USER_OPTIONS
+    };
+    let items: Vec<Line> = options
         .iter()
         .map(|i| {
             Line::from(*i)
@@ -87,10 +95,10 @@ pub fn draw_user_routes(f: &mut Frame, app: &App, layout_chunk: Rect) {
     }
     let [list_layout] = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3)])
+        .constraints([Constraint::Length(items.len() as u16)])
         .flex(Flex::Center)
         .areas(layout_chunk);
-    draw_selectable_list(f, app, list_layout, items, index);
+    draw_selectable_list(f, app, list_layout, items, index, USER_OPTIONS_RANGE.start);
 }
 
 pub fn draw_options_routes(f: &mut Frame, app: &App, layout_chunk: Rect) {
@@ -126,7 +134,14 @@ pub fn draw_options_routes(f: &mut Frame, app: &App, layout_chunk: Rect) {
         .constraints([Constraint::Length(3)])
         .flex(Flex::Center)
         .areas(layout_chunk);
-    draw_selectable_list(f, app, list_layout, items, index);
+    draw_selectable_list(
+        f,
+        app,
+        list_layout,
+        items,
+        index,
+        GENERAL_OPTIONS_RANGE.start,
+    );
 }
 
 pub fn draw_user_block(f: &mut Frame, app: &App, layout_chunk: Rect) {
@@ -135,7 +150,7 @@ pub fn draw_user_block(f: &mut Frame, app: &App, layout_chunk: Rect) {
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(5),
-                Constraint::Length(5),
+                Constraint::Length(7),
                 Constraint::Length(5),
                 Constraint::Fill(1),
             ])
@@ -153,10 +168,12 @@ pub fn draw_selectable_list(
     layout_chunk: Rect,
     items: Vec<Line>,
     selected_index: Option<usize>,
+    first_index: usize,
 ) {
+    let len = items.len();
     let mut state = ListState::default();
     if let Some(index) = selected_index {
-        state.select(Some(index % items.len()));
+        state.select(Some(index - first_index));
     }
 
     // choose color based on hover state
@@ -169,4 +186,7 @@ pub fn draw_selectable_list(
     // let centered_rect = display_block::center_area(layout_chunk, 80, 60);
 
     f.render_stateful_widget(items, layout_chunk, &mut state);
+    register_list_items(app, layout_chunk, state.offset(), len, |i| {
+        ClickTarget::MenuItem(first_index + i)
+    });
 }
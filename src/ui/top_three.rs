@@ -12,7 +12,7 @@ use crate::{
         AnimeMediaType, AnimeRankingType, MangaMediaType, MangaRankingType, RankingType,
         UserReadStatus, UserWatchStatus,
     },
-    app::{ActiveBlock, App, TopThreeBlock},
+    app::{ActiveBlock, App, ClickTarget, TopThreeBlock},
 };
 
 use super::{
@@ -91,7 +91,7 @@ fn draw_anime_top_three(
                 .border_type(BorderType::Rounded)
                 .border_style(get_color(is_active, app.app_config.theme));
 
-            let anime_title = data.get_title(&app.app_config, false)[0].clone();
+            let anime_title = data.get_title(&app.app_config.title_language, false)[0].clone();
 
             let score = data
                 .mean
@@ -159,6 +159,7 @@ fn draw_anime_top_three(
                 .wrap(ratatui::widgets::Wrap { trim: false })
                 .block(block);
             f.render_widget(card, *chunk);
+            app.register_click_area(*chunk, ClickTarget::TopThree(i));
         }
     } else {
         // draw error in the top three layout
@@ -221,7 +222,7 @@ fn draw_manga_top_three(
                 .border_type(BorderType::Rounded)
                 .border_style(get_color(is_active, app.app_config.theme));
 
-            let manga_title = data.get_title(&app.app_config, false)[0].clone();
+            let manga_title = data.get_title(&app.app_config.title_language, false)[0].clone();
             let score = data.mean.map_or("N/A".to_string(), |m| m.to_string());
             let media_type: &str = Into::<&str>::into(
                 data.media_type
@@ -272,6 +273,7 @@ fn draw_manga_top_three(
                 .wrap(ratatui::widgets::Wrap { trim: false })
                 .block(block);
             f.render_widget(card, *chunk);
+            app.register_click_area(*chunk, ClickTarget::TopThree(i));
         }
     } else {
         // Draw error in the top three layout
@@ -1,7 +1,10 @@
-use ratatui::style::{Modifier, Style};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+};
 
 // use crate::api::model::*;
-use crate::app::App;
+use crate::app::{App, ClickTarget};
 use crate::config::app_config::Theme;
 
 pub const SMALL_TERMINAL_HEIGHT: u16 = 45;
@@ -15,6 +18,20 @@ pub fn get_color(is_active: bool, theme: Theme) -> Style {
     }
 }
 
+/// one click area per visible item of a list drawn in `area` (inside its block)
+pub fn register_list_items(
+    app: &App,
+    area: Rect,
+    offset: usize,
+    len: usize,
+    target: impl Fn(usize) -> ClickTarget,
+) {
+    for (line, index) in (offset..len).enumerate().take(area.height as usize) {
+        let item = Rect::new(area.x, area.y + line as u16, area.width, 1);
+        app.register_click_area(item, target(index));
+    }
+}
+
 pub fn capitalize_each_word(text: String) -> String {
     text.split_whitespace()
         .map(|word| {
@@ -0,0 +1,356 @@
+use std::fmt::Display;
+
+use crate::{
+    api::{
+        model::{DateWrapper, UserAnimeListStatus, UserMangaListStatus},
+        UpdateUserAnimeListStatusQuery, UpdateUserMangaStatus,
+    },
+    bookmarks::MediaKind,
+    bulk::BulkRequest,
+};
+
+// changes kept for the session, the oldest ones are dropped
+pub const LIST_CHANGES_LIMIT: usize = 50;
+
+/// My list entry before the change and what the change sent, None is a deletion
+#[derive(Clone, Debug)]
+pub enum Change {
+    Anime {
+        before: Option<UserAnimeListStatus>,
+        applied: Option<UpdateUserAnimeListStatusQuery>,
+    },
+    Manga {
+        before: Option<UserMangaListStatus>,
+        applied: Option<UpdateUserMangaStatus>,
+    },
+}
+
+/// A change of my list that went through
+#[derive(Clone, Debug)]
+pub struct ListChange {
+    // order of the changes, to put one back once its revert failed
+    pub seq: u64,
+    pub id: u64,
+    pub title: String,
+    pub change: Change,
+    // unix timestamp
+    pub changed_at: i64,
+}
+
+impl ListChange {
+    pub fn kind(&self) -> MediaKind {
+        match self.change {
+            Change::Anime { .. } => MediaKind::Anime,
+            Change::Manga { .. } => MediaKind::Manga,
+        }
+    }
+
+    /// call bringing the entry back to what it was, only the fields the change sent are restored
+    pub fn revert(&self) -> BulkRequest {
+        match &self.change {
+            Change::Anime { before: None, .. } => BulkRequest::DeleteAnime(self.id),
+            Change::Anime {
+                before: Some(before),
+                applied,
+            } => BulkRequest::UpdateAnime(self.id, restore_anime(before, applied.as_ref())),
+            Change::Manga { before: None, .. } => BulkRequest::DeleteManga(self.id),
+            Change::Manga {
+                before: Some(before),
+                applied,
+            } => BulkRequest::UpdateManga(self.id, restore_manga(before, applied.as_ref())),
+        }
+    }
+
+    /// "status: watching → completed, score: - → 8"
+    pub fn describe(&self) -> String {
+        let parts = match &self.change {
+            Change::Anime { applied: None, .. } | Change::Manga { applied: None, .. } => {
+                return "deleted from the list".to_string();
+            }
+            Change::Anime {
+                before,
+                applied: Some(query),
+            } => {
+                let before = before.as_ref();
+                vec![
+                    field(
+                        "status",
+                        before.map(|s| status_text(&s.status)),
+                        query.status.as_ref().map(status_text),
+                    ),
+                    field("score", before.map(|s| s.score), query.score),
+                    field(
+                        "episodes",
+                        before.map(|s| s.num_episodes_watched),
+                        query.num_watched_episodes,
+                    ),
+                    field(
+                        "tags",
+                        before.map(|s| s.tags.clone().unwrap_or_default().join(",")),
+                        query.tags.clone(),
+                    ),
+                    field(
+                        "start date",
+                        before.and_then(|s| date_text(&s.start_date)),
+                        date_text(&query.start_date),
+                    ),
+                    field(
+                        "finish date",
+                        before.and_then(|s| date_text(&s.finish_date)),
+                        date_text(&query.finish_date),
+                    ),
+                ]
+            }
+            Change::Manga {
+                before,
+                applied: Some(query),
+            } => {
+                let before = before.as_ref();
+                vec![
+                    field(
+                        "status",
+                        before.map(|s| status_text(&s.status)),
+                        query.status.as_ref().map(status_text),
+                    ),
+                    field("score", before.map(|s| s.score), query.score),
+                    field(
+                        "chapters",
+                        before.map(|s| s.num_chapters_read),
+                        query.num_chapters_read,
+                    ),
+                    field(
+                        "volumes",
+                        before.map(|s| s.num_volumes_read),
+                        query.num_volumes_read,
+                    ),
+                    field(
+                        "tags",
+                        before.map(|s| s.tags.clone().unwrap_or_default().join(",")),
+                        query.tags.clone(),
+                    ),
+                    field(
+                        "start date",
+                        before.and_then(|s| date_text(&s.start_date)),
+                        date_text(&query.start_date),
+                    ),
+                    field(
+                        "finish date",
+                        before.and_then(|s| date_text(&s.finish_date)),
+                        date_text(&query.finish_date),
+                    ),
+                ]
+            }
+        };
+        let parts: Vec<String> = parts.into_iter().flatten().collect();
+        if parts.is_empty() {
+            "updated".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// Changes of my list made in this session, the last one first
+#[derive(Clone, Debug, Default)]
+pub struct ListChanges {
+    pub entries: Vec<ListChange>,
+    next_seq: u64,
+}
+
+impl ListChanges {
+    pub fn record(&mut self, id: u64, title: String, change: Change, changed_at: i64) {
+        self.entries.insert(
+            0,
+            ListChange {
+                seq: self.next_seq,
+                id,
+                title,
+                change,
+                changed_at,
+            },
+        );
+        self.next_seq += 1;
+        self.entries.truncate(LIST_CHANGES_LIMIT);
+    }
+
+    /// takes the change out while its revert is sent, so it can't be sent twice
+    pub fn take(&mut self, index: usize) -> Option<ListChange> {
+        (index < self.entries.len()).then(|| self.entries.remove(index))
+    }
+
+    /// puts back a change whose revert failed, at its place
+    pub fn put_back(&mut self, change: ListChange) {
+        let index = self
+            .entries
+            .iter()
+            .position(|c| c.seq < change.seq)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(index, change);
+    }
+}
+
+fn restore_anime(
+    before: &UserAnimeListStatus,
+    applied: Option<&UpdateUserAnimeListStatusQuery>,
+) -> UpdateUserAnimeListStatusQuery {
+    // a deleted entry comes back whole
+    let sent = |is_sent: fn(&UpdateUserAnimeListStatusQuery) -> bool| applied.is_none_or(is_sent);
+    UpdateUserAnimeListStatusQuery {
+        status: sent(|q| q.status.is_some()).then(|| before.status.clone()),
+        is_rewatching: sent(|q| q.is_rewatching.is_some()).then_some(before.is_rewatching),
+        score: sent(|q| q.score.is_some()).then_some(before.score),
+        num_watched_episodes: sent(|q| q.num_watched_episodes.is_some())
+            .then_some(before.num_episodes_watched),
+        priority: sent(|q| q.priority.is_some()).then(|| before.priority.unwrap_or(0)),
+        num_times_rewatched: sent(|q| q.num_times_rewatched.is_some())
+            .then(|| before.num_times_rewatched.unwrap_or(0)),
+        rewatch_value: sent(|q| q.rewatch_value.is_some())
+            .then(|| before.rewatch_value.unwrap_or(0)),
+        tags: sent(|q| q.tags.is_some()).then(|| before.tags.clone().unwrap_or_default().join(",")),
+        comments: sent(|q| q.comments.is_some())
+            .then(|| before.comments.clone().unwrap_or_default()),
+        // the api can't clear a date, one the change filled in is kept
+        start_date: before
+            .start_date
+            .clone()
+            .filter(|_| sent(|q| q.start_date.is_some())),
+        finish_date: before
+            .finish_date
+            .clone()
+            .filter(|_| sent(|q| q.finish_date.is_some())),
+    }
+}
+
+fn restore_manga(
+    before: &UserMangaListStatus,
+    applied: Option<&UpdateUserMangaStatus>,
+) -> UpdateUserMangaStatus {
+    let sent = |is_sent: fn(&UpdateUserMangaStatus) -> bool| applied.is_none_or(is_sent);
+    UpdateUserMangaStatus {
+        status: sent(|q| q.status.is_some()).then(|| before.status.clone()),
+        is_rereading: sent(|q| q.is_rereading.is_some()).then_some(before.is_rereading),
+        score: sent(|q| q.score.is_some()).then_some(before.score),
+        num_volumes_read: sent(|q| q.num_volumes_read.is_some()).then_some(before.num_volumes_read),
+        num_chapters_read: sent(|q| q.num_chapters_read.is_some())
+            .then_some(before.num_chapters_read),
+        priority: sent(|q| q.priority.is_some()).then(|| before.priority.unwrap_or(0)),
+        num_times_reread: sent(|q| q.num_times_reread.is_some())
+            .then(|| before.num_times_reread.unwrap_or(0)),
+        reread_value: sent(|q| q.reread_value.is_some()).then(|| before.reread_value.unwrap_or(0)),
+        tags: sent(|q| q.tags.is_some()).then(|| before.tags.clone().unwrap_or_default().join(",")),
+        comments: sent(|q| q.comments.is_some())
+            .then(|| before.comments.clone().unwrap_or_default()),
+        start_date: before
+            .start_date
+            .clone()
+            .filter(|_| sent(|q| q.start_date.is_some())),
+        finish_date: before
+            .finish_date
+            .clone()
+            .filter(|_| sent(|q| q.finish_date.is_some())),
+    }
+}
+
+/// "name: before → after" when the change sent the field
+fn field<T: Display>(name: &str, before: Option<T>, after: Option<T>) -> Option<String> {
+    let after = after?;
+    let before = before.map_or("-".to_string(), |b| b.to_string());
+    Some(format!("{}: {} → {}", name, before, after))
+}
+
+fn status_text(status: &impl Display) -> String {
+    status.to_string().replace('_', " ")
+}
+
+fn date_text(date: &Option<DateWrapper>) -> Option<String> {
+    date.as_ref().map(|d| d.date.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::model::UserWatchStatus;
+
+    fn watching(score: u8) -> UserAnimeListStatus {
+        serde_json::from_value(serde_json::json!({
+            "status": "watching",
+            "score": score,
+            "num_episodes_watched": 3,
+            "is_rewatching": false,
+            "tags": ["fall"],
+            "updated_at": "2025-01-01T00:00:00+00:00",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_revert() {
+        let mut changes = ListChanges::default();
+        let rated = UpdateUserAnimeListStatusQuery {
+            score: Some(8),
+            ..Default::default()
+        };
+        changes.record(
+            1,
+            "rated".to_string(),
+            Change::Anime {
+                before: Some(watching(0)),
+                applied: Some(rated),
+            },
+            0,
+        );
+        changes.record(
+            2,
+            "added".to_string(),
+            Change::Anime {
+                before: None,
+                applied: Some(UpdateUserAnimeListStatusQuery {
+                    status: Some(UserWatchStatus::PlanToWatch),
+                    ..Default::default()
+                }),
+            },
+            0,
+        );
+        changes.record(
+            3,
+            "deleted".to_string(),
+            Change::Anime {
+                before: Some(watching(7)),
+                applied: None,
+            },
+            0,
+        );
+
+        // a deleted entry comes back with everything it had
+        let deleted = changes.take(0).unwrap();
+        assert_eq!(deleted.describe(), "deleted from the list");
+        match deleted.revert() {
+            BulkRequest::UpdateAnime(3, query) => {
+                assert_eq!(query.score, Some(7));
+                assert_eq!(query.status, Some(UserWatchStatus::Watching));
+                assert_eq!(query.tags.as_deref(), Some("fall"));
+            }
+            request => panic!("unexpected request {:?}", request),
+        }
+        // an added one is deleted
+        assert!(matches!(
+            changes.take(0).unwrap().revert(),
+            BulkRequest::DeleteAnime(2)
+        ));
+        // only the score goes back
+        let rated = &changes.entries[0];
+        assert_eq!(rated.describe(), "score: 0 → 8");
+        match rated.revert() {
+            BulkRequest::UpdateAnime(1, query) => {
+                assert_eq!(query.score, Some(0));
+                assert!(query.status.is_none() && query.num_watched_episodes.is_none());
+            }
+            request => panic!("unexpected request {:?}", request),
+        }
+
+        // a failed revert is put back in order
+        changes.put_back(deleted);
+        assert_eq!(changes.entries[0].title, "deleted");
+        assert_eq!(changes.entries[1].title, "rated");
+    }
+}